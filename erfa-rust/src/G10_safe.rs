@@ -1,17 +1,20 @@
 // G10
 //   dtdb.c  → eraDtdb_safe
-//   dtf2d.c → eraDtf2d_safe
+//   dtf2d.c → eraDtf2d_safe, eraDtf2dTable_safe
 
 use crate::G19_safe::eraJd2cal_safe;
 use crate::G8_safe::eraCal2jd_safe;
-use crate::G9_safe::eraDat_safe;
-use crate::H1_safe::{ERFA_D2PI, ERFA_DAYSEC, ERFA_DD2R, ERFA_DJ00, ERFA_DJM};
+use crate::G14_safe::LeapSecondTable;
+use crate::G9_safe::eraDatTable_safe;
+use crate::H1_safe::{
+    Dtf2dStatus, ErfaError, ERFA_D2PI, ERFA_DAYSEC, ERFA_DD2R, ERFA_DJ00, ERFA_DJM,
+};
 
 #[path = "data/G10_safe/FAIRHD.rs"]
 mod fairhd_mod;
 use fairhd_mod::FAIRHD;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 #[inline]
 fn fmod(a: f64, b: f64) -> f64 {
@@ -106,41 +109,43 @@ pub fn eraDtf2d_safe(
     ihr: i32,
     imn: i32,
     sec: f64,
-) -> Result<((f64, f64), i32), i32> {
+) -> ErfaResult<((f64, f64), Dtf2dStatus)> {
+    eraDtf2dTable_safe(&LeapSecondTable::current()?, scale, iy, im, id, ihr, imn, sec)
+}
+
+// As eraDtf2d_safe, with UTC leap seconds taken from the given table.
+#[allow(clippy::too_many_arguments)]
+pub fn eraDtf2dTable_safe(
+    table: &LeapSecondTable,
+    scale: &str,
+    iy: i32,
+    im: i32,
+    id: i32,
+    ihr: i32,
+    imn: i32,
+    sec: f64,
+) -> ErfaResult<((f64, f64), Dtf2dStatus)> {
     // Convert calendar date to JD (0h today)
-    let ((djm0, djm), j_cal) = eraCal2jd_safe(iy, im, id).map_err(|_| -9999)?;
-    if j_cal != 0 {
-        return Err(j_cal);
-    }
+    let (djm0, djm) = eraCal2jd_safe(iy, im, id)?;
     let dj = djm0 + djm;
 
     // Default day length and final-minute seconds
     let mut day = ERFA_DAYSEC;
     let mut seclim = 60.0_f64;
+    let mut js = Dtf2dStatus::default();
 
     // Leap-second handling for UTC only (exact match)
     if scale == "UTC" {
         // TAI−UTC at 0h today
-        let (dat0, j0) = eraDat_safe(iy, im, id, 0.0).map_err(|_| -9999)?;
-        if j0 < 0 {
-            return Err(j0);
-        }
+        let (dat0, _j0) = eraDatTable_safe(table, iy, im, id, 0.0)?;
 
         // TAI−UTC at 12h today
-        let (dat12, j12) = eraDat_safe(iy, im, id, 0.5).map_err(|_| -9999)?;
-        if j12 < 0 {
-            return Err(j12);
-        }
+        let (dat12, _j12) = eraDatTable_safe(table, iy, im, id, 0.5)?;
 
         // TAI−UTC at 0h tomorrow
-        let ((iy2, im2, id2), _w, j_next) = eraJd2cal_safe(dj + 1.5, 0.0).map_err(|_| -9999)?;
-        if j_next != 0 {
-            return Err(j_next);
-        }
-        let (dat24, j24) = eraDat_safe(iy2, im2, id2, 0.0).map_err(|_| -9999)?;
-        if j24 < 0 {
-            return Err(j24);
-        }
+        let ((iy2, im2, id2), _w) = eraJd2cal_safe(dj + 1.5, 0.0)?;
+        let (dat24, j24) = eraDatTable_safe(table, iy2, im2, id2, 0.0)?;
+        js.dat = j24;
 
         // Leap-second increment for today
         let dleap = dat24 - (2.0 * dat12 - dat0);
@@ -153,26 +158,25 @@ pub fn eraDtf2d_safe(
     }
 
     // Validate clock fields and produce warning if time after end-of-day
-    let mut js_warn = 0;
     if (0..=23).contains(&ihr) {
         if (0..=59).contains(&imn) {
             if sec >= 0.0 {
                 if sec >= seclim {
-                    js_warn += 2;
+                    js.time_after_end_of_day = true;
                 }
             } else {
-                return Err(-6);
+                return Err(ErfaError::BadSecond);
             }
         } else {
-            return Err(-5);
+            return Err(ErfaError::BadMinute);
         }
     } else {
-        return Err(-4);
+        return Err(ErfaError::BadHour);
     }
 
     // Fraction of the day
     let time = (60.0 * (60.0 * ihr as f64 + imn as f64) + sec) / day;
 
-    // Return ((d1, d2), j) with j = 0 or +2
-    Ok(((dj, time), js_warn))
+    // Return ((d1, d2), status)
+    Ok(((dj, time), js))
 }
@@ -7,6 +7,7 @@
 //   ee06a.c   → eraEe06a_safe
 //   eect00.c  → eraEect00_safe
 //   eform.c   → eraEform_safe
+//               Ellipsoid (reference ellipsoids by value, code or name)
 //   eo06a.c   → eraEo06a_safe
 //   eors.c    → eraEors_safe
 
@@ -27,9 +28,12 @@ use crate::G30_safe::eraS06_safe;
 use crate::G33_safe::eraTrxp_safe;
 use crate::G6_safe::eraBpn2xy_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::{ERFA_DAS2R, ERFA_DJ00, ERFA_DJC, ERFA_GRS80, ERFA_WGS72, ERFA_WGS84};
+use crate::H1_safe::{
+    ErfaError, ERFA_DAS2R, ERFA_DJ00, ERFA_DJC, ERFA_GRS80, ERFA_WGS72, ERFA_WGS84,
+};
+use std::sync::RwLock;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Ecliptic to ICRS (equatorial), IAU 2006.
 pub fn eraEceq06_safe(date1: f64, date2: f64, dl: f64, db: f64) -> ErfaResult<(f64, f64)> {
@@ -308,15 +312,163 @@ pub fn eraEect00_safe(date1: f64, date2: f64) -> ErfaResult<f64> {
     Ok(eect)
 }
 
-// Reference ellipsoid parameters; returns ((a, f), j).
-pub fn eraEform_safe(n: i32) -> ErfaResult<((f64, f64), i32)> {
-    let (a, f, j) = match n {
-        ERFA_WGS84 => (6_378_137.0, 1.0 / 298.257_223_563, 0),
-        ERFA_GRS80 => (6_378_137.0, 1.0 / 298.257_222_101, 0),
-        ERFA_WGS72 => (6_378_135.0, 1.0 / 298.26, 0),
-        _ => (0.0, 0.0, -1),
+// Reference ellipsoid parameters; returns (a, f).
+pub fn eraEform_safe(n: i32) -> ErfaResult<(f64, f64)> {
+    let (a, f) = match n {
+        ERFA_WGS84 => (6_378_137.0, 1.0 / 298.257_223_563),
+        ERFA_GRS80 => (6_378_137.0, 1.0 / 298.257_222_101),
+        ERFA_WGS72 => (6_378_135.0, 1.0 / 298.26),
+        _ => return Err(ErfaError::InvalidEllipsoid),
+    };
+    Ok((a, f))
+}
+
+// A reference ellipsoid: equatorial radius a (m) and flattening f, for the
+// eraGd2gcEll_safe, eraGc2gdEll_safe and eraPvtobEll_safe routines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipsoid {
+    pub a: f64,
+    pub f: f64,
+}
+
+// Built-in ellipsoids by name; the first three are eraEform's.
+const BUILTIN_ELLIPSOIDS: [(&str, Ellipsoid); 10] = [
+    ("WGS84", Ellipsoid::WGS84),
+    ("GRS80", Ellipsoid::GRS80),
+    ("WGS72", Ellipsoid::WGS72),
+    ("IERS2010", Ellipsoid::IERS2010),
+    ("Airy1830", Ellipsoid::AIRY1830),
+    ("Bessel1841", Ellipsoid::BESSEL1841),
+    ("Clarke1866", Ellipsoid::CLARKE1866),
+    ("International1924", Ellipsoid::INTERNATIONAL1924),
+    ("Moon", Ellipsoid::MOON),
+    ("Mars", Ellipsoid::MARS),
+];
+
+// Ellipsoids added with Ellipsoid::register.
+static ELLIPSOIDS: RwLock<Vec<(String, Ellipsoid)>> = RwLock::new(Vec::new());
+
+impl Ellipsoid {
+    pub const WGS84: Ellipsoid = Ellipsoid {
+        a: 6_378_137.0,
+        f: 1.0 / 298.257_223_563,
+    };
+    pub const GRS80: Ellipsoid = Ellipsoid {
+        a: 6_378_137.0,
+        f: 1.0 / 298.257_222_101,
     };
-    Ok(((a, f), j))
+    pub const WGS72: Ellipsoid = Ellipsoid {
+        a: 6_378_135.0,
+        f: 1.0 / 298.26,
+    };
+    // IERS Conventions (2010), Table 1.1.
+    pub const IERS2010: Ellipsoid = Ellipsoid {
+        a: 6_378_136.6,
+        f: 1.0 / 298.256_42,
+    };
+    pub const AIRY1830: Ellipsoid = Ellipsoid {
+        a: 6_377_563.396,
+        f: 1.0 / 299.324_964_6,
+    };
+    pub const BESSEL1841: Ellipsoid = Ellipsoid {
+        a: 6_377_397.155,
+        f: 1.0 / 299.152_812_8,
+    };
+    pub const CLARKE1866: Ellipsoid = Ellipsoid {
+        a: 6_378_206.4,
+        f: 1.0 / 294.978_698_2,
+    };
+    pub const INTERNATIONAL1924: Ellipsoid = Ellipsoid {
+        a: 6_378_388.0,
+        f: 1.0 / 297.0,
+    };
+    // IAU WGCCRE mean lunar radius, as a sphere.
+    pub const MOON: Ellipsoid = Ellipsoid {
+        a: 1_737_400.0,
+        f: 0.0,
+    };
+    // IAU WGCCRE (2000) Mars: 3396.19 km by 3376.20 km.
+    pub const MARS: Ellipsoid = Ellipsoid {
+        a: 3_396_190.0,
+        f: (3_396_190.0 - 3_376_200.0) / 3_396_190.0,
+    };
+
+    // Ellipsoid from a radius and flattening, checked as eraGc2gde does.
+    pub fn new(a: f64, f: f64) -> ErfaResult<Ellipsoid> {
+        if !(0.0..1.0).contains(&f) {
+            return Err(ErfaError::InvalidFlattening);
+        }
+        if a <= 0.0 || !a.is_finite() {
+            return Err(ErfaError::InvalidSemiMajorAxis);
+        }
+        Ok(Ellipsoid { a, f })
+    }
+
+    // Ellipsoid for an eraEform identifier (ERFA_WGS84 etc.).
+    pub fn from_code(n: i32) -> ErfaResult<Ellipsoid> {
+        let (a, f) = eraEform_safe(n)?;
+        Ok(Ellipsoid { a, f })
+    }
+
+    // Ellipsoid by name, built-in or registered, ignoring case, spaces,
+    // hyphens and underscores ("Clarke 1866", "clarke_1866").
+    pub fn named(name: &str) -> ErfaResult<Ellipsoid> {
+        let key = ellipsoid_key(name);
+        if let Some((_, e)) = BUILTIN_ELLIPSOIDS
+            .iter()
+            .find(|(n, _)| ellipsoid_key(n) == key)
+        {
+            return Ok(*e);
+        }
+        let guard = ELLIPSOIDS
+            .read()
+            .map_err(|_| ErfaError::EllipsoidRegistryPoisoned)?;
+        guard
+            .iter()
+            .find(|(n, _)| *n == key)
+            .map(|(_, e)| *e)
+            .ok_or(ErfaError::UnknownEllipsoid)
+    }
+
+    // Register an ellipsoid under a name for Ellipsoid::named, replacing
+    // any earlier one of that name; built-in names cannot be redefined.
+    pub fn register(name: &str, ellipsoid: Ellipsoid) -> ErfaResult<()> {
+        let e = Ellipsoid::new(ellipsoid.a, ellipsoid.f)?;
+        let key = ellipsoid_key(name);
+        if key.is_empty()
+            || BUILTIN_ELLIPSOIDS
+                .iter()
+                .any(|(n, _)| ellipsoid_key(n) == key)
+        {
+            return Err(ErfaError::InvalidEllipsoid);
+        }
+        let mut guard = ELLIPSOIDS
+            .write()
+            .map_err(|_| ErfaError::EllipsoidRegistryPoisoned)?;
+        match guard.iter_mut().find(|(n, _)| *n == key) {
+            Some(entry) => entry.1 = e,
+            None => guard.push((key, e)),
+        }
+        Ok(())
+    }
+
+    // Polar radius (m).
+    pub fn b(&self) -> f64 {
+        self.a * (1.0 - self.f)
+    }
+
+    // First eccentricity squared.
+    pub fn e2(&self) -> f64 {
+        (2.0 - self.f) * self.f
+    }
+}
+
+// Registry key: lower case, without spaces, hyphens or underscores.
+fn ellipsoid_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
 }
 
 // Equation of origins, IAU 2006/2000A.
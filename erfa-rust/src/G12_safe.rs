@@ -6,7 +6,7 @@
 
 use crate::H1_safe::{ERFA_DJ00, ERFA_DJM0, ERFA_DJM00, ERFA_DJY, ERFA_DTY};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Julian Date → Besselian Epoch (B1900-based).
 pub fn eraEpb_safe(dj1: f64, dj2: f64) -> ErfaResult<f64> {
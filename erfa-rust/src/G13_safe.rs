@@ -1,7 +1,9 @@
 // G13
 //   epv00.c → eraEpv00_safe
+//             Ephemeris (Earth pv source for the eraApc*13Eph_safe routines)
+//             Epv00 (the eraEpv00 series as an Ephemeris)
 
-use crate::H1_safe::{ERFA_DJ00, ERFA_DJY};
+use crate::H1_safe::{EphemStatus, ERFA_DJ00, ERFA_DJY};
 
 #[path = "data/G13_safe/E0Z.rs"]
 mod e0z_mod;
@@ -46,7 +48,7 @@ use s2x_mod::S2X;
 mod s1z_mod;
 use s1z_mod::S1Z;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Orientation matrix aligning simplified VSOP2000 to DE405.
 const AM12: f64 = 0.000000211284;
@@ -121,13 +123,20 @@ const CS1: [&[f64]; 3] = [S1X, S1Y, S1Z];
 const CS2: [&[f64]; 3] = [S2X, S2Y, S2Z];
 
 // Earth heliocentric and barycentric position-velocity (au, au/day).
-// Returns (pvh, pvb, jstat) with jstat=0 OK, +1 if |t|>100y (warning).
-pub fn eraEpv00_safe(date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2], i32)> {
+// Returns (pvh, pvb, jstat); jstat warns if |t|>100y.
+pub fn eraEpv00_safe(
+    date1: f64,
+    date2: f64,
+) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2], EphemStatus)> {
     // Time since J2000.0 in Julian years
     let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJY;
 
     // Warn if |t| > 100 years
-    let jstat = if t.abs() <= 100.0 { 0 } else { 1 };
+    let jstat = if t.abs() <= 100.0 {
+        EphemStatus::Ok
+    } else {
+        EphemStatus::DateOutsideRange
+    };
 
     // Work vectors
     let mut ph = [0.0_f64; 3];
@@ -167,6 +176,24 @@ pub fn eraEpv00_safe(date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64
     Ok((pvh, pvb, jstat))
 }
 
+// Source of Earth heliocentric and barycentric pv (au, au/day) at a TDB
+// date, returned as (pvh, pvb) like eraEpv00.
+pub trait Ephemeris {
+    #[allow(clippy::type_complexity)]
+    fn earth_pv(&self, date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2])>;
+}
+
+// The eraEpv00 series; the default for the *13 routines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Epv00;
+
+impl Ephemeris for Epv00 {
+    fn earth_pv(&self, date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2])> {
+        let (pvh, pvb, _jstat) = eraEpv00_safe(date1, date2)?;
+        Ok((pvh, pvb))
+    }
+}
+
 // Accumulate a single component for given power of t (0,1,2), summing in reverse order.
 #[inline]
 fn accumulate_component(xyz: &mut f64, xyzd: &mut f64, coeffs: &[f64], t: f64, power: u8) {
@@ -3,6 +3,7 @@
 //   eqeq94.c       → eraEqeq94_safe
 //   era00.c        → eraEra00_safe
 //   erfadatextra.c → eraGetLeapSeconds_safe, eraSetLeapSeconds_safe, eraDatini_safe
+//                    LeapSecondTable (explicit table for the *Table_safe routines)
 //   erfaversion.c  → eraVersion_safe, eraVersionMajor_safe, eraVersionMinor_safe, eraVersionMicro_safe, eraSofaVersion_safe
 
 use std::sync::RwLock;
@@ -13,12 +14,12 @@ use crate::G23_safe::{eraNut80_safe, eraObl80_safe};
 use crate::G28_safe::eraRxp_safe;
 use crate::G29_safe::eraS2c_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::{eraLEAPSECOND, ERFA_D2PI, ERFA_DAS2R, ERFA_DJ00, ERFA_DJC};
+use crate::H1_safe::{eraLEAPSECOND, ErfaError, ERFA_D2PI, ERFA_DAS2R, ERFA_DJ00, ERFA_DJC};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Built-in leap second table for initialization.
-const G14_BUILTIN_LEAPSECONDS: &[eraLEAPSECOND] = &[
+pub(crate) const G14_BUILTIN_LEAPSECONDS: &[eraLEAPSECOND] = &[
     eraLEAPSECOND {
         iyear: 1960,
         month: 1,
@@ -231,18 +232,96 @@ const G14_BUILTIN_LEAPSECONDS: &[eraLEAPSECOND] = &[
     },
 ];
 
+// Expiry of the built-in table (MJD of 2029 January 1): dat.c trusts it to
+// the end of IYV + 5, with IYV = 2023 the year of the last table review.
+pub const G14_BUILTIN_EXPIRY_MJD: f64 = 62_137.0;
+
 // Minimal state for leap seconds.
 #[derive(Clone, Default)]
 struct LeapState {
     ndat: i32,
     table: Vec<eraLEAPSECOND>,
+    expires: f64, // MJD (UTC)
 }
 
 static LEAP_STATE: RwLock<LeapState> = RwLock::new(LeapState {
     ndat: -1,
     table: Vec::new(),
+    expires: G14_BUILTIN_EXPIRY_MJD,
 });
 
+// Leap-second table passed explicitly to the *Table_safe routines (eraDat
+// and its UTC callers); the process-wide table is the default elsewhere.
+// Dates from the expiry on get DatStatus::PastExpiry.
+#[derive(Clone, Debug, Default)]
+pub struct LeapSecondTable {
+    entries: Vec<eraLEAPSECOND>,
+    expires: f64, // MJD (UTC)
+}
+
+impl LeapSecondTable {
+    // Entries in date order, including the pre-1972 drift eras eraDat expects;
+    // expires is the MJD (UTC) up to which no further leap second is known.
+    pub fn new(entries: Vec<eraLEAPSECOND>, expires: f64) -> Self {
+        LeapSecondTable { entries, expires }
+    }
+
+    // The table compiled into the library.
+    pub fn builtin() -> Self {
+        LeapSecondTable::new(G14_BUILTIN_LEAPSECONDS.to_vec(), G14_BUILTIN_EXPIRY_MJD)
+    }
+
+    // Snapshot of the process-wide table and its expiry, read together.
+    pub fn current() -> ErfaResult<Self> {
+        let guard = LEAP_STATE
+            .read()
+            .map_err(|_| ErfaError::LeapTablePoisoned)?;
+        let entries = if guard.ndat > 0 {
+            guard.table.clone()
+        } else {
+            G14_BUILTIN_LEAPSECONDS.to_vec()
+        };
+        Ok(LeapSecondTable::new(entries, guard.expires))
+    }
+
+    pub fn entries(&self) -> &[eraLEAPSECOND] {
+        &self.entries
+    }
+
+    pub fn expires(&self) -> f64 {
+        self.expires
+    }
+
+    // True if the UTC MJD is at or past the expiry.
+    pub fn is_expired(&self, mjd: f64) -> bool {
+        mjd >= self.expires
+    }
+
+    // Make this the process-wide table, expiry included, in one update.
+    // An empty table resets to the built-in one and its expiry.
+    pub fn install(&self) -> ErfaResult<()> {
+        let mut guard = LEAP_STATE
+            .write()
+            .map_err(|_| ErfaError::LeapTablePoisoned)?;
+        set_locked(&mut guard, &self.entries, self.expires);
+        Ok(())
+    }
+}
+
+// Replace the table and expiry under a held write lock; an empty table
+// marks reset-to-builtin with the built-in expiry.
+fn set_locked(state: &mut LeapState, table: &[eraLEAPSECOND], expires: f64) {
+    if table.is_empty() {
+        state.table.clear();
+        state.ndat = 0;
+        state.expires = G14_BUILTIN_EXPIRY_MJD;
+    } else {
+        state.table = table.to_vec();
+        state.ndat = state.table.len() as i32;
+        state.expires = expires;
+    }
+}
+
 // G14/eqec06.c → eraEqec06_safe
 // ICRS equatorial to ecliptic (mean of date, IAU 2006).
 pub fn eraEqec06_safe(date1: f64, date2: f64, dr: f64, dd: f64) -> ErfaResult<(f64, f64)> {
@@ -288,38 +367,41 @@ pub fn eraEra00_safe(dj1: f64, dj2: f64) -> ErfaResult<f64> {
 // Return current leap-second table (copy); init to builtin if needed.
 pub fn eraGetLeapSeconds_safe() -> ErfaResult<Vec<eraLEAPSECOND>> {
     {
-        let guard = LEAP_STATE.read().map_err(|_| ())?;
+        let guard = LEAP_STATE
+            .read()
+            .map_err(|_| ErfaError::LeapTablePoisoned)?;
         if guard.ndat > 0 {
             return Ok(guard.table.clone());
         }
     }
     let _ = eraDatini_safe(G14_BUILTIN_LEAPSECONDS)?;
-    let guard = LEAP_STATE.read().map_err(|_| ())?;
+    let guard = LEAP_STATE
+        .read()
+        .map_err(|_| ErfaError::LeapTablePoisoned)?;
     if guard.ndat > 0 {
         Ok(guard.table.clone())
     } else {
-        Err(())
+        Err(ErfaError::InternalError)
     }
 }
 
 // G14/erfadatextra.c → eraSetLeapSeconds_safe
 // Replace current leap-second table; empty slice marks reset-to-builtin.
+// The expiry reverts to the built-in one (use LeapSecondTable::install to set it).
 pub fn eraSetLeapSeconds_safe(table: &[eraLEAPSECOND]) -> ErfaResult<()> {
-    let mut guard = LEAP_STATE.write().map_err(|_| ())?;
-    if table.is_empty() {
-        guard.table.clear();
-        guard.ndat = 0;
-    } else {
-        guard.table = table.to_vec();
-        guard.ndat = guard.table.len() as i32;
-    }
+    let mut guard = LEAP_STATE
+        .write()
+        .map_err(|_| ErfaError::LeapTablePoisoned)?;
+    set_locked(&mut guard, table, G14_BUILTIN_EXPIRY_MJD);
     Ok(())
 }
 
 // G14/erfadatextra.c → eraDatini_safe
 // Ensure table is initialized to builtin if needed; return current copy.
 pub fn eraDatini_safe(builtin: &[eraLEAPSECOND]) -> ErfaResult<Vec<eraLEAPSECOND>> {
-    let mut guard = LEAP_STATE.write().map_err(|_| ())?;
+    let mut guard = LEAP_STATE
+        .write()
+        .map_err(|_| ErfaError::LeapTablePoisoned)?;
     if guard.ndat <= 0 {
         guard.table = builtin.to_vec();
         guard.ndat = guard.table.len() as i32;
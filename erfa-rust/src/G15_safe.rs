@@ -18,7 +18,7 @@
 use crate::G28_safe::eraRv2m_safe;
 use crate::H1_safe::{ERFA_D2PI, ERFA_DAS2R, ERFA_TURNAS};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Positive modulus for angles; ensures result in [0,y).
 #[inline]
@@ -29,7 +29,7 @@ use crate::G29_safe::{eraS2c_safe, eraS2pv_safe};
 use crate::G30_safe::eraStarpv_safe;
 use crate::G33_safe::eraTrxp_safe;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // G16/fk425.c → eraFk425_safe
 
@@ -360,7 +360,7 @@ pub fn eraFk52h_safe(
     let pvh_vel = eraRxp_safe(&r5h_m, &vv)?;
 
     let pvh = [pvh_pos, pvh_vel];
-    let ((rh, dh, drh, ddh, pxh, rvh), _j) = eraPvstar_safe(&pvh)?;
+    let (rh, dh, drh, ddh, pxh, rvh) = eraPvstar_safe(&pvh)?;
     Ok((rh, dh, drh, ddh, pxh, rvh))
 }
 
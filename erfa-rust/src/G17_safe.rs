@@ -1,8 +1,8 @@
 // G17
 //   g2icrs.c  → eraG2icrs_safe
-//   gc2gd.c   → eraGc2gd_safe
+//   gc2gd.c   → eraGc2gd_safe, eraGc2gdEll_safe
 //   gc2gde.c  → eraGc2gde_safe
-//   gd2gc.c   → eraGd2gc_safe
+//   gd2gc.c   → eraGd2gc_safe, eraGd2gcEll_safe
 //   gd2gce.c  → eraGd2gce_safe
 //   gmst00.c  → eraGmst00_safe
 //   gmst06.c  → eraGmst06_safe
@@ -13,7 +13,7 @@
 //   gst06a.c  → eraGst06a_safe
 //   gst94.c   → eraGst94_safe
 
-use crate::G11_safe::{eraEe00a_safe, eraEe00b_safe, eraEform_safe, eraEors_safe};
+use crate::G11_safe::{eraEe00a_safe, eraEe00b_safe, eraEform_safe, eraEors_safe, Ellipsoid};
 use crate::G14_safe::{eraEqeq94_safe, eraEra00_safe};
 use crate::G1_safe::{eraAnp_safe, eraAnpm_safe};
 use crate::G26_safe::eraPnm06a_safe;
@@ -22,9 +22,11 @@ use crate::G30_safe::eraS06_safe;
 use crate::G33_safe::eraTrxp_safe;
 use crate::G6_safe::eraBpn2xy_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::{ERFA_DAS2R, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJC, ERFA_DPI, ERFA_DS2R};
+use crate::H1_safe::{
+    ErfaError, ERFA_DAS2R, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJC, ERFA_DPI, ERFA_DS2R,
+};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 // G17/g2icrs.c → eraG2icrs_safe
@@ -62,42 +64,26 @@ pub fn eraG2icrs_safe(dl: f64, db: f64) -> ErfaResult<(f64, f64)> {
 // G17/gc2gd.c → eraGc2gd_safe
 //----------------------------------------------------------------------
 // Geocentric XYZ (m) to geodetic using ellipsoid selector n.
-pub fn eraGc2gd_safe(n: i32, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f64, i32)> {
-    let ((a, f), j_eform) = eraEform_safe(n)?;
-    let mut elong = 0.0_f64;
-    let mut phi = 0.0_f64;
-    let mut height = 0.0_f64;
-
-    let mut j = j_eform;
-    if j == 0 {
-        let (el, ph, h, j2) = eraGc2gde_safe(a, f, xyz)?;
-        elong = el;
-        phi = ph;
-        height = h;
-        if j2 < 0 {
-            j = -2;
-        }
-    }
-
-    if j < 0 {
-        elong = -1e9;
-        phi = -1e9;
-        height = -1e9;
-    }
+pub fn eraGc2gd_safe(n: i32, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f64)> {
+    let (a, f) = eraEform_safe(n)?;
+    eraGc2gde_safe(a, f, xyz)
+}
 
-    Ok((elong, phi, height, j))
+// As eraGc2gd_safe on the given ellipsoid.
+pub fn eraGc2gdEll_safe(ellipsoid: &Ellipsoid, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f64)> {
+    eraGc2gde_safe(ellipsoid.a, ellipsoid.f, xyz)
 }
 
 //----------------------------------------------------------------------
 // G17/gc2gde.c → eraGc2gde_safe
 //----------------------------------------------------------------------
 // Geocentric XYZ (m) to geodetic given ellipsoid (a,f).
-pub fn eraGc2gde_safe(a: f64, f: f64, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f64, i32)> {
+pub fn eraGc2gde_safe(a: f64, f: f64, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f64)> {
     if f < 0.0 || f >= 1.0 {
-        return Ok((0.0, 0.0, 0.0, -1));
+        return Err(ErfaError::InvalidFlattening);
     }
     if a <= 0.0 {
-        return Ok((0.0, 0.0, 0.0, -2));
+        return Err(ErfaError::InvalidSemiMajorAxis);
     }
 
     let aeps2 = a * a * 1e-32;
@@ -105,7 +91,7 @@ pub fn eraGc2gde_safe(a: f64, f: f64, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f
     let e4t = e2 * e2 * 1.5;
     let ec2 = 1.0 - e2;
     if ec2 <= 0.0 {
-        return Ok((0.0, 0.0, 0.0, -1));
+        return Err(ErfaError::InvalidFlattening);
     }
     let ec = ec2.sqrt();
     let b = a * ec;
@@ -155,50 +141,40 @@ pub fn eraGc2gde_safe(a: f64, f: f64, xyz: &[f64; 3]) -> ErfaResult<(f64, f64, f
         (ERFA_DPI / 2.0 * if z < 0.0 { -1.0 } else { 1.0 }, absz - b)
     };
 
-    Ok((elong, phi, height, 0))
+    Ok((elong, phi, height))
 }
 
 //----------------------------------------------------------------------
 // G17/gd2gc.c → eraGd2gc_safe
 //----------------------------------------------------------------------
 // Geodetic (elong, phi, height) to geocentric XYZ (m) using selector n.
-pub fn eraGd2gc_safe(n: i32, elong: f64, phi: f64, height: f64) -> ErfaResult<([f64; 3], i32)> {
-    let ((a, f), j_eform) = eraEform_safe(n)?;
-    let mut j = j_eform;
-    let mut xyz = [0.0_f64; 3];
-
-    if j == 0 {
-        let (out, j2) = eraGd2gce_safe(a, f, elong, phi, height)?;
-        xyz = out;
-        if j2 != 0 {
-            j = -2;
-        }
-    }
+pub fn eraGd2gc_safe(n: i32, elong: f64, phi: f64, height: f64) -> ErfaResult<[f64; 3]> {
+    let (a, f) = eraEform_safe(n)?;
+    eraGd2gce_safe(a, f, elong, phi, height)
+}
 
-    if j != 0 {
-        xyz = [0.0, 0.0, 0.0];
-    }
-    Ok((xyz, j))
+// As eraGd2gc_safe on the given ellipsoid.
+pub fn eraGd2gcEll_safe(
+    ellipsoid: &Ellipsoid,
+    elong: f64,
+    phi: f64,
+    height: f64,
+) -> ErfaResult<[f64; 3]> {
+    eraGd2gce_safe(ellipsoid.a, ellipsoid.f, elong, phi, height)
 }
 
 //----------------------------------------------------------------------
 // G17/gd2gce.c → eraGd2gce_safe
 //----------------------------------------------------------------------
 // Geodetic (elong, phi, height) to geocentric XYZ (m) given (a,f).
-pub fn eraGd2gce_safe(
-    a: f64,
-    f: f64,
-    elong: f64,
-    phi: f64,
-    height: f64,
-) -> ErfaResult<([f64; 3], i32)> {
+pub fn eraGd2gce_safe(a: f64, f: f64, elong: f64, phi: f64, height: f64) -> ErfaResult<[f64; 3]> {
     let sp = phi.sin();
     let cp = phi.cos();
     let mut w = 1.0 - f;
     w *= w;
     let d = cp * cp + w * sp * sp;
     if d <= 0.0 {
-        return Ok(([0.0, 0.0, 0.0], -1));
+        return Err(ErfaError::IllegalCase);
     }
 
     let ac = a / d.sqrt();
@@ -209,7 +185,7 @@ pub fn eraGd2gce_safe(
     let y = r * elong.sin();
     let z = (as_ + height) * sp;
 
-    Ok(([x, y, z], 0))
+    Ok([x, y, z])
 }
 
 //----------------------------------------------------------------------
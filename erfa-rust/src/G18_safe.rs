@@ -14,7 +14,7 @@ use crate::G30_safe::{eraStarpv_safe, eraSxp_safe};
 use crate::G33_safe::eraTrxp_safe;
 use crate::H1_safe::{ERFA_D2PI, ERFA_DJ00, ERFA_DJY};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 // G18/h2fk5.c → eraH2fk5_safe
@@ -56,7 +56,7 @@ pub fn eraH2fk5_safe(
     pv5[1] = eraTrxp_safe(&r5h_m, &vv)?;
 
     // FK5 pv-vector → catalog fields
-    let ((r5, d5, dr5, dd5, px5, rv5), _j) = eraPvstar_safe(&pv5)?;
+    let (r5, d5, dr5, dd5, px5, rv5) = eraPvstar_safe(&pv5)?;
     Ok((r5, d5, dr5, dd5, px5, rv5))
 }
 
@@ -8,9 +8,9 @@ use crate::G1_safe::{eraAnp_safe, eraAnpm_safe};
 use crate::G28_safe::eraRxp_safe;
 use crate::G29_safe::eraS2c_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::ERFA_DNINT;
+use crate::H1_safe::{ErfaError, JdcalfStatus, ERFA_DNINT};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 // G19/icrs2g.c → eraIcrs2g_safe
@@ -61,15 +61,15 @@ pub fn eraIr_safe(r: &mut [[f64; 3]; 3]) -> ErfaResult<()> {
 // G19/jd2cal.c → eraJd2cal_safe
 //----------------------------------------------------------------------
 // Two-part JD to Gregorian (iy, im, id) and fractional day fd.
-// Returns ((iy, im, id), fd, j) with j=0 OK, -1 out of range.
-pub fn eraJd2cal_safe(dj1: f64, dj2: f64) -> ErfaResult<((i32, i32, i32), f64, i32)> {
+// Returns ((iy, im, id), fd); out-of-range dates are an error.
+pub fn eraJd2cal_safe(dj1: f64, dj2: f64) -> ErfaResult<((i32, i32, i32), f64)> {
     const DJMIN: f64 = -68_569.5;
     const DJMAX: f64 = 1e9;
 
     // Range check.
     let dj = dj1 + dj2;
     if dj < DJMIN || dj > DJMAX {
-        return Ok(((0, 0, 0), 0.0, -1));
+        return Err(ErfaError::UnacceptableDate);
     }
 
     // Separate integer and fractional parts (compensated summation).
@@ -125,18 +125,18 @@ pub fn eraJd2cal_safe(dj1: f64, dj2: f64) -> ErfaResult<((i32, i32, i32), f64, i
     let im = (k + 2 - 12 * l) as i32;
     let iy = (100 * (n - 49) + i + l) as i32;
 
-    Ok(((iy, im, id), f, 0))
+    Ok(((iy, im, id), f))
 }
 
 //----------------------------------------------------------------------
 // G19/jdcalf.c → eraJdcalf_safe
 //----------------------------------------------------------------------
 // JD to calendar with ndp decimals; returns [iy, im, id, frac×10^ndp] and status.
-pub fn eraJdcalf_safe(ndp: i32, dj1: f64, dj2: f64) -> ErfaResult<([i32; 4], i32)> {
-    let (mut jstat, denom) = if (0..=9).contains(&ndp) {
-        (0, 10_f64.powi(ndp))
+pub fn eraJdcalf_safe(ndp: i32, dj1: f64, dj2: f64) -> ErfaResult<([i32; 4], JdcalfStatus)> {
+    let (jstat, denom) = if (0..=9).contains(&ndp) {
+        (JdcalfStatus::Ok, 10_f64.powi(ndp))
     } else {
-        (1, 1.0)
+        (JdcalfStatus::NdpOutOfRange, 1.0)
     };
 
     // Arrange parts so |d1| ≥ |d2|.
@@ -171,17 +171,8 @@ pub fn eraJdcalf_safe(ndp: i32, dj1: f64, dj2: f64) -> ErfaResult<([i32; 4], i32
     djd += 0.5;
 
     // Convert to calendar date.
-    let ((year, month, day), frac, js) = eraJd2cal_safe(djd, rf)?;
-    let mut out = [0i32; 4];
-    if js == 0 {
-        let frac_i = ERFA_DNINT(frac * denom) as i32;
-        out[0] = year;
-        out[1] = month;
-        out[2] = day;
-        out[3] = frac_i;
-    } else {
-        jstat = js;
-    }
+    let ((year, month, day), frac) = eraJd2cal_safe(djd, rf)?;
+    let out = [year, month, day, ERFA_DNINT(frac * denom) as i32];
 
     Ok((out, jstat))
 }
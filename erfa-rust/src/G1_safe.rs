@@ -7,13 +7,13 @@
 //   anp.c        → eraAnp_safe
 //   anpm.c       → eraAnpm_safe
 //   apcg.c       → eraApcg_safe
-//   apcg13.c     → eraApcg13_safe
+//   apcg13.c     → eraApcg13_safe, eraApcg13Eph_safe
 //   apci.c       → eraApci_safe
-//   apci13.c     → eraApci13_safe
+//   apci13.c     → eraApci13_safe, eraApci13Eph_safe
 
 
 use crate::G11_safe::eraEors_safe;
-use crate::G13_safe::eraEpv00_safe;
+use crate::G13_safe::{Ephemeris, Epv00};
 use crate::G24_safe::eraPdp_safe;
 use crate::G26_safe::eraPnm06a_safe;
 use crate::G2_safe::eraApcs_safe;
@@ -21,9 +21,9 @@ use crate::G30_safe::eraS06_safe;
 use crate::G6_safe::eraBpn2xy_safe;
 use crate::G7_safe::eraC2ixys_safe;
 use crate::G9_safe::eraD2tf_safe;
-use crate::H1_safe::{eraASTROM, ERFA_D2PI, ERFA_DAS2R, ERFA_DPI, ERFA_SRS};
+use crate::H1_safe::{eraASTROM, FieldStatus, ERFA_D2PI, ERFA_DAS2R, ERFA_DPI, ERFA_SRS};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 //  G1/a2af.c
@@ -105,8 +105,13 @@ pub fn eraAe2hd_safe(az: f64, el: f64, phi: f64) -> ErfaResult<(f64, f64)> {
 //  G1/af2a.c
 //----------------------------------------------------------------------
 
-// Convert sign and DMS fields to radians; return status code per ERFA.
-pub fn eraAf2a_safe(s: char, ideg: i32, iamin: i32, asec: f64) -> ErfaResult<(f64, i32)> {
+// Convert sign and DMS fields to radians; status flags the first out-of-range field.
+pub fn eraAf2a_safe(
+    s: char,
+    ideg: i32,
+    iamin: i32,
+    asec: f64,
+) -> ErfaResult<(f64, FieldStatus)> {
     // Magnitude.
     let mag =
         (60.0 * (60.0 * (ideg.abs() as f64) + (iamin.abs() as f64)) + asec.abs()) * ERFA_DAS2R;
@@ -114,13 +119,13 @@ pub fn eraAf2a_safe(s: char, ideg: i32, iamin: i32, asec: f64) -> ErfaResult<(f6
 
     // Range checks.
     let status = if ideg < 0 || ideg > 359 {
-        1
+        FieldStatus::BadDegOrHour
     } else if iamin < 0 || iamin > 59 {
-        2
+        FieldStatus::BadMinute
     } else if asec < 0.0 || asec >= 60.0 {
-        3
+        FieldStatus::BadSecond
     } else {
-        0
+        FieldStatus::Ok
     };
 
     Ok((rad, status))
@@ -177,8 +182,18 @@ pub fn eraApcg_safe(
 
 // Fill astrom using IAU 2006/2000A star-independent parameters.
 pub fn eraApcg13_safe(date1: f64, date2: f64, astrom: &mut eraASTROM) -> ErfaResult<()> {
+    eraApcg13Eph_safe(&Epv00, date1, date2, astrom)
+}
+
+// As eraApcg13_safe with Earth pv from the given ephemeris.
+pub fn eraApcg13Eph_safe<E: Ephemeris + ?Sized>(
+    eph: &E,
+    date1: f64,
+    date2: f64,
+    astrom: &mut eraASTROM,
+) -> ErfaResult<()> {
     // Earth barycentric/heliocentric PV (au, au/day).
-    let (ehpv, ebpv, _jstat) = eraEpv00_safe(date1, date2)?;
+    let (ehpv, ebpv) = eph.earth_pv(date1, date2)?;
     // Use heliocentric position as 'ehp' input.
     eraApcg_safe(date1, date2, &ebpv, &ehpv[0], astrom)
 }
@@ -213,8 +228,18 @@ pub fn eraApci_safe(
 
 // Compute astrometry parameters and return equation of origins (eo).
 pub fn eraApci13_safe(date1: f64, date2: f64, astrom: &mut eraASTROM) -> ErfaResult<f64> {
+    eraApci13Eph_safe(&Epv00, date1, date2, astrom)
+}
+
+// As eraApci13_safe with Earth pv from the given ephemeris.
+pub fn eraApci13Eph_safe<E: Ephemeris + ?Sized>(
+    eph: &E,
+    date1: f64,
+    date2: f64,
+    astrom: &mut eraASTROM,
+) -> ErfaResult<f64> {
     // Earth PV (au, au/day).
-    let (ehpv, ebpv, _jstat) = eraEpv00_safe(date1, date2)?;
+    let (ehpv, ebpv) = eph.earth_pv(date1, date2)?;
 
     // NPB matrix, IAU 2006/2000A.
     let r = eraPnm06a_safe(date1, date2)?;
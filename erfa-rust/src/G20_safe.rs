@@ -23,7 +23,7 @@ use crate::H1_safe::{
     eraLDBODY, ERFA_AULT, ERFA_D2PI, ERFA_DAS2R, ERFA_DAYSEC, ERFA_GMAX, ERFA_GMIN, ERFA_SRS,
 };
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 // G20/ld.c → eraLd_safe
@@ -21,7 +21,7 @@ use tlr_mod::TLR;
 mod tb_mod;
 use tb_mod::TB;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // eraMoon98_safe: Approximate geocentric Moon pv (Meeus 1998), GCRS, au and au/day.
 pub fn eraMoon98_safe(date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 2]> {
@@ -15,7 +15,7 @@ use xls_mod::XLS;
 mod xpl_mod;
 use xpl_mod::XPL;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Small helper: positive modulo for floating angles.
 #[inline]
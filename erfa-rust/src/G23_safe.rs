@@ -19,7 +19,7 @@ use x00b_mod::X00B;
 mod x80_mod;
 use x80_mod::X80;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Helper: positive modulo for angles.
 #[inline]
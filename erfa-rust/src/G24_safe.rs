@@ -16,7 +16,9 @@ use crate::G25_safe::{eraPmat06_safe, eraPmp_safe, eraPn_safe};
 use crate::G27_safe::eraPxp_safe;
 use crate::G28_safe::eraRz_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::{ERFA_D2PI, ERFA_DAS2R, ERFA_DJ00, ERFA_DJC, ERFA_DJM};
+use crate::H1_safe::{
+    EphemStatus, ErfaError, ERFA_D2PI, ERFA_DAS2R, ERFA_DJ00, ERFA_DJC, ERFA_DJM,
+};
 
 #[path = "data/G24_safe/plan94_tables.rs"]
 mod plan94_tables;
@@ -27,7 +29,7 @@ use plan94_tables::{
     TRIG_COEFF_L_COS as CL, TRIG_COEFF_L_SIN as SL,
 };
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // eraP06e_safe: IAU 2006 equinox-based precession parameter set.
 pub fn eraP06e_safe(
@@ -271,19 +273,27 @@ pub fn eraPm_safe(p: &[f64; 3]) -> ErfaResult<f64> {
 }
 
 // eraPlan94_safe: Approximate heliocentric position/velocity for planet np (1..8).
-pub fn eraPlan94_safe(date1: f64, date2: f64, np_in: i32) -> ErfaResult<([[f64; 3]; 2], i32)> {
+pub fn eraPlan94_safe(
+    date1: f64,
+    date2: f64,
+    np_in: i32,
+) -> ErfaResult<([[f64; 3]; 2], EphemStatus)> {
     const GK: f64 = 0.017_202_098_950; // Gaussian gravitational constant (au^3/d^2).
     const SINEPS: f64 = 0.397_777_155_931_913_7; // J2000 mean obliquity (IAU 1976).
     const COSEPS: f64 = 0.917_482_062_069_181_8;
     const KMAX: i32 = 10;
 
     if np_in < 1 || np_in > 8 {
-        return Ok(([[0.0; 3]; 2], -1));
+        return Err(ErfaError::InvalidPlanet);
     }
     let np = (np_in - 1) as usize;
 
     let t = ((date1 - ERFA_DJ00) + date2) / ERFA_DJM;
-    let mut jstat = if t.abs() <= 1.0 { 0 } else { 1 };
+    let mut jstat = if t.abs() <= 1.0 {
+        EphemStatus::Ok
+    } else {
+        EphemStatus::DateOutsideRange
+    };
 
     let mut da = A[np][0] + (A[np][1] + A[np][2] * t) * t;
     let mut dl = (3600.0 * DLM[np][0] + (DLM[np][1] + DLM[np][2] * t) * t) * ERFA_DAS2R;
@@ -320,7 +330,7 @@ pub fn eraPlan94_safe(date1: f64, date2: f64, np_in: i32) -> ErfaResult<([[f64;
         }
     }
     if k_iter >= KMAX {
-        jstat = 2;
+        jstat = EphemStatus::NotConverged;
     }
 
     let ae2 = 0.5 * ae;
@@ -4,7 +4,7 @@
 //   pmat76.c → eraPmat76_safe
 //   pmp.c    → eraPmp_safe
 //   pmpx.c   → eraPmpx_safe
-//   pmsafe.c → eraPmsafe_safe
+//   pmsafe.c → eraPmsafe_safe, eraPmsafeCov_safe
 //   pn.c     → eraPn_safe
 //   pn00.c   → eraPn00_safe
 //   pn00a.c  → eraPn00a_safe
@@ -19,12 +19,14 @@ use crate::G24_safe::{eraPdp_safe, eraPfw06_safe, eraPm_safe};
 use crate::G26_safe::eraPr00_safe;
 use crate::G27_safe::eraPrec76_safe;
 use crate::G28_safe::{eraRxr_safe, eraRy_safe, eraRz_safe};
-use crate::G30_safe::{eraSeps_safe, eraStarpm_safe, eraSxp_safe};
+use crate::G30_safe::{eraSeps_safe, eraStarpmCov_safe, eraStarpm_safe, eraSxp_safe};
 use crate::G35_safe::eraZp_safe;
 use crate::G6_safe::eraBp00_safe;
-use crate::H1_safe::{ERFA_AULT, ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJM, ERFA_DJY};
+use crate::H1_safe::{
+    StarpvStatus, ERFA_AULT, ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJM, ERFA_DJY,
+};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Precession matrix, IAU 2000 bias-precession model.
 pub fn eraPmat00_safe(date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 3]> {
@@ -110,7 +112,7 @@ pub fn eraPmsafe_safe(
     ep1b: f64,
     ep2a: f64,
     ep2b: f64,
-) -> ErfaResult<((f64, f64, f64, f64, f64, f64), i32)> {
+) -> ErfaResult<((f64, f64, f64, f64, f64, f64), StarpvStatus)> {
     const PXMIN: f64 = 5.0e-7; // arcsec
     const F: f64 = 326.0; // scale giving ~1% c max transverse speed
 
@@ -118,30 +120,59 @@ pub fn eraPmsafe_safe(
     let pm = eraSeps_safe(ra1, dec1, ra1 + pmr1, dec1 + pmd1)?;
 
     // Override small parallax if implausible given PM.
-    let mut jpx = 0;
+    let mut jpx = false;
     let mut px1a = px1;
     let pm_scaled = pm * F;
     if px1a < pm_scaled {
         px1a = pm_scaled;
-        jpx = 1;
+        jpx = true;
     }
     if px1a < PXMIN {
         px1a = PXMIN;
-        jpx = 1;
+        jpx = true;
     }
 
     // Propagate star parameters.
     let ((ra2, dec2, pmr2, pmd2, px2, rv2), mut j) =
         eraStarpm_safe(ra1, dec1, pmr1, pmd1, px1a, rv1, ep1a, ep1b, ep2a, ep2b)?;
 
-    // Revise status to reflect parallax override if no error bits set.
-    if (j & 1) == 0 {
-        j += jpx;
-    }
+    // Revise status to reflect parallax override.
+    j.distance_overridden |= jpx;
 
     Ok(((ra2, dec2, pmr2, pmd2, px2, rv2), j))
 }
 
+// As eraPmsafe_safe, also propagating the covariance (see
+// eraStarpmCov_safe).  An overridden parallax keeps its input variance.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn eraPmsafeCov_safe(
+    ra1: f64,
+    dec1: f64,
+    pmr1: f64,
+    pmd1: f64,
+    px1: f64,
+    rv1: f64,
+    cov1: &[[f64; 6]; 6],
+    ep1a: f64,
+    ep1b: f64,
+    ep2a: f64,
+    ep2b: f64,
+) -> ErfaResult<((f64, f64, f64, f64, f64, f64), [[f64; 6]; 6], StarpvStatus)> {
+    const PXMIN: f64 = 5.0e-7; // arcsec
+    const F: f64 = 326.0; // scale giving ~1% c max transverse speed
+
+    // Same override as eraPmsafe.
+    let pm = eraSeps_safe(ra1, dec1, ra1 + pmr1, dec1 + pmd1)?;
+    let px1a = px1.max(pm * F).max(PXMIN);
+    let jpx = px1a != px1;
+
+    let (star2, cov2, mut j) = eraStarpmCov_safe(
+        ra1, dec1, pmr1, pmd1, px1a, rv1, cov1, ep1a, ep1b, ep2a, ep2b,
+    )?;
+    j.distance_overridden |= jpx;
+    Ok((star2, cov2, j))
+}
+
 // Decompose p-vector into modulus and unit vector.
 pub fn eraPn_safe(p: &[f64; 3]) -> ErfaResult<(f64, [f64; 3])> {
     let w = eraPm_safe(p)?;
@@ -22,7 +22,7 @@ use crate::G30_safe::eraSxp_safe;
 use crate::G33_safe::eraTr_safe;
 use crate::G8_safe::eraCr_safe;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // G26/pn06.c
 // Precession-nutation products, IAU 2006 bias-precession with given dpsi,deps.
@@ -7,17 +7,18 @@
 //   pvmpv.c   → eraPvmpv_safe
 //   pvppv.c   → eraPvppv_safe
 //   pvstar.c  → eraPvstar_safe
-//   pvtob.c   → eraPvtob_safe
+//   pvtob.c   → eraPvtob_safe, eraPvtobEll_safe
 //   pvu.c     → eraPvu_safe
 //   pvup.c    → eraPvup_safe
 //   pvxpv.c   → eraPvxpv_safe
 //   pxp.c     → eraPxp_safe
 use crate::H1_safe::{
-    ERFA_D2PI, ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DC, ERFA_DJ00, ERFA_DJC, ERFA_DJY,
-    ERFA_DR2AS,
+    ErfaError, ERFA_D2PI, ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DC, ERFA_DJ00, ERFA_DJC,
+    ERFA_DJY, ERFA_DR2AS,
 };
 
-use crate::G17_safe::eraGd2gc_safe;
+use crate::G11_safe::Ellipsoid;
+use crate::G17_safe::eraGd2gcEll_safe;
 use crate::G1_safe::eraAnp_safe;
 use crate::G24_safe::{eraPdp_safe, eraPm_safe};
 use crate::G25_safe::{eraPmp_safe, eraPn_safe};
@@ -26,7 +27,7 @@ use crate::G30_safe::eraSxp_safe;
 use crate::G33_safe::eraTrxp_safe;
 use crate::G8_safe::{eraCp_safe, eraCpv_safe};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Compute IAU 1976 precession angles (zeta, z, theta) between two epochs.
 pub fn eraPrec76_safe(
@@ -127,8 +128,8 @@ pub fn eraPvppv_safe(a: &[[f64; 3]; 2], b: &[[f64; 3]; 2]) -> ErfaResult<[[f64;
     Ok([p, v])
 }
 
-// Convert pv-vector to catalog parameters (ra,dec,pmr,pmd,px,rv); j=-1 superluminal, -2 null pos.
-pub fn eraPvstar_safe(pv: &[[f64; 3]; 2]) -> ErfaResult<((f64, f64, f64, f64, f64, f64), i32)> {
+// Convert pv-vector to catalog parameters (ra,dec,pmr,pmd,px,rv); errors if superluminal or null.
+pub fn eraPvstar_safe(pv: &[[f64; 3]; 2]) -> ErfaResult<(f64, f64, f64, f64, f64, f64)> {
     let (_r, pu) = eraPn_safe(&pv[0])?;
     let vr = eraPdp_safe(&pu, &pv[1])?;
     let ur = eraSxp_safe(vr, &pu)?;
@@ -140,7 +141,7 @@ pub fn eraPvstar_safe(pv: &[[f64; 3]; 2]) -> ErfaResult<((f64, f64, f64, f64, f6
     let d = 1.0 + betr;
     let w = betr * betr + bett * bett;
     if d == 0.0 || w > 1.0 {
-        return Ok(((0.0, 0.0, 0.0, 0.0, 0.0, 0.0), -1));
+        return Err(ErfaError::Superluminal);
     }
     let del = -w / ((1.0 - w).sqrt() + 1.0);
 
@@ -151,7 +152,7 @@ pub fn eraPvstar_safe(pv: &[[f64; 3]; 2]) -> ErfaResult<((f64, f64, f64, f64, f6
 
     let (a, dec, r_out, rad, decd, rd) = eraPv2s_safe(&pv_mod)?;
     if r_out == 0.0 {
-        return Ok(((0.0, 0.0, 0.0, 0.0, 0.0, 0.0), -2));
+        return Err(ErfaError::NullPosition);
     }
 
     let ra = eraAnp_safe(a)?;
@@ -160,7 +161,7 @@ pub fn eraPvstar_safe(pv: &[[f64; 3]; 2]) -> ErfaResult<((f64, f64, f64, f64, f6
     let px = ERFA_DR2AS / r_out;
     let rv = 1e-3 * rd * ERFA_DAU / ERFA_DAYSEC;
 
-    Ok(((ra, dec, pmr, pmd, px, rv), 0))
+    Ok((ra, dec, pmr, pmd, px, rv))
 }
 
 // Observer geocentric position/velocity from site geodetic coordinates.
@@ -172,10 +173,26 @@ pub fn eraPvtob_safe(
     yp: f64,
     sp: f64,
     theta: f64,
+) -> ErfaResult<[[f64; 3]; 2]> {
+    eraPvtobEll_safe(&Ellipsoid::WGS84, elong, phi, hm, xp, yp, sp, theta)
+}
+
+// As eraPvtob_safe with the site's geodetic coordinates on the given
+// ellipsoid; the velocity is still from the Earth's rotation rate.
+#[allow(clippy::too_many_arguments)]
+pub fn eraPvtobEll_safe(
+    ellipsoid: &Ellipsoid,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    sp: f64,
+    theta: f64,
 ) -> ErfaResult<[[f64; 3]; 2]> {
     const OM: f64 = 1.002_737_811_911_354_48 * ERFA_D2PI / ERFA_DAYSEC;
 
-    let (xyzm, _j) = eraGd2gc_safe(1, elong, phi, hm)?;
+    let xyzm = eraGd2gcEll_safe(ellipsoid, elong, phi, hm)?;
     let rpm = eraPom00_safe(xp, yp, sp)?;
     let xyz = eraTrxp_safe(&rpm, &xyzm)?;
 
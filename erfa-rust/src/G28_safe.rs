@@ -1,5 +1,7 @@
 // G28_safe  Vector/Matrix & Refraction helpers
 //   refco.c → eraRefco_safe
+//             Refraction (model for eraAtioqRef_safe / eraAtoiqRef_safe)
+//             TwoTerm (A tan z + B tan³ z, as in eraASTROM)
 //   rm2v.c  → eraRm2v_safe
 //   rv2m.c  → eraRv2m_safe
 //   rx.c    → eraRx_safe
@@ -9,7 +11,9 @@
 //   ry.c    → eraRy_safe
 //   rz.c    → eraRz_safe
 
-pub type ErfaResult<T> = Result<T, ()>;
+use crate::H1_safe::eraASTROM;
+
+pub use crate::H1_safe::ErfaResult;
 
 // Compute refraction coefficients A & B.
 pub fn eraRefco_safe(phpa: f64, tc: f64, rh: f64, wl: f64) -> ErfaResult<(f64, f64)> {
@@ -57,6 +61,49 @@ pub fn eraRefco_safe(phpa: f64, tc: f64, rh: f64, wl: f64) -> ErfaResult<(f64, f
     Ok((refa, refb))
 }
 
+// Refraction model: the amount (radians) by which refraction reduces the
+// zenith distance, given either the topocentric (unrefracted) or the
+// observed zenith distance.
+pub trait Refraction {
+    fn for_topocentric(&self, zt: f64) -> f64;
+    fn for_observed(&self, zo: f64) -> f64;
+}
+
+// Green's two-term model, as eraAtioq / eraAtoiq apply it, including their
+// floors near the horizon.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TwoTerm {
+    pub refa: f64,
+    pub refb: f64,
+}
+
+impl TwoTerm {
+    pub fn from_astrom(astrom: &eraASTROM) -> TwoTerm {
+        TwoTerm {
+            refa: astrom.refa,
+            refb: astrom.refb,
+        }
+    }
+}
+
+impl Refraction for TwoTerm {
+    fn for_topocentric(&self, zt: f64) -> f64 {
+        const CELMIN: f64 = 1e-6;
+        const SELMIN: f64 = 0.05;
+        let r = zt.sin().max(CELMIN);
+        let z = zt.cos().max(SELMIN);
+        let tz = r / z;
+        let w = self.refb * tz * tz;
+        (self.refa + w) * tz / (1.0 + (self.refa + 3.0 * w) / (z * z))
+    }
+
+    fn for_observed(&self, zo: f64) -> f64 {
+        const SELMIN: f64 = 0.05;
+        let tz = zo.sin() / zo.cos().max(SELMIN);
+        (self.refa + self.refb * tz * tz) * tz
+    }
+}
+
 // Rotation matrix → rotation vector.
 pub fn eraRm2v_safe(r: &[[f64; 3]; 3]) -> ErfaResult<[f64; 3]> {
     let x = r[1][2] - r[2][1];
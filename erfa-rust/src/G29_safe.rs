@@ -15,7 +15,7 @@ use crate::G26_safe::{eraPnm00a_safe, eraPnm00b_safe};
 use crate::G6_safe::eraBpn2xy_safe;
 use crate::H1_safe::{ERFA_DAS2R, ERFA_DJ00, ERFA_DJC};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // ===================================
 // G29/s00.c
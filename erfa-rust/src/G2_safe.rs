@@ -1,13 +1,13 @@
 // G2
 //   apco.c    → eraApco_safe
-//   apco13.c  → eraApco13_safe
+//   apco13.c  → eraApco13_safe, eraApco13Eph_safe
 //   apcs.c    → eraApcs_safe
-//   apcs13.c  → eraApcs13_safe
+//   apcs13.c  → eraApcs13_safe, eraApcs13Eph_safe
 //   aper.c    → eraAper_safe
 
 
 use crate::G11_safe::eraEors_safe;
-use crate::G13_safe::eraEpv00_safe;
+use crate::G13_safe::{Ephemeris, Epv00};
 use crate::G14_safe::eraEra00_safe;
 use crate::G19_safe::eraIr_safe;
 use crate::G1_safe::eraAnpm_safe;
@@ -21,9 +21,11 @@ use crate::G33_safe::{eraTrxpv_safe, eraUtctai_safe, eraUtcut1_safe};
 use crate::G6_safe::eraBpn2xy_safe;
 use crate::G7_safe::eraC2ixys_safe;
 use crate::G8_safe::{eraCp_safe, eraCr_safe};
-use crate::H1_safe::{eraASTROM, ERFA_AULT, ERFA_DAU, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJY};
+use crate::H1_safe::{
+    eraASTROM, DatStatus, ERFA_AULT, ERFA_DAU, ERFA_DAYSEC, ERFA_DJ00, ERFA_DJY,
+};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // G2/apco.c
 // Prepare star-independent astrometry parameters for a terrestrial observer.
@@ -108,7 +110,7 @@ pub fn eraApco_safe(
 }
 
 // G2/apco13.c
-// Prepare astrometry parameters from UTC; returns (eo, j) where j=0 or +1.
+// Prepare astrometry parameters from UTC; returns (eo, j) where j flags a dubious year.
 pub fn eraApco13_safe(
     utc1: f64,
     utc2: f64,
@@ -123,20 +125,37 @@ pub fn eraApco13_safe(
     rh: f64,
     wl: f64,
     astrom: &mut eraASTROM,
-) -> ErfaResult<(f64, i32)> {
+) -> ErfaResult<(f64, DatStatus)> {
+    eraApco13Eph_safe(
+        &Epv00, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl, astrom,
+    )
+}
+
+// As eraApco13_safe with Earth pv from the given ephemeris.
+#[allow(clippy::too_many_arguments)]
+pub fn eraApco13Eph_safe<E: Ephemeris + ?Sized>(
+    eph: &E,
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+    astrom: &mut eraASTROM,
+) -> ErfaResult<(f64, DatStatus)> {
     // UTC→TAI and TAI→TT; UTC→UT1.
-    let ((tai1, tai2), j_utctai) = eraUtctai_safe(utc1, utc2)?;
-    if j_utctai < 0 {
-        return Err(());
-    }
-    let ((tt1, tt2), _j_tai_tt) = eraTaitt_safe(tai1, tai2)?;
+    let ((tai1, tai2), _j_utctai) = eraUtctai_safe(utc1, utc2)?;
+    let (tt1, tt2) = eraTaitt_safe(tai1, tai2)?;
     let ((ut11, ut12), j_ut1) = eraUtcut1_safe(utc1, utc2, dut1)?;
-    if j_ut1 < 0 {
-        return Err(());
-    }
 
     // Earth ephemeris, CIP/CIO, refraction.
-    let (ehpv, ebpv, _jstat) = eraEpv00_safe(tt1, tt2)?;
+    let (ehpv, ebpv) = eph.earth_pv(tt1, tt2)?;
     let r = eraPnm06a_safe(tt1, tt2)?;
     let (x, y) = eraBpn2xy_safe(&r)?;
     let s = eraS06_safe(tt1, tt2, x, y)?;
@@ -152,7 +171,7 @@ pub fn eraApco13_safe(
     // Equation of the origins.
     let eo = eraEors_safe(&r, s)?;
 
-    // Return EO and UT1 status (0 or +1).
+    // Return EO and UT1 status.
     Ok((eo, j_ut1))
 }
 
@@ -215,9 +234,20 @@ pub fn eraApcs13_safe(
     date2: f64,
     pv: &[[f64; 3]; 2], // (m, m/s)
     astrom: &mut eraASTROM,
+) -> ErfaResult<()> {
+    eraApcs13Eph_safe(&Epv00, date1, date2, pv, astrom)
+}
+
+// As eraApcs13_safe with Earth pv from the given ephemeris.
+pub fn eraApcs13Eph_safe<E: Ephemeris + ?Sized>(
+    eph: &E,
+    date1: f64,
+    date2: f64,
+    pv: &[[f64; 3]; 2], // (m, m/s)
+    astrom: &mut eraASTROM,
 ) -> ErfaResult<()> {
     // Earth barycentric & heliocentric PV (au, au/day).
-    let (ehpv, ebpv, _jstat) = eraEpv00_safe(date1, date2)?;
+    let (ehpv, ebpv) = eph.earth_pv(date1, date2)?;
 
     // Compute the star-independent astrometry parameters.
     eraApcs_safe(date1, date2, pv, &ebpv, &ehpv[0], astrom)
@@ -4,8 +4,8 @@
 //   sepp.c   → eraSepp_safe
 //   seps.c   → eraSeps_safe
 //   sp00.c   → eraSp00_safe
-//   starpm.c → eraStarpm_safe
-//   starpv.c → eraStarpv_safe
+//   starpm.c → eraStarpm_safe, eraStarpmCov_safe
+//   starpv.c → eraStarpv_safe, eraStarpvCov_safe
 //   sxp.c    → eraSxp_safe
 //   sxpv.c   → eraSxpv_safe
 
@@ -20,11 +20,13 @@ use crate::G27_safe::{eraPvstar_safe, eraPvu_safe, eraPxp_safe};
 use crate::G29_safe::{eraS2c_safe, eraS2pv_safe, eraS2xpv_safe};
 use crate::G35_safe::eraZp_safe;
 use crate::G6_safe::eraBpn2xy_safe;
+use crate::G7_safe::eraC2s_safe;
 use crate::H1_safe::{
-    ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DC, ERFA_DJ00, ERFA_DJC, ERFA_DJY, ERFA_DR2AS,
+    ErfaError, StarpvStatus, ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DC, ERFA_DJ00, ERFA_DJC,
+    ERFA_DJY, ERFA_DR2AS,
 };
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 // G30/s06.c
@@ -526,7 +528,7 @@ pub fn eraStarpm_safe(
     ep1b: f64,
     ep2a: f64,
     ep2b: f64,
-) -> ErfaResult<((f64, f64, f64, f64, f64, f64), i32)> {
+) -> ErfaResult<((f64, f64, f64, f64, f64, f64), StarpvStatus)> {
     let (pv1, j1) = eraStarpv_safe(ra1, dec1, pmr1, pmd1, px1, rv1)?;
     let tl1 = eraPm_safe(&pv1[0])? / ERFA_DC;
     let dt = (ep2a - ep1a) + (ep2b - ep1b);
@@ -537,14 +539,52 @@ pub fn eraStarpm_safe(
     let v2 = eraPdp_safe(&pv[1], &pv[1])?;
     let c2mv2 = ERFA_DC * ERFA_DC - v2;
     if c2mv2 <= 0.0 {
-        return Ok(((0.0, 0.0, 0.0, 0.0, 0.0, 0.0), -1));
+        return Err(ErfaError::Superluminal);
     }
     let tl2 = (-rdv + (rdv * rdv + c2mv2 * r2).sqrt()) / c2mv2;
 
     let pv2 = eraPvu_safe(dt + (tl1 - tl2), &pv1)?;
-    let ((ra2, dec2, pmr2, pmd2, px2, rv2), j2) = eraPvstar_safe(&pv2)?;
-    let j = if j2 == 0 { j1 } else { -1 };
-    Ok(((ra2, dec2, pmr2, pmd2, px2, rv2), j))
+    let (ra2, dec2, pmr2, pmd2, px2, rv2) = eraPvstar_safe(&pv2)?;
+    Ok(((ra2, dec2, pmr2, pmd2, px2, rv2), j1))
+}
+
+// As eraStarpm_safe, also propagating the 6×6 covariance of (ra, dec,
+// pmr, pmd, px, rv) in the same units.  pmr is dRA/dt, so the Gaia
+// ra* and pmra* rows and columns must be divided by cos δ, after the
+// conversion from mas to these units.
+// The covariance goes through the Jacobian of uniform space motion
+// without light time (Hipparcos Vol. 1, §1.5.5), which differs from that
+// of the full model by terms of order v/c.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn eraStarpmCov_safe(
+    ra1: f64,
+    dec1: f64,
+    pmr1: f64,
+    pmd1: f64,
+    px1: f64,
+    rv1: f64,
+    cov1: &[[f64; 6]; 6],
+    ep1a: f64,
+    ep1b: f64,
+    ep2a: f64,
+    ep2b: f64,
+) -> ErfaResult<((f64, f64, f64, f64, f64, f64), [[f64; 6]; 6], StarpvStatus)> {
+    let (star2, j) = eraStarpm_safe(ra1, dec1, pmr1, pmd1, px1, rv1, ep1a, ep1b, ep2a, ep2b)?;
+
+    // Parameters → (b, v) at ep1, b += t v, (b, v) → parameters at ep2.
+    let t = ((ep2a - ep1a) + (ep2b - ep1b)) / ERFA_DJY;
+    let (jin, b, v) = star_cartesian_jacobian(ra1, dec1, pmr1, pmd1, px1, rv1);
+    let mut jprop = [[0.0; 6]; 6];
+    for (i, row) in jprop.iter_mut().enumerate() {
+        row[i] = 1.0;
+        if i < 3 {
+            row[i + 3] = t;
+        }
+    }
+    let b2 = [b[0] + t * v[0], b[1] + t * v[1], b[2] + t * v[2]];
+    let jout = star_params_jacobian(&b2, &v)?;
+    let jac = mat6_mul(&jout, &mat6_mul(&jprop, &jin));
+    Ok((star2, congruence(&jac, cov1), j))
 }
 
 //----------------------------------------------------------------------
@@ -558,17 +598,17 @@ pub fn eraStarpv_safe(
     pmd: f64,
     px: f64,
     rv: f64,
-) -> ErfaResult<([[f64; 3]; 2], i32)> {
+) -> ErfaResult<([[f64; 3]; 2], StarpvStatus)> {
     const PXMIN: f64 = 1e-7;
     const VMAX: f64 = 0.5;
     const IMAX: i32 = 100;
 
-    let mut warn = 0;
+    let mut warn = StarpvStatus::default();
 
     let w = if px >= PXMIN {
         px
     } else {
-        warn += 1;
+        warn.distance_overridden = true;
         PXMIN
     };
     let r = ERFA_DR2AS / w;
@@ -583,7 +623,7 @@ pub fn eraStarpv_safe(
     let v = eraPm_safe(&pv[1])?;
     if v / ERFA_DC > VMAX {
         pv[1] = eraZp_safe();
-        warn += 2;
+        warn.excessive_speed = true;
     }
 
     let (_r_u, pu) = eraPn_safe(&pv[0])?;
@@ -628,7 +668,7 @@ pub fn eraStarpv_safe(
     }
 
     if i >= IMAX {
-        warn += 4;
+        warn.not_converged = true;
     }
 
     let ut = eraSxp_safe(d, &ust)?;
@@ -639,6 +679,148 @@ pub fn eraStarpv_safe(
     Ok((pv, warn))
 }
 
+// As eraStarpv_safe, also transforming the 6×6 covariance of (ra, dec,
+// pmr, pmd, px, rv) into that of the pv-vector (au, au/day).  The
+// Jacobian omits the relativistic velocity corrections.
+#[allow(clippy::type_complexity)]
+pub fn eraStarpvCov_safe(
+    ra: f64,
+    dec: f64,
+    pmr: f64,
+    pmd: f64,
+    px: f64,
+    rv: f64,
+    cov: &[[f64; 6]; 6],
+) -> ErfaResult<([[f64; 3]; 2], [[f64; 6]; 6], StarpvStatus)> {
+    let (pv, j) = eraStarpv_safe(ra, dec, pmr, pmd, px, rv)?;
+    let (mut jac, _, _) = star_cartesian_jacobian(ra, dec, pmr, pmd, px, rv);
+    for row in jac.iter_mut().skip(3) {
+        for x in row.iter_mut() {
+            *x /= ERFA_DJY;
+        }
+    }
+    Ok((pv, congruence(&jac, cov), j))
+}
+
+// Smallest parallax used, as in eraStarpv (arcsec).
+const COV_PXMIN: f64 = 1e-7;
+
+// au/yr in km/s.
+const AUYR_KMS: f64 = ERFA_DAU / 1e3 / (ERFA_DJY * ERFA_DAYSEC);
+
+// Unit vectors p, q, r towards increasing RA, increasing Dec and the star.
+fn star_triad(ra: f64, dec: f64) -> ([f64; 3], [f64; 3], [f64; 3]) {
+    let (sa, ca) = ra.sin_cos();
+    let (sd, cd) = dec.sin_cos();
+    (
+        [-sa, ca, 0.0],
+        [-sd * ca, -sd * sa, cd],
+        [cd * ca, cd * sa, sd],
+    )
+}
+
+// Barycentric position b (au) and velocity v (au/yr) of a star, ignoring
+// light time and relativity, with the Jacobian of (b, v) in eraStarpm's
+// units; columns follow the argument order (ra, dec, pmr, pmd, px, rv).
+#[allow(clippy::type_complexity)]
+fn star_cartesian_jacobian(
+    ra: f64,
+    dec: f64,
+    pmr: f64,
+    pmd: f64,
+    px: f64,
+    rv: f64,
+) -> ([[f64; 6]; 6], [f64; 3], [f64; 3]) {
+    let (p, q, r) = star_triad(ra, dec);
+    let (sd, cd) = dec.sin_cos();
+    let w = px.max(COV_PXMIN) * ERFA_DAS2R;
+    let k = AUYR_KMS;
+
+    let mut jac = [[0.0; 6]; 6];
+    let mut b = [0.0; 3];
+    let mut v = [0.0; 3];
+    for i in 0..3 {
+        b[i] = r[i] / w;
+        v[i] = (p[i] * pmr * cd + q[i] * pmd) / w + r[i] * rv / k;
+
+        // Position.
+        jac[i][0] = cd * p[i] / w;
+        jac[i][1] = q[i] / w;
+        jac[i][4] = -r[i] / (w * w) * ERFA_DAS2R;
+
+        // Velocity.
+        jac[i + 3][0] =
+            (-(r[i] * cd - q[i] * sd) * pmr * cd - sd * p[i] * pmd) / w + cd * p[i] * rv / k;
+        jac[i + 3][1] = (-sd * p[i] * pmr - r[i] * pmd) / w + q[i] * rv / k;
+        jac[i + 3][2] = p[i] * cd / w;
+        jac[i + 3][3] = q[i] / w;
+        jac[i + 3][4] = -(p[i] * pmr * cd + q[i] * pmd) / (w * w) * ERFA_DAS2R;
+        jac[i + 3][5] = r[i] / k;
+    }
+    (jac, b, v)
+}
+
+// Jacobian of (ra, dec, pmr, pmd, px, rv) with respect to (b, v), the
+// inverse of star_cartesian_jacobian's mapping.
+fn star_params_jacobian(b: &[f64; 3], v: &[f64; 3]) -> ErfaResult<[[f64; 6]; 6]> {
+    let (ra, dec) = eraC2s_safe(b)?;
+    let (p, q, r) = star_triad(ra, dec);
+    let (sd, cd) = dec.sin_cos();
+    let w = 1.0 / eraPm_safe(b)?;
+    let k = AUYR_KMS;
+    let pv = eraPdp_safe(&p, v)?;
+    let qv = eraPdp_safe(&q, v)?;
+    let rv = eraPdp_safe(&r, v)?;
+
+    let mut jac = [[0.0; 6]; 6];
+    for i in 0..3 {
+        // Derivatives of RA, Dec and w = 1/|b| with respect to b.
+        let da = p[i] * w / cd;
+        let dd = q[i] * w;
+        let dw = -r[i] * w * w;
+
+        jac[0][i] = da;
+        jac[1][i] = dd;
+        jac[2][i] =
+            w * (-(cd * rv - sd * qv) * da) / cd + pv / cd * dw + w * pv * sd / (cd * cd) * dd;
+        jac[2][i + 3] = p[i] * w / cd;
+        jac[3][i] = qv * dw + w * (-sd * pv * da - rv * dd);
+        jac[3][i + 3] = q[i] * w;
+        jac[4][i] = dw / ERFA_DAS2R;
+        jac[5][i] = k * (pv * cd * da + qv * dd);
+        jac[5][i + 3] = k * r[i];
+    }
+    Ok(jac)
+}
+
+fn mat6_mul(a: &[[f64; 6]; 6], b: &[[f64; 6]; 6]) -> [[f64; 6]; 6] {
+    let mut c = [[0.0; 6]; 6];
+    for (ci, ai) in c.iter_mut().zip(a) {
+        for (j, cij) in ci.iter_mut().enumerate() {
+            *cij = (0..6).map(|k| ai[k] * b[k][j]).sum();
+        }
+    }
+    c
+}
+
+// J C Jᵀ, symmetrized.
+fn congruence(jac: &[[f64; 6]; 6], cov: &[[f64; 6]; 6]) -> [[f64; 6]; 6] {
+    let mut jt = [[0.0; 6]; 6];
+    for (i, row) in jt.iter_mut().enumerate() {
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = jac[j][i];
+        }
+    }
+    let c = mat6_mul(jac, &mat6_mul(cov, &jt));
+    let mut cs = c;
+    for (i, row) in cs.iter_mut().enumerate() {
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = 0.5 * (c[i][j] + c[j][i]);
+        }
+    }
+    cs
+}
+
 //----------------------------------------------------------------------
 // G30/sxp.c
 //----------------------------------------------------------------------
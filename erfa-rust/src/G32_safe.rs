@@ -1,7 +1,7 @@
 // G32
 //   taitt.c   → eraTaitt_safe
 //   taiut1.c  → eraTaiut1_safe
-//   taiutc.c  → eraTaiutc_safe
+//   taiutc.c  → eraTaiutc_safe, eraTaiutcTable_safe
 //   tcbtdb.c  → eraTcbtdb_safe
 //   tcgtt.c   → eraTcgtt_safe
 //   tdbtcb.c  → eraTdbtcb_safe
@@ -16,50 +16,57 @@
 //   tpxev.c   → eraTpxev_safe
 
 use crate::G1_safe::eraAnp_safe;
-use crate::G33_safe::eraUtctai_safe;
+use crate::G14_safe::LeapSecondTable;
+use crate::G33_safe::eraUtctaiTable_safe;
 use crate::H1_safe::{
-    ERFA_DAYSEC, ERFA_DJM0, ERFA_DJM77, ERFA_DS2R, ERFA_ELB, ERFA_ELG, ERFA_TDB0, ERFA_TTMTAI,
+    DatStatus, FieldStatus, TangentSolutions, TpxStatus, ERFA_DAYSEC, ERFA_DJM0, ERFA_DJM77,
+    ERFA_DS2R, ERFA_ELB, ERFA_ELG, ERFA_TDB0, ERFA_TTMTAI,
 };
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // TAI → TT (two-part JD)
-pub fn eraTaitt_safe(tai1: f64, tai2: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTaitt_safe(tai1: f64, tai2: f64) -> ErfaResult<(f64, f64)> {
     const DTAT: f64 = ERFA_TTMTAI / ERFA_DAYSEC;
     let (tt1, tt2) = if tai1.abs() > tai2.abs() {
         (tai1, tai2 + DTAT)
     } else {
         (tai1 + DTAT, tai2)
     };
-    Ok(((tt1, tt2), 0))
+    Ok((tt1, tt2))
 }
 
 // TAI → UT1 given dTA = UT1−TAI (s)
-pub fn eraTaiut1_safe(tai1: f64, tai2: f64, dta: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTaiut1_safe(tai1: f64, tai2: f64, dta: f64) -> ErfaResult<(f64, f64)> {
     let dtad = dta / ERFA_DAYSEC;
     let (ut11, ut12) = if tai1.abs() > tai2.abs() {
         (tai1, tai2 + dtad)
     } else {
         (tai1 + dtad, tai2)
     };
-    Ok(((ut11, ut12), 0))
+    Ok((ut11, ut12))
 }
 
 // TAI → UTC using iteration with UTC→TAI
-pub fn eraTaiutc_safe(tai1: f64, tai2: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTaiutc_safe(tai1: f64, tai2: f64) -> ErfaResult<((f64, f64), DatStatus)> {
+    eraTaiutcTable_safe(&LeapSecondTable::current()?, tai1, tai2)
+}
+
+// As eraTaiutc_safe, with leap seconds taken from the given table.
+pub fn eraTaiutcTable_safe(
+    table: &LeapSecondTable,
+    tai1: f64,
+    tai2: f64,
+) -> ErfaResult<((f64, f64), DatStatus)> {
     let big1 = tai1.abs() >= tai2.abs();
     let (a1, a2) = if big1 { (tai1, tai2) } else { (tai2, tai1) };
 
     let (u1, mut u2) = (a1, a2);
-    let mut j: i32 = 0;
+    let mut j = DatStatus::Ok;
 
     for _ in 0..3 {
-        let ((g1, g2), jj) = eraUtctai_safe(u1, u2)?;
+        let ((g1, g2), jj) = eraUtctaiTable_safe(table, u1, u2)?;
         j = jj;
-        if j < 0 {
-            let (utc1, utc2) = if big1 { (u1, u2) } else { (u2, u1) };
-            return Ok(((utc1, utc2), j));
-        }
         u2 += a1 - g1;
         u2 += a2 - g2;
     }
@@ -69,7 +76,7 @@ pub fn eraTaiutc_safe(tai1: f64, tai2: f64) -> ErfaResult<((f64, f64), i32)> {
 }
 
 // TCB → TDB (two-part JD)
-pub fn eraTcbtdb_safe(tcb1: f64, tcb2: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTcbtdb_safe(tcb1: f64, tcb2: f64) -> ErfaResult<(f64, f64)> {
     const T77TD: f64 = ERFA_DJM0 + ERFA_DJM77;
     const T77TF: f64 = ERFA_TTMTAI / ERFA_DAYSEC;
     const TDB0: f64 = ERFA_TDB0 / ERFA_DAYSEC;
@@ -81,22 +88,22 @@ pub fn eraTcbtdb_safe(tcb1: f64, tcb2: f64) -> ErfaResult<((f64, f64), i32)> {
         let d = tcb2 - T77TD;
         (tcb1 + TDB0 - (d + (tcb1 - T77TF)) * ERFA_ELB, tcb2)
     };
-    Ok(((tdb1, tdb2), 0))
+    Ok((tdb1, tdb2))
 }
 
 // TCG → TT (two-part JD)
-pub fn eraTcgtt_safe(tcg1: f64, tcg2: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTcgtt_safe(tcg1: f64, tcg2: f64) -> ErfaResult<(f64, f64)> {
     const T77T: f64 = ERFA_DJM77 + ERFA_TTMTAI / ERFA_DAYSEC;
     let (tt1, tt2) = if tcg1.abs() > tcg2.abs() {
         (tcg1, tcg2 - ((tcg1 - ERFA_DJM0) + (tcg2 - T77T)) * ERFA_ELG)
     } else {
         (tcg1 - ((tcg2 - ERFA_DJM0) + (tcg1 - T77T)) * ERFA_ELG, tcg2)
     };
-    Ok(((tt1, tt2), 0))
+    Ok((tt1, tt2))
 }
 
 // TDB → TCB (two-part JD)
-pub fn eraTdbtcb_safe(tdb1: f64, tdb2: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTdbtcb_safe(tdb1: f64, tdb2: f64) -> ErfaResult<(f64, f64)> {
     const T77TD: f64 = ERFA_DJM0 + ERFA_DJM77;
     const T77TF: f64 = ERFA_TTMTAI / ERFA_DAYSEC;
     const TDB0: f64 = ERFA_TDB0 / ERFA_DAYSEC;
@@ -111,52 +118,62 @@ pub fn eraTdbtcb_safe(tdb1: f64, tdb2: f64) -> ErfaResult<((f64, f64), i32)> {
         let f = tdb1 - TDB0;
         (f - (d - (f - T77TF)) * ELBB, tdb2)
     };
-    Ok(((tcb1, tcb2), 0))
+    Ok((tcb1, tcb2))
 }
 
 // TDB → TT using supplied ΔT_R = TDB−TT (s)
-pub fn eraTdbtt_safe(tdb1: f64, tdb2: f64, dtr: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTdbtt_safe(tdb1: f64, tdb2: f64, dtr: f64) -> ErfaResult<(f64, f64)> {
     let dtrd = dtr / ERFA_DAYSEC;
     let (tt1, tt2) = if tdb1.abs() > tdb2.abs() {
         (tdb1, tdb2 - dtrd)
     } else {
         (tdb1 - dtrd, tdb2)
     };
-    Ok(((tt1, tt2), 0))
+    Ok((tt1, tt2))
 }
 
-// HMS → radians; j=0 OK, 1 bad hour, 2 bad minute, 3 bad second
-pub fn eraTf2a_safe(s: char, ihour: i32, imin: i32, sec: f64) -> ErfaResult<(f64, i32)> {
+// HMS → radians; status flags the first out-of-range field
+pub fn eraTf2a_safe(
+    s: char,
+    ihour: i32,
+    imin: i32,
+    sec: f64,
+) -> ErfaResult<(f64, FieldStatus)> {
     let sign = if s == '-' { -1.0 } else { 1.0 };
     let rad =
         sign * (60.0 * (60.0 * (ihour.abs() as f64) + (imin.abs() as f64)) + sec.abs()) * ERFA_DS2R;
     if ihour < 0 || ihour > 23 {
-        return Ok((rad, 1));
+        return Ok((rad, FieldStatus::BadDegOrHour));
     }
     if imin < 0 || imin > 59 {
-        return Ok((rad, 2));
+        return Ok((rad, FieldStatus::BadMinute));
     }
     if sec < 0.0 || sec >= 60.0 {
-        return Ok((rad, 3));
+        return Ok((rad, FieldStatus::BadSecond));
     }
-    Ok((rad, 0))
+    Ok((rad, FieldStatus::Ok))
 }
 
-// HMS → days; j=0 OK, 1 bad hour, 2 bad minute, 3 bad second
-pub fn eraTf2d_safe(s: char, ihour: i32, imin: i32, sec: f64) -> ErfaResult<(f64, i32)> {
+// HMS → days; status flags the first out-of-range field
+pub fn eraTf2d_safe(
+    s: char,
+    ihour: i32,
+    imin: i32,
+    sec: f64,
+) -> ErfaResult<(f64, FieldStatus)> {
     let sign = if s == '-' { -1.0 } else { 1.0 };
     let days = sign * (60.0 * (60.0 * (ihour.abs() as f64) + (imin.abs() as f64)) + sec.abs())
         / ERFA_DAYSEC;
     if ihour < 0 || ihour > 23 {
-        return Ok((days, 1));
+        return Ok((days, FieldStatus::BadDegOrHour));
     }
     if imin < 0 || imin > 59 {
-        return Ok((days, 2));
+        return Ok((days, FieldStatus::BadMinute));
     }
     if sec < 0.0 || sec >= 60.0 {
-        return Ok((days, 3));
+        return Ok((days, FieldStatus::BadSecond));
     }
-    Ok((days, 0))
+    Ok((days, FieldStatus::Ok))
 }
 
 // Tangent-point from star (spherical); returns two solutions and status
@@ -165,7 +182,7 @@ pub fn eraTpors_safe(
     eta: f64,
     a: f64,
     b: f64,
-) -> ErfaResult<((f64, f64), (f64, f64), i32)> {
+) -> ErfaResult<((f64, f64), (f64, f64), TangentSolutions)> {
     let xi2 = xi * xi;
     let r = (1.0 + xi2 + eta * eta).sqrt();
     let sb = b.sin();
@@ -190,15 +207,23 @@ pub fn eraTpors_safe(
         let a02 = eraAnp_safe(a - xi.atan2(w2n))?;
         let b02 = s.atan2(c);
 
-        let j = if rsb.abs() < 1.0 { 1 } else { 2 };
+        let j = if rsb.abs() < 1.0 {
+            TangentSolutions::One
+        } else {
+            TangentSolutions::Two
+        };
         Ok(((a01, b01), (a02, b02), j))
     } else {
-        Ok(((0.0, 0.0), (0.0, 0.0), 0))
+        Ok(((0.0, 0.0), (0.0, 0.0), TangentSolutions::None))
     }
 }
 
 // Tangent-point from star (vector); returns two unit vectors and status
-pub fn eraTporv_safe(xi: f64, eta: f64, v: &[f64; 3]) -> ErfaResult<([f64; 3], [f64; 3], i32)> {
+pub fn eraTporv_safe(
+    xi: f64,
+    eta: f64,
+    v: &[f64; 3],
+) -> ErfaResult<([f64; 3], [f64; 3], TangentSolutions)> {
     let x = v[0];
     let y = v[1];
     let z = v[2];
@@ -228,10 +253,14 @@ pub fn eraTporv_safe(xi: f64, eta: f64, v: &[f64; 3]) -> ErfaResult<([f64; 3], [
             (rsb - eta * w) / eta2p1,
         ];
 
-        let j = if rsb.abs() < 1.0 { 1 } else { 2 };
+        let j = if rsb.abs() < 1.0 {
+            TangentSolutions::One
+        } else {
+            TangentSolutions::Two
+        };
         Ok((v01, v02, j))
     } else {
-        Ok(([0.0; 3], [0.0; 3], 0))
+        Ok(([0.0; 3], [0.0; 3], TangentSolutions::None))
     }
 }
 
@@ -266,7 +295,7 @@ pub fn eraTpstv_safe(xi: f64, eta: f64, v0: &[f64; 3]) -> ErfaResult<[f64; 3]> {
 }
 
 // Solve for (xi,eta) given two sets of spherical coordinates
-pub fn eraTpxes_safe(a: f64, b: f64, a0: f64, b0: f64) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTpxes_safe(a: f64, b: f64, a0: f64, b0: f64) -> ErfaResult<((f64, f64), TpxStatus)> {
     const TINY: f64 = 1e-6;
 
     let sb0 = b0.sin();
@@ -280,15 +309,15 @@ pub fn eraTpxes_safe(a: f64, b: f64, a0: f64, b0: f64) -> ErfaResult<((f64, f64)
     let mut d = sb * sb0 + cb * cb0 * cda;
 
     let j = if d > TINY {
-        0
+        TpxStatus::Ok
     } else if d >= 0.0 {
         d = TINY;
-        1
+        TpxStatus::StarTooFarFromAxis
     } else if d > -TINY {
         d = -TINY;
-        2
+        TpxStatus::AntistarOnPlane
     } else {
-        3
+        TpxStatus::AntistarTooFarFromAxis
     };
 
     let xi = cb * sda / d;
@@ -297,7 +326,7 @@ pub fn eraTpxes_safe(a: f64, b: f64, a0: f64, b0: f64) -> ErfaResult<((f64, f64)
 }
 
 // Solve for (xi,eta) given two direction-cosine vectors
-pub fn eraTpxev_safe(v: &[f64; 3], v0: &[f64; 3]) -> ErfaResult<((f64, f64), i32)> {
+pub fn eraTpxev_safe(v: &[f64; 3], v0: &[f64; 3]) -> ErfaResult<((f64, f64), TpxStatus)> {
     const TINY: f64 = 1e-6;
 
     let x = v[0];
@@ -318,15 +347,15 @@ pub fn eraTpxev_safe(v: &[f64; 3], v0: &[f64; 3]) -> ErfaResult<((f64, f64), i32
     let mut d = w + z * z0;
 
     let j = if d > TINY {
-        0
+        TpxStatus::Ok
     } else if d >= 0.0 {
         d = TINY;
-        1
+        TpxStatus::StarTooFarFromAxis
     } else if d > -TINY {
         d = -TINY;
-        2
+        TpxStatus::AntistarOnPlane
     } else {
-        3
+        TpxStatus::AntistarTooFarFromAxis
     };
 
     d *= r;
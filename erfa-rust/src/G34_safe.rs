@@ -8,7 +8,7 @@ use crate::G15_safe::{
 };
 use crate::H1_safe::{ERFA_DAS2R, ERFA_DJ00, ERFA_DJC};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 #[path = "data/G34_safe/MFALS.rs"]
 mod mfals_mod;
@@ -11,7 +11,7 @@ use crate::G29_safe::eraS00_safe;
 use crate::G30_safe::eraS06_safe;
 use crate::G6_safe::eraBpn2xy_safe;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 
 //  eraXys00a_safe    CIP X,Y and CIO locator s using IAU 2000A model
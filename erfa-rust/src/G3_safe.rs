@@ -1,11 +1,11 @@
 // G3
 //   aper13.c  → eraAper13_safe
-//   apio.c    → eraApio_safe
+//   apio.c    → eraApio_safe, eraApioJac_safe
 //   apio13.c  → eraApio13_safe
 //   atcc13.c  → eraAtcc13_safe
 //   atccq.c   → eraAtccq_safe
 
-
+use crate::dual::{jacobian, Dual};
 use crate::G14_safe::eraEra00_safe;
 use crate::G19_safe::eraIr_safe;
 use crate::G1_safe::{eraAnp_safe, eraAnpm_safe, eraApci13_safe};
@@ -17,9 +17,9 @@ use crate::G30_safe::eraSp00_safe;
 use crate::G32_safe::eraTaitt_safe;
 use crate::G33_safe::{eraUtctai_safe, eraUtcut1_safe};
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::{eraASTROM, ERFA_CMPS};
+use crate::H1_safe::{eraASTROM, DatStatus, ERFA_CMPS};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // G3/aper13.c
 // Update only the ERA element inside an existing eraASTROM.
@@ -94,8 +94,61 @@ pub fn eraApio_safe(
     Ok(())
 }
 
+// As eraApio_safe, also returning the partial derivatives of astrom's
+// (eral, xpl, ypl, phi) with respect to (elong, phi, xp, yp, theta), for
+// use with eraAtioqJac_safe and eraAtoiqJac_safe.  eraApco_safe sets the
+// same quantities in the same way.  For DUT1 in seconds multiply the
+// theta column by 2π × 1.00273781191135448 / 86400.  diurab is taken as
+// fixed; its dependence on the site is below 1e-6 of the above.
+#[allow(clippy::too_many_arguments)]
+pub fn eraApioJac_safe(
+    sp: f64,
+    theta: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    refa: f64,
+    refb: f64,
+    astrom: &mut eraASTROM,
+) -> ErfaResult<[[f64; 5]; 4]> {
+    eraApio_safe(sp, theta, elong, phi, hm, xp, yp, refa, refb, astrom)?;
+
+    // The rotation of eraApio, on dual numbers.
+    let [elong, phi, xp, yp, theta] = Dual::variables([elong, phi, xp, yp, theta]);
+    let one = Dual::constant(1.0);
+    let zero = Dual::constant(0.0);
+    let mut r = [[one, zero, zero], [zero, one, zero], [zero, zero, one]];
+    r = rotate_dual(2, theta + sp, &r);
+    r = rotate_dual(1, -xp, &r);
+    r = rotate_dual(0, -yp, &r);
+    r = rotate_dual(2, elong, &r);
+
+    let eral = r[0][1].atan2(r[0][0]);
+    let xpl = r[0][2].atan2((r[0][0] * r[0][0] + r[0][1] * r[0][1]).sqrt());
+    let ypl = -r[1][2].atan2(r[2][2]);
+    Ok(jacobian(&[eral, xpl, ypl, phi]))
+}
+
+// Rotation of r about axis x, y or z (0, 1, 2), as eraRx/eraRy/eraRz.
+fn rotate_dual(axis: usize, angle: Dual<5>, r: &[[Dual<5>; 3]; 3]) -> [[Dual<5>; 3]; 3] {
+    let (s, c) = angle.sin_cos();
+    let (i, j) = match axis {
+        0 => (1, 2),
+        1 => (2, 0),
+        _ => (0, 1),
+    };
+    let mut out = *r;
+    for k in 0..3 {
+        out[i][k] = c * r[i][k] + s * r[j][k];
+        out[j][k] = c * r[j][k] - s * r[i][k];
+    }
+    out
+}
+
 // G3/apio13.c
-// Derive CIRS-observed parameters from UTC/site/weather; returns status 0 or +1.
+// Derive CIRS-observed parameters from UTC/site/weather; returns the UT1 status.
 pub fn eraApio13_safe(
     utc1: f64,
     utc2: f64,
@@ -110,21 +163,15 @@ pub fn eraApio13_safe(
     rh: f64,
     wl: f64,
     astrom: &mut eraASTROM,
-) -> ErfaResult<i32> {
+) -> ErfaResult<DatStatus> {
     // UTC → TAI.
-    let ((tai1, tai2), j_utctai) = eraUtctai_safe(utc1, utc2)?;
-    if j_utctai < 0 {
-        return Err(());
-    }
+    let ((tai1, tai2), _j_utctai) = eraUtctai_safe(utc1, utc2)?;
 
     // TAI → TT.
-    let ((tt1, tt2), _j_taitt) = eraTaitt_safe(tai1, tai2)?;
+    let (tt1, tt2) = eraTaitt_safe(tai1, tai2)?;
 
     // UTC → UT1 (with DUT1).
     let ((ut11, ut12), j_utcut1) = eraUtcut1_safe(utc1, utc2, dut1)?;
-    if j_utcut1 < 0 {
-        return Err(());
-    }
 
     // TIO locator s'.
     let sp = eraSp00_safe(tt1, tt2)?;
@@ -138,7 +185,7 @@ pub fn eraApio13_safe(
     // Populate astrometry parameters.
     eraApio_safe(sp, theta, elong, phi, hm, xp, yp, refa, refb, astrom)?;
 
-    // Return the UT1 conversion warning status (0 or +1).
+    // Return the UT1 conversion warning status.
     Ok(j_utcut1)
 }
 
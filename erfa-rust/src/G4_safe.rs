@@ -1,6 +1,6 @@
 // G4
 //   atci13.c   → eraAtci13_safe
-//   atciq.c    → eraAtciq_safe
+//   atciq.c    → eraAtciq_safe, eraAtciqJac_safe
 //   atciqn.c   → eraAtciqn_safe
 //   atciqz.c   → eraAtciqz_safe
 //   atco13.c   → eraAtco13_safe
@@ -8,21 +8,25 @@
 //   aticq.c    → eraAticq_safe
 //   aticqn.c   → eraAticqn_safe
 //   atio13.c   → eraAtio13_safe
-//   atioq.c    → eraAtioq_safe
+//   atioq.c    → eraAtioq_safe, eraAtioqRef_safe, eraAtioqJac_safe
 
+use crate::dual::{c2s, jacobian, pdp, pn, pxp, rxp, s2c, Dual};
 use crate::G1_safe::{eraAb_safe, eraAnp_safe, eraApci13_safe};
 use crate::G20_safe::{eraLdn_safe, eraLdsun_safe};
 use crate::G25_safe::eraPmpx_safe;
-use crate::G28_safe::eraRxp_safe;
+use crate::G28_safe::{eraRxp_safe, Refraction};
 use crate::G29_safe::eraS2c_safe;
 use crate::G2_safe::eraApco13_safe;
 use crate::G33_safe::eraTrxp_safe;
 use crate::G35_safe::eraZp_safe;
 use crate::G3_safe::eraApio13_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::{eraASTROM, eraLDBODY};
+use crate::H1_safe::{
+    eraASTROM, eraLDBODY, DatStatus, ERFA_AULT, ERFA_DAS2R, ERFA_DAU, ERFA_DAYSEC, ERFA_DJM,
+    ERFA_DJY, ERFA_SRS,
+};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 /*----------------------------------------------------------------------
  *  atci13.c  → eraAtci13_safe
@@ -79,6 +83,79 @@ pub fn eraAtciq_safe(
     Ok((ri, di))
 }
 
+// As eraAtciq_safe, also returning the partial derivatives of (ri, di)
+// with respect to (rc, dc, pr, pd, px, rv), one row each.
+#[allow(clippy::type_complexity)]
+pub fn eraAtciqJac_safe(
+    rc: f64,
+    dc: f64,
+    pr: f64,
+    pd: f64,
+    px: f64,
+    rv: f64,
+    astrom: &eraASTROM,
+) -> ErfaResult<((f64, f64), [[f64; 6]; 2])> {
+    let (ri, di) = eraAtciq_safe(rc, dc, pr, pd, px, rv, astrom)?;
+    let a = astrom;
+
+    // The steps of eraAtciq, on dual numbers.
+    let pco = pmpx_dual(Dual::variables([rc, dc, pr, pd, px, rv]), a.pmt, &a.eb);
+    let pnat = ldsun_dual(&pco, &a.eh, a.em);
+    let ppr = ab_dual(&pnat, &a.v, a.em, a.bm1);
+    let (w, d) = c2s(&rxp(&a.bpn, &ppr));
+    Ok(((ri, di), jacobian(&[w, d])))
+}
+
+// eraPmpx_safe on dual numbers (rc, dc, pr, pd, px, rv).
+fn pmpx_dual(x: [Dual<6>; 6], pmt: f64, pob: &[f64; 3]) -> [Dual<6>; 3] {
+    const VF: f64 = ERFA_DAYSEC * ERFA_DJM / ERFA_DAU;
+    const AULTY: f64 = ERFA_AULT / ERFA_DAYSEC / ERFA_DJY;
+    let [rc, dc, pr, pd, px, rv] = x;
+    let pob = pob.map(Dual::constant);
+
+    let (sr, cr) = rc.sin_cos();
+    let (sd, cd) = dc.sin_cos();
+    let p = [cr * cd, sr * cd, sd];
+    let dt = pdp(&p, &pob) * AULTY + pmt;
+    let pxr = px * ERFA_DAS2R;
+    let w = rv * pxr * VF;
+    let pdz = pd * p[2];
+    let pm = [
+        -pr * p[1] - pdz * cr + w * p[0],
+        pr * p[0] - pdz * sr + w * p[1],
+        pd * cd + w * p[2],
+    ];
+    pn(&std::array::from_fn(|i| p[i] + dt * pm[i] - pxr * pob[i]))
+}
+
+// eraLdsun_safe on dual numbers.
+fn ldsun_dual(p: &[Dual<6>; 3], e: &[f64; 3], em: f64) -> [Dual<6>; 3] {
+    let em2 = em * em;
+    let dlim = 1.0e-6 / if em2 < 1.0 { 1.0 } else { em2 };
+    let e = e.map(Dual::constant);
+    let qpe = std::array::from_fn(|i| p[i] + e[i]);
+    let qdqpe = pdp(p, &qpe);
+    let w = ERFA_SRS
+        / em
+        / if qdqpe.v > dlim {
+            qdqpe
+        } else {
+            Dual::constant(dlim)
+        };
+    let peq = pxp(p, &pxp(&e, p));
+    std::array::from_fn(|i| p[i] + w * peq[i])
+}
+
+// eraAb_safe on dual numbers.
+fn ab_dual(pnat: &[Dual<6>; 3], v: &[f64; 3], s: f64, bm1: f64) -> [Dual<6>; 3] {
+    let pdv = pdp(pnat, &v.map(Dual::constant));
+    let w1 = 1.0 + pdv / (1.0 + bm1);
+    let w2 = ERFA_SRS / s;
+    pn(&std::array::from_fn(|i| {
+        pnat[i] * bm1 + w1 * v[i] + w2 * (v[i] - pdv * pnat[i])
+    }))
+}
+
 /*----------------------------------------------------------------------
  *  atciqn.c  → eraAtciqn_safe
  *--------------------------------------------------------------------*/
@@ -162,7 +239,7 @@ pub fn eraAtco13_safe(
     tc: f64,
     rh: f64,
     wl: f64,
-) -> ErfaResult<(f64, f64, f64, f64, f64, f64, i32)> {
+) -> ErfaResult<(f64, f64, f64, f64, f64, f64, DatStatus)> {
     let mut astrom = eraASTROM::default();
 
     // Site-dependent astrometry params from UTC.
@@ -395,7 +472,7 @@ pub fn eraAtio13_safe(
     tc: f64,
     rh: f64,
     wl: f64,
-) -> ErfaResult<(f64, f64, f64, f64, f64, i32)> {
+) -> ErfaResult<(f64, f64, f64, f64, f64, DatStatus)> {
     let mut astrom = eraASTROM::default();
     let j = eraApio13_safe(
         utc1,
@@ -502,3 +579,104 @@ pub fn eraAtioq_safe(
     let rob = eraAnp_safe(raobs)?;
     Ok((aob, zob, hob, dob, rob))
 }
+
+// As eraAtioq_safe with refraction from the given model in place of
+// astrom's refa/refb, and no floor on the elevation.
+pub fn eraAtioqRef_safe<R: Refraction + ?Sized>(
+    ri: f64,
+    di: f64,
+    astrom: &eraASTROM,
+    refraction: &R,
+) -> ErfaResult<(f64, f64, f64, f64, f64)> {
+    // Topocentric azimuth and zenith distance, refraction off.
+    let mut a = *astrom;
+    a.refa = 0.0;
+    a.refb = 0.0;
+    let (aob, zt, _, _, _) = eraAtioq_safe(ri, di, &a)?;
+
+    // Observed zenith distance.
+    let zob = zt - refraction.for_topocentric(zt);
+
+    // Az,ZD (S=0,E=90) → Cartesian -HA,Dec.
+    let (sz, cz) = zob.sin_cos();
+    let xaeo = -aob.cos() * sz;
+    let yaeo = aob.sin() * sz;
+    let v = [
+        a.sphi * xaeo + a.cphi * cz,
+        yaeo,
+        -a.cphi * xaeo + a.sphi * cz,
+    ];
+    let (hmobs, dob) = eraC2s_safe(&v)?;
+    let rob = eraAnp_safe(a.eral + hmobs)?;
+    Ok((aob, zob, -hmobs, dob, rob))
+}
+
+// As eraAtioq_safe, also returning the partial derivatives of (aob, zob,
+// hob, dob, rob) with respect to (ri, di, eral, xpl, ypl, phi), one row
+// each; eraApioJac_safe carries the last four back to the site.  refa,
+// refb and diurab are taken as fixed.
+#[allow(clippy::type_complexity)]
+pub fn eraAtioqJac_safe(
+    ri: f64,
+    di: f64,
+    astrom: &eraASTROM,
+) -> ErfaResult<((f64, f64, f64, f64, f64), [[f64; 6]; 5])> {
+    const CELMIN: f64 = 1e-6;
+    const SELMIN: f64 = 0.05;
+
+    let obs = eraAtioq_safe(ri, di, astrom)?;
+    let a = astrom;
+
+    // The steps of eraAtioq, on dual numbers.
+    let [ri, di, eral, xpl, ypl, phi] =
+        Dual::variables([ri, di, a.eral, a.xpl, a.ypl, a.sphi.atan2(a.cphi)]);
+    let [x, y, z] = s2c(ri - eral, di);
+
+    let (sx, cx) = xpl.sin_cos();
+    let (sy, cy) = ypl.sin_cos();
+    let xhd = cx * x + sx * z;
+    let yhd = sx * sy * x + cy * y - cx * sy * z;
+    let zhd = -sx * cy * x + sy * y + cx * cy * z;
+
+    let f = 1.0 - yhd * a.diurab;
+    let xhdt = f * xhd;
+    let yhdt = f * (yhd + a.diurab);
+    let zhdt = f * zhd;
+
+    let (sphi, cphi) = phi.sin_cos();
+    let xaet = sphi * xhdt - cphi * zhdt;
+    let yaet = yhdt;
+    let zaet = cphi * xhdt + sphi * zhdt;
+
+    let azobs = if xaet.v != 0.0 || yaet.v != 0.0 {
+        yaet.atan2(-xaet)
+    } else {
+        Dual::constant(0.0)
+    };
+
+    let r = (xaet * xaet + yaet * yaet).sqrt();
+    let r = if r.v > CELMIN {
+        r
+    } else {
+        Dual::constant(CELMIN)
+    };
+    let z = if zaet.v > SELMIN {
+        zaet
+    } else {
+        Dual::constant(SELMIN)
+    };
+    let tz = r / z;
+    let w = tz * tz * a.refb;
+    let del = (w + a.refa) * tz / (1.0 + (w * 3.0 + a.refa) / (z * z));
+
+    let cosdel = 1.0 - del * del / 2.0;
+    let f2 = cosdel - del * z / r;
+    let xaeo = xaet * f2;
+    let yaeo = yaet * f2;
+    let zaeo = cosdel * zaet + del * r;
+    let zdobs = (xaeo * xaeo + yaeo * yaeo).sqrt().atan2(zaeo);
+
+    let v = [sphi * xaeo + cphi * zaeo, yaeo, -cphi * xaeo + sphi * zaeo];
+    let (hmobs, dcobs) = c2s(&v);
+    Ok((obs, jacobian(&[azobs, zdobs, -hmobs, dcobs, eral + hmobs])))
+}
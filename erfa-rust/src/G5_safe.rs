@@ -1,18 +1,19 @@
 // G5
 //   atoc13.c  → eraAtoc13_safe
 //   atoi13.c  → eraAtoi13_safe
-//   atoiq.c   → eraAtoiq_safe
-
+//   atoiq.c   → eraAtoiq_safe, eraAtoiqRef_safe, eraAtoiqJac_safe
 
+use crate::dual::{c2s, jacobian, s2c, Dual};
 use crate::G1_safe::eraAnp_safe;
+use crate::G28_safe::Refraction;
 use crate::G29_safe::eraS2c_safe;
 use crate::G2_safe::eraApco13_safe;
 use crate::G3_safe::eraApio13_safe;
 use crate::G4_safe::eraAticq_safe;
 use crate::G7_safe::eraC2s_safe;
-use crate::H1_safe::eraASTROM;
+use crate::H1_safe::{eraASTROM, DatStatus};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 /*----------------------------------------------------------------------
  *  G5/atoc13.c  →  eraAtoc13_safe
@@ -21,7 +22,7 @@ pub type ErfaResult<T> = Result<T, ()>;
 /// Returns (rc, dc, eo, j) where:
 /// - rc, dc: ICRS astrometric RA,Dec (radians)
 /// - eo: equation of the origins (radians)
-/// - j: UT1 conversion status from eraApco13 (0 or +1)
+/// - j: UT1 conversion status from eraApco13 (dubious year warning)
 ///
 /// NOTE: The original C function eraAtoc13 computes but discards the
 /// equation of the origins (eo) value. This safe Rust version corrects
@@ -49,7 +50,7 @@ pub fn eraAtoc13_safe(
     tc: f64,
     rh: f64,
     wl: f64,
-) -> ErfaResult<(f64, f64, f64, i32)> {
+) -> ErfaResult<(f64, f64, f64, DatStatus)> {
     // Star-independent astrometry parameters
     let mut astrom = eraASTROM::default();
 
@@ -100,7 +101,7 @@ pub fn eraAtoi13_safe(
     tc: f64,
     rh: f64,
     wl: f64,
-) -> ErfaResult<(f64, f64, i32)> {
+) -> ErfaResult<(f64, f64, DatStatus)> {
     // Star-independent astrometry parameters
     let mut astrom = eraASTROM::default();
 
@@ -237,3 +238,124 @@ pub fn eraAtoiq_safe(
 
     Ok((ri, di))
 }
+
+// As eraAtoiq_safe with refraction from the given model in place of
+// astrom's refa/refb, and no floor on the elevation.
+pub fn eraAtoiqRef_safe<R: Refraction + ?Sized>(
+    type_: &str,
+    ob1: f64,
+    ob2: f64,
+    astrom: &eraASTROM,
+    refraction: &R,
+) -> ErfaResult<(f64, f64)> {
+    let mut a = *astrom;
+    a.refa = 0.0;
+    a.refb = 0.0;
+
+    // Observed azimuth (N=0,E=90) and zenith distance.
+    let (az, zdo) = match type_.as_bytes().first() {
+        Some(b'r' | b'R' | b'h' | b'H') => {
+            let ha = if matches!(type_.as_bytes()[0], b'r' | b'R') {
+                a.eral - ob1
+            } else {
+                ob1
+            };
+            let v = eraS2c_safe(-ha, ob2)?;
+            let xaeo = a.sphi * v[0] - a.cphi * v[2];
+            let yaeo = v[1];
+            let zaeo = a.cphi * v[0] + a.sphi * v[2];
+            let az = if xaeo != 0.0 || yaeo != 0.0 {
+                yaeo.atan2(-xaeo)
+            } else {
+                0.0
+            };
+            (az, (xaeo * xaeo + yaeo * yaeo).sqrt().atan2(zaeo))
+        }
+        _ => (ob1, ob2),
+    };
+
+    // Topocentric zenith distance, then CIRS with refraction off.
+    let zdt = zdo + refraction.for_observed(zdo);
+    eraAtoiq_safe("A", az, zdt, &a)
+}
+
+// As eraAtoiq_safe, also returning the partial derivatives of (ri, di)
+// with respect to (ob1, ob2, eral, xpl, ypl, phi), one row each;
+// eraApioJac_safe carries the last four back to the site.  refa, refb
+// and diurab are taken as fixed.
+#[allow(clippy::type_complexity)]
+pub fn eraAtoiqJac_safe(
+    type_: &str,
+    ob1: f64,
+    ob2: f64,
+    astrom: &eraASTROM,
+) -> ErfaResult<((f64, f64), [[f64; 6]; 2])> {
+    const SELMIN: f64 = 0.05;
+
+    let (ri, di) = eraAtoiq_safe(type_, ob1, ob2, astrom)?;
+    let a = astrom;
+
+    // The steps of eraAtoiq, on dual numbers.
+    let [c1, c2, eral, xpl, ypl, phi] =
+        Dual::variables([ob1, ob2, a.eral, a.xpl, a.ypl, a.sphi.atan2(a.cphi)]);
+    let (sphi, cphi) = phi.sin_cos();
+    let (xaeo, yaeo, zaeo) = match type_.as_bytes().first() {
+        Some(b'r' | b'R' | b'h' | b'H') => {
+            let ha = if matches!(type_.as_bytes()[0], b'r' | b'R') {
+                eral - c1
+            } else {
+                c1
+            };
+            let [xmhdo, ymhdo, zmhdo] = s2c(-ha, c2);
+            (
+                sphi * xmhdo - cphi * zmhdo,
+                ymhdo,
+                cphi * xmhdo + sphi * zmhdo,
+            )
+        }
+        _ => {
+            let ce = c2.sin();
+            (-c1.cos() * ce, c1.sin() * ce, c2.cos())
+        }
+    };
+
+    let az = if xaeo.v != 0.0 || yaeo.v != 0.0 {
+        yaeo.atan2(xaeo)
+    } else {
+        Dual::constant(0.0)
+    };
+    let sz = (xaeo * xaeo + yaeo * yaeo).sqrt();
+    let zdo = sz.atan2(zaeo);
+
+    let tz = sz
+        / if zaeo.v > SELMIN {
+            zaeo
+        } else {
+            Dual::constant(SELMIN)
+        };
+    let zdt = zdo + (tz * tz * a.refb + a.refa) * tz;
+
+    let ce = zdt.sin();
+    let xaet = az.cos() * ce;
+    let yaet = az.sin() * ce;
+    let zaet = zdt.cos();
+
+    let xmhda = sphi * xaet + cphi * zaet;
+    let ymhda = yaet;
+    let zmhda = -cphi * xaet + sphi * zaet;
+
+    let f = 1.0 + ymhda * a.diurab;
+    let xhd = f * xmhda;
+    let yhd = f * (ymhda - a.diurab);
+    let zhd = f * zmhda;
+
+    let (sx, cx) = xpl.sin_cos();
+    let (sy, cy) = ypl.sin_cos();
+    let v = [
+        cx * xhd + sx * sy * yhd - sx * cy * zhd,
+        cy * yhd + sy * zhd,
+        sx * xhd - cx * sy * yhd + cx * cy * zhd,
+    ];
+    let (hma, d) = c2s(&v);
+    Ok(((ri, di), jacobian(&[eral + hma, d])))
+}
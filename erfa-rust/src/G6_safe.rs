@@ -15,7 +15,7 @@ use crate::G28_safe::{eraRx_safe, eraRxr_safe, eraRy_safe, eraRz_safe};
 use crate::G33_safe::eraTr_safe;
 use crate::G8_safe::eraCr_safe;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // eraBi00_safe → bi00.c
 // Frame-bias corrections (ICRS → J2000.0), radians; returns (dpsibi, depsbi, dra).
@@ -19,7 +19,7 @@ use crate::G30_safe::{eraS06_safe, eraSp00_safe};
 use crate::G6_safe::eraBpn2xy_safe;
 use crate::G8_safe::eraC2tcio_safe;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // Celestial-to-intermediate matrix, IAU 2000A; returns rc2i.
 pub fn eraC2i00a_safe(date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 3]> {
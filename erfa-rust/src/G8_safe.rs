@@ -8,7 +8,7 @@
 //   cpv.c     → eraCpv_safe
 //   cr.c      → eraCr_safe
 
-use crate::H1_safe::ERFA_DJM0;
+use crate::H1_safe::{ErfaError, ERFA_DJM0};
 
 use crate::G11_safe::eraEe00_safe;
 use crate::G14_safe::eraEra00_safe;
@@ -19,7 +19,7 @@ use crate::G28_safe::{eraRxr_safe, eraRz_safe};
 use crate::G30_safe::eraSp00_safe;
 use crate::G7_safe::eraC2ixy_safe;
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 //----------------------------------------------------------------------
 // G8/c2tcio.c → eraC2tcio_safe
@@ -134,17 +134,17 @@ pub fn eraC2txy_safe(
 // G8/cal2jd.c → eraCal2jd_safe
 //----------------------------------------------------------------------
 
-// Gregorian calendar to JD, split into (djm0, djm); j=0 OK, -1/-2/-3 for errors.
-pub fn eraCal2jd_safe(iy: i32, im: i32, id: i32) -> ErfaResult<((f64, f64), i32)> {
+// Gregorian calendar to JD, split into (djm0, djm); bad year/month/day are errors.
+pub fn eraCal2jd_safe(iy: i32, im: i32, id: i32) -> ErfaResult<(f64, f64)> {
     const IYMIN: i32 = -4799;
     const MTAB: [i32; 12] = [31, 28, 31, 30, 31, 31, 30, 31, 30, 31, 30, 31];
 
     // Validate year & month.
-    let mut j = 0;
     if iy < IYMIN {
-        j = -1;
-    } else if im < 1 || im > 12 {
-        j = -2;
+        return Err(ErfaError::BadYear);
+    }
+    if im < 1 || im > 12 {
+        return Err(ErfaError::BadMonth);
     }
 
     // Leap year test for February.
@@ -154,9 +154,9 @@ pub fn eraCal2jd_safe(iy: i32, im: i32, id: i32) -> ErfaResult<((f64, f64), i32)
         0
     };
 
-    // Validate day; for -3 we still compute JD per C behavior.
-    if j == 0 && (id < 1 || id > MTAB[(im - 1) as usize] + ly) {
-        j = -3;
+    // Validate day.
+    if id < 1 || id > MTAB[(im - 1) as usize] + ly {
+        return Err(ErfaError::BadDay);
     }
 
     // Compute JD parts (djm0, djm).
@@ -172,7 +172,7 @@ pub fn eraCal2jd_safe(iy: i32, im: i32, id: i32) -> ErfaResult<((f64, f64), i32)
         (djm0, djm)
     };
 
-    Ok(((djm0, djm), j))
+    Ok((djm0, djm))
 }
 
 //----------------------------------------------------------------------
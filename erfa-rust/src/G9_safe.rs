@@ -1,14 +1,14 @@
 // G9
 //   d2tf.c   → eraD2tf_safe
-//   d2dtf.c  → eraD2dtf_safe
-//   dat.c    → eraDat_safe
+//   d2dtf.c  → eraD2dtf_safe, eraD2dtfTable_safe
+//   dat.c    → eraDat_safe, eraDatTable_safe
 
-use crate::G14_safe::eraDatini_safe;
+use crate::G14_safe::{eraDatini_safe, LeapSecondTable};
 use crate::G19_safe::eraJd2cal_safe;
 use crate::G8_safe::eraCal2jd_safe;
-use crate::H1_safe::{eraLEAPSECOND, ERFA_DAYSEC, ERFA_DINT, ERFA_DNINT};
+use crate::H1_safe::{eraLEAPSECOND, DatStatus, ErfaError, ERFA_DAYSEC, ERFA_DINT, ERFA_DNINT};
 
-pub type ErfaResult<T> = Result<T, ()>;
+pub use crate::H1_safe::ErfaResult;
 
 // G9/d2tf.c → eraD2tf_safe
 // Convert interval in days to sign and HMS with fractional field.
@@ -55,42 +55,39 @@ pub fn eraD2dtf_safe(
     ndp: i32,
     d1: f64,
     d2: f64,
-) -> ErfaResult<((i32, i32, i32), [i32; 4], i32)> {
-    let ((iy0, im0, id0), mut fd, j_cal) = eraJd2cal_safe(d1, d2)?;
-    if j_cal != 0 {
-        return Err(());
-    }
+) -> ErfaResult<((i32, i32, i32), [i32; 4], DatStatus)> {
+    eraD2dtfTable_safe(&LeapSecondTable::current()?, scale, ndp, d1, d2)
+}
+
+// As eraD2dtf_safe, with UTC leap seconds taken from the given table.
+#[allow(clippy::type_complexity)]
+pub fn eraD2dtfTable_safe(
+    table: &LeapSecondTable,
+    scale: &str,
+    ndp: i32,
+    d1: f64,
+    d2: f64,
+) -> ErfaResult<((i32, i32, i32), [i32; 4], DatStatus)> {
+    let ((iy0, im0, id0), mut fd) = eraJd2cal_safe(d1, d2)?;
     let (mut iy1, mut im1, mut id1) = (iy0, im0, id0);
 
-    let mut js = 0;
+    let mut js = DatStatus::Ok;
     let mut leap = false;
 
     if scale == "UTC" {
-        let (dat0, j0) = eraDat_safe(iy1, im1, id1, 0.0)?;
-        if j0 < 0 {
-            return Err(());
-        }
-        if j0 > 0 {
+        let (dat0, j0) = eraDatTable_safe(table, iy1, im1, id1, 0.0)?;
+        if j0 != DatStatus::Ok {
             js = j0;
         }
 
-        let (dat12, j12) = eraDat_safe(iy1, im1, id1, 0.5)?;
-        if j12 < 0 {
-            return Err(());
-        }
-        if j12 > 0 && js == 0 {
+        let (dat12, j12) = eraDatTable_safe(table, iy1, im1, id1, 0.5)?;
+        if j12 != DatStatus::Ok && js == DatStatus::Ok {
             js = j12;
         }
 
-        let ((iy2, im2, id2), _w, j_next) = eraJd2cal_safe(d1 + 1.5, d2 - fd)?;
-        if j_next != 0 {
-            return Err(());
-        }
-        let (dat24, j24) = eraDat_safe(iy2, im2, id2, 0.0)?;
-        if j24 < 0 {
-            return Err(());
-        }
-        if j24 > 0 && js == 0 {
+        let ((iy2, im2, id2), _w) = eraJd2cal_safe(d1 + 1.5, d2 - fd)?;
+        let (dat24, j24) = eraDatTable_safe(table, iy2, im2, id2, 0.0)?;
+        if j24 != DatStatus::Ok && js == DatStatus::Ok {
             js = j24;
         }
 
@@ -104,10 +101,7 @@ pub fn eraD2dtf_safe(
     let (_s, mut ihmsf1) = eraD2tf_safe(ndp, fd)?;
 
     if ihmsf1[0] > 23 {
-        let ((iy2, im2, id2), _w, j_next) = eraJd2cal_safe(d1 + 1.5, d2 - fd)?;
-        if j_next != 0 {
-            return Err(());
-        }
+        let ((iy2, im2, id2), _w) = eraJd2cal_safe(d1 + 1.5, d2 - fd)?;
 
         if !leap {
             iy1 = iy2;
@@ -139,27 +133,7 @@ pub fn eraD2dtf_safe(
 
 // G9/dat.c → eraDat_safe
 // TAI−UTC = ΔAT for a given date; returns (ΔAT seconds, status).
-pub fn eraDat_safe(iy: i32, im: i32, id: i32, fd: f64) -> ErfaResult<(f64, i32)> {
-    const IYV: i32 = 2023;
-
-    const DRIFT: &[[f64; 2]] = &[
-        [37300.0, 0.0012960],
-        [37300.0, 0.0012960],
-        [37300.0, 0.0012960],
-        [37665.0, 0.0011232],
-        [37665.0, 0.0011232],
-        [38761.0, 0.0012960],
-        [38761.0, 0.0012960],
-        [38761.0, 0.0012960],
-        [38761.0, 0.0012960],
-        [38761.0, 0.0012960],
-        [38761.0, 0.0012960],
-        [38761.0, 0.0012960],
-        [39126.0, 0.0025920],
-        [39126.0, 0.0025920],
-    ];
-    const NERA1: usize = DRIFT.len();
-
+pub fn eraDat_safe(iy: i32, im: i32, id: i32, fd: f64) -> ErfaResult<(f64, DatStatus)> {
     const CHANGES_BUILTIN: &[eraLEAPSECOND] = &[
         eraLEAPSECOND {
             iyear: 1960,
@@ -372,25 +346,55 @@ pub fn eraDat_safe(iy: i32, im: i32, id: i32, fd: f64) -> ErfaResult<(f64, i32)>
             delat: 37.0,
         },
     ];
+    eraDatini_safe(CHANGES_BUILTIN)?;
+    eraDatTable_safe(&LeapSecondTable::current()?, iy, im, id, fd)
+}
+
+// As eraDat_safe, with ΔAT taken from the given table instead of the
+// process-wide one.  Entries before index 14 are the pre-1972 drift eras.
+// Dates at or past the table's expiry return DatStatus::PastExpiry.
+pub fn eraDatTable_safe(
+    table: &LeapSecondTable,
+    iy: i32,
+    im: i32,
+    id: i32,
+    fd: f64,
+) -> ErfaResult<(f64, DatStatus)> {
+    const DRIFT: &[[f64; 2]] = &[
+        [37300.0, 0.0012960],
+        [37300.0, 0.0012960],
+        [37300.0, 0.0012960],
+        [37665.0, 0.0011232],
+        [37665.0, 0.0011232],
+        [38761.0, 0.0012960],
+        [38761.0, 0.0012960],
+        [38761.0, 0.0012960],
+        [38761.0, 0.0012960],
+        [38761.0, 0.0012960],
+        [38761.0, 0.0012960],
+        [38761.0, 0.0012960],
+        [39126.0, 0.0025920],
+        [39126.0, 0.0025920],
+    ];
+    const NERA1: usize = DRIFT.len();
 
     if fd < 0.0 || fd > 1.0 {
-        return Err(());
+        return Err(ErfaError::BadFraction);
     }
 
-    let ((_, djm), jcal) = eraCal2jd_safe(iy, im, id)?;
-    if jcal < 0 {
-        return Err(());
-    }
+    let (_, djm) = eraCal2jd_safe(iy, im, id)?;
 
-    let table = eraDatini_safe(CHANGES_BUILTIN)?;
+    let table_expired = table.is_expired(djm + fd);
+    let table = table.entries();
+    let first = table.first().ok_or(ErfaError::EmptyLeapTable)?;
 
-    if iy < table[0].iyear {
-        return Ok((0.0, 1));
+    if iy < first.iyear {
+        return Ok((0.0, DatStatus::DubiousYear));
     }
 
-    let mut j = 0;
-    if iy > IYV + 5 {
-        j = 1;
+    let mut j = DatStatus::Ok;
+    if table_expired {
+        j = DatStatus::PastExpiry;
     }
 
     let m = 12 * iy + im;
@@ -404,7 +408,7 @@ pub fn eraDat_safe(iy: i32, im: i32, id: i32, fd: f64) -> ErfaResult<(f64, i32)>
     }
     let i = match idx {
         Some(i) => i,
-        None => return Err(()),
+        None => return Err(ErfaError::InternalError),
     };
 
     let mut da = table[i].delat;
@@ -7,6 +7,8 @@
 
 // H1/erfa.h
 
+use std::fmt;
+
 // Star-independent astrometry parameters
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default)]
@@ -39,6 +41,240 @@ pub struct eraLDBODY {
     pub pv: [[f64; 3]; 2],
 }
 
+// Error conditions reported by the safe API
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErfaError {
+    BadYear,
+    BadMonth,
+    BadDay,
+    BadHour,
+    BadMinute,
+    BadSecond,
+    BadFraction,
+    UnacceptableDate,
+    LeapTablePoisoned,
+    InvalidEllipsoid,
+    UnknownEllipsoid,
+    EllipsoidRegistryPoisoned,
+    InvalidFlattening,
+    InvalidSemiMajorAxis,
+    IllegalCase,
+    InvalidPlanet,
+    Superluminal,
+    NullPosition,
+    MissingDut1,
+    FileRead,
+    LeapFileSyntax,
+    LeapFileChecksum,
+    LeapFileNoExpiry,
+    EmptyLeapTable,
+    EopFileSyntax,
+    EopOutOfRange,
+    BadTimeString,
+    BadPrecision,
+    BadAngleString,
+    SpkFileSyntax,
+    SpkOutOfRange,
+    TleSyntax,
+    TleChecksum,
+    Sgp4Elements,
+    Sgp4Decayed,
+    InternalError,
+}
+
+impl fmt::Display for ErfaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ErfaError::BadYear => "bad year",
+            ErfaError::BadMonth => "bad month",
+            ErfaError::BadDay => "bad day",
+            ErfaError::BadHour => "bad hour",
+            ErfaError::BadMinute => "bad minute",
+            ErfaError::BadSecond => "bad second (< 0)",
+            ErfaError::BadFraction => "bad fraction of day",
+            ErfaError::UnacceptableDate => "unacceptable date",
+            ErfaError::LeapTablePoisoned => "leap-second table lock poisoned",
+            ErfaError::InvalidEllipsoid => "illegal ellipsoid identifier",
+            ErfaError::UnknownEllipsoid => "no ellipsoid of that name",
+            ErfaError::EllipsoidRegistryPoisoned => "ellipsoid registry lock poisoned",
+            ErfaError::InvalidFlattening => "illegal ellipsoid flattening",
+            ErfaError::InvalidSemiMajorAxis => "illegal ellipsoid equatorial radius",
+            ErfaError::IllegalCase => "illegal case",
+            ErfaError::InvalidPlanet => "illegal planet identifier",
+            ErfaError::Superluminal => "superluminal speed",
+            ErfaError::NullPosition => "null position vector",
+            ErfaError::MissingDut1 => "UT1-UTC (DUT1) required for this conversion",
+            ErfaError::FileRead => "cannot read file",
+            ErfaError::LeapFileSyntax => "malformed leap-second file",
+            ErfaError::LeapFileChecksum => "leap-second file SHA-1 mismatch",
+            ErfaError::LeapFileNoExpiry => "leap-second file has no expiry date",
+            ErfaError::EmptyLeapTable => "leap-second table is empty",
+            ErfaError::EopFileSyntax => "malformed EOP file",
+            ErfaError::EopOutOfRange => "date outside the EOP table",
+            ErfaError::BadTimeString => "malformed date/time string",
+            ErfaError::BadPrecision => "precision out of range",
+            ErfaError::BadAngleString => "malformed sexagesimal string",
+            ErfaError::SpkFileSyntax => "malformed SPK file",
+            ErfaError::SpkOutOfRange => "no SPK segment covers the body and date",
+            ErfaError::TleSyntax => "malformed two-line element set",
+            ErfaError::TleChecksum => "two-line element checksum mismatch",
+            ErfaError::Sgp4Elements => "SGP4 elements out of range",
+            ErfaError::Sgp4Decayed => "satellite has decayed",
+            ErfaError::InternalError => "internal error",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for ErfaError {}
+
+// Result type shared by every safe function
+pub type ErfaResult<T> = Result<T, ErfaError>;
+
+// Warning from leap-second dependent (UTC) routines: eraDat and its callers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DatStatus {
+    #[default]
+    Ok,
+    DubiousYear,
+    PastExpiry, // after the table's expiry: future leap seconds unknown
+}
+
+impl DatStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            DatStatus::Ok => 0,
+            DatStatus::DubiousYear | DatStatus::PastExpiry => 1,
+        }
+    }
+}
+
+// Warnings from eraDtf2d: leap-table status plus clock overflow
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dtf2dStatus {
+    pub dat: DatStatus,
+    pub time_after_end_of_day: bool,
+}
+
+impl Dtf2dStatus {
+    pub fn code(self) -> i32 {
+        self.dat.code() + if self.time_after_end_of_day { 2 } else { 0 }
+    }
+}
+
+// Warning from eraJdcalf
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JdcalfStatus {
+    #[default]
+    Ok,
+    NdpOutOfRange,
+}
+
+impl JdcalfStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            JdcalfStatus::Ok => 0,
+            JdcalfStatus::NdpOutOfRange => 1,
+        }
+    }
+}
+
+// Range warning from eraAf2a, eraTf2a and eraTf2d (value is still computed)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FieldStatus {
+    #[default]
+    Ok,
+    BadDegOrHour,
+    BadMinute,
+    BadSecond,
+}
+
+impl FieldStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            FieldStatus::Ok => 0,
+            FieldStatus::BadDegOrHour => 1,
+            FieldStatus::BadMinute => 2,
+            FieldStatus::BadSecond => 3,
+        }
+    }
+}
+
+// Warning from the analytical ephemerides eraEpv00 and eraPlan94
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EphemStatus {
+    #[default]
+    Ok,
+    DateOutsideRange,
+    NotConverged,
+}
+
+impl EphemStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            EphemStatus::Ok => 0,
+            EphemStatus::DateOutsideRange => 1,
+            EphemStatus::NotConverged => 2,
+        }
+    }
+}
+
+// Warnings from eraStarpv, eraStarpm and eraPmsafe (may occur together)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StarpvStatus {
+    pub distance_overridden: bool,
+    pub excessive_speed: bool,
+    pub not_converged: bool,
+}
+
+impl StarpvStatus {
+    pub fn code(self) -> i32 {
+        (self.distance_overridden as i32)
+            + 2 * (self.excessive_speed as i32)
+            + 4 * (self.not_converged as i32)
+    }
+}
+
+// Number of tangent points found by eraTpors and eraTporv
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TangentSolutions {
+    #[default]
+    None,
+    One,
+    Two,
+}
+
+impl TangentSolutions {
+    pub fn code(self) -> i32 {
+        match self {
+            TangentSolutions::None => 0,
+            TangentSolutions::One => 1,
+            TangentSolutions::Two => 2,
+        }
+    }
+}
+
+// Warning from the gnomonic projections eraTpxes and eraTpxev
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TpxStatus {
+    #[default]
+    Ok,
+    StarTooFarFromAxis,
+    AntistarOnPlane,
+    AntistarTooFarFromAxis,
+}
+
+impl TpxStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            TpxStatus::Ok => 0,
+            TpxStatus::StarTooFarFromAxis => 1,
+            TpxStatus::AntistarOnPlane => 2,
+            TpxStatus::AntistarTooFarFromAxis => 3,
+        }
+    }
+}
+
 // macro_rules! stub { ($ret:ty) => ( { todo!() } ); }
 
 // Astronomy/Calendars
@@ -53,24 +289,30 @@ pub use crate::G8_safe::eraCal2jd_safe;
 // Astronomy/Astrometry
 pub use crate::G1_safe::eraAb_safe;
 pub use crate::G1_safe::eraApcg13_safe;
+pub use crate::G1_safe::eraApcg13Eph_safe;
 pub use crate::G1_safe::eraApcg_safe;
 pub use crate::G1_safe::eraApci13_safe;
+pub use crate::G1_safe::eraApci13Eph_safe;
 pub use crate::G1_safe::eraApci_safe;
 
 pub use crate::G2_safe::eraApco13_safe;
+pub use crate::G2_safe::eraApco13Eph_safe;
 pub use crate::G2_safe::eraApco_safe;
 pub use crate::G2_safe::eraApcs13_safe;
+pub use crate::G2_safe::eraApcs13Eph_safe;
 pub use crate::G2_safe::eraApcs_safe;
 pub use crate::G2_safe::eraAper_safe;
 
 pub use crate::G3_safe::eraAper13_safe;
 pub use crate::G3_safe::eraApio13_safe;
 pub use crate::G3_safe::eraApio_safe;
+pub use crate::G3_safe::eraApioJac_safe;
 pub use crate::G3_safe::eraAtcc13_safe;
 pub use crate::G3_safe::eraAtccq_safe;
 
 pub use crate::G4_safe::eraAtci13_safe;
 pub use crate::G4_safe::eraAtciq_safe;
+pub use crate::G4_safe::eraAtciqJac_safe;
 pub use crate::G4_safe::eraAtciqn_safe;
 pub use crate::G4_safe::eraAtciqz_safe;
 pub use crate::G4_safe::eraAtco13_safe;
@@ -79,21 +321,29 @@ pub use crate::G4_safe::eraAticq_safe;
 pub use crate::G4_safe::eraAticqn_safe;
 pub use crate::G4_safe::eraAtio13_safe;
 pub use crate::G4_safe::eraAtioq_safe;
+pub use crate::G4_safe::eraAtioqRef_safe;
+pub use crate::G4_safe::eraAtioqJac_safe;
 
 pub use crate::G5_safe::eraAtoc13_safe;
 pub use crate::G5_safe::eraAtoi13_safe;
 pub use crate::G5_safe::eraAtoiq_safe;
+pub use crate::G5_safe::eraAtoiqRef_safe;
+pub use crate::G5_safe::eraAtoiqJac_safe;
 
 pub use crate::G20_safe::eraLd_safe;
 pub use crate::G20_safe::eraLdn_safe;
 pub use crate::G20_safe::eraLdsun_safe;
 pub use crate::G25_safe::eraPmpx_safe;
 pub use crate::G25_safe::eraPmsafe_safe;
+pub use crate::G25_safe::eraPmsafeCov_safe;
 pub use crate::G27_safe::eraPvtob_safe;
+pub use crate::G27_safe::eraPvtobEll_safe;
 pub use crate::G28_safe::eraRefco_safe;
+pub use crate::G28_safe::{Refraction, TwoTerm};
 
 // Astronomy/Ephemerides
 pub use crate::G13_safe::eraEpv00_safe;
+pub use crate::G13_safe::{Ephemeris, Epv00};
 pub use crate::G21_safe::eraMoon98_safe;
 pub use crate::G24_safe::eraPlan94_safe;
 
@@ -216,6 +466,7 @@ pub use crate::G17_safe::eraGst94_safe;
 // Astronomy/SpaceMotion
 pub use crate::G27_safe::eraPvstar_safe;
 pub use crate::G30_safe::eraStarpv_safe;
+pub use crate::G30_safe::eraStarpvCov_safe;
 
 // Astronomy/StarCatalogs
 pub use crate::G15_safe::eraFk5hip_safe;
@@ -228,6 +479,7 @@ pub use crate::G16_safe::eraFk5hz_safe;
 pub use crate::G18_safe::eraH2fk5_safe;
 pub use crate::G18_safe::eraHfk5z_safe;
 pub use crate::G30_safe::eraStarpm_safe;
+pub use crate::G30_safe::eraStarpmCov_safe;
 
 // Astronomy/EclipticCoordinates
 pub use crate::G11_safe::eraEceq06_safe;
@@ -243,17 +495,22 @@ pub use crate::G19_safe::eraIcrs2g_safe;
 
 // Astronomy/GeodeticGeocentric
 pub use crate::G11_safe::eraEform_safe;
+pub use crate::G11_safe::Ellipsoid;
 pub use crate::G17_safe::eraGc2gd_safe;
+pub use crate::G17_safe::eraGc2gdEll_safe;
 pub use crate::G17_safe::eraGc2gde_safe;
 pub use crate::G17_safe::eraGd2gc_safe;
+pub use crate::G17_safe::eraGd2gcEll_safe;
 pub use crate::G17_safe::eraGd2gce_safe;
 
 // Astronomy/Timescales
 pub use crate::G10_safe::eraDtdb_safe;
 pub use crate::G10_safe::eraDtf2d_safe;
+pub use crate::G10_safe::eraDtf2dTable_safe;
 pub use crate::G32_safe::eraTaitt_safe;
 pub use crate::G32_safe::eraTaiut1_safe;
 pub use crate::G32_safe::eraTaiutc_safe;
+pub use crate::G32_safe::eraTaiutcTable_safe;
 pub use crate::G32_safe::eraTcbtdb_safe;
 pub use crate::G32_safe::eraTcgtt_safe;
 pub use crate::G32_safe::eraTdbtcb_safe;
@@ -266,9 +523,12 @@ pub use crate::G33_safe::eraUt1tai_safe;
 pub use crate::G33_safe::eraUt1tt_safe;
 pub use crate::G33_safe::eraUt1utc_safe;
 pub use crate::G33_safe::eraUtctai_safe;
+pub use crate::G33_safe::eraUtctaiTable_safe;
 pub use crate::G33_safe::eraUtcut1_safe;
 pub use crate::G9_safe::eraD2dtf_safe;
+pub use crate::G9_safe::eraD2dtfTable_safe;
 pub use crate::G9_safe::eraDat_safe;
+pub use crate::G9_safe::eraDatTable_safe;
 
 // Astronomy/HorizonEquatorial
 pub use crate::G18_safe::eraHd2ae_safe;
@@ -380,6 +640,7 @@ pub use crate::G14_safe::eraVersion_safe;
 // Experimental leap-second accessors
 pub use crate::G14_safe::eraGetLeapSeconds_safe;
 pub use crate::G14_safe::eraSetLeapSeconds_safe;
+pub use crate::G14_safe::LeapSecondTable;
 
 
 //  H1/erfadatextra.h
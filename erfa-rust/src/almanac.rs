@@ -0,0 +1,141 @@
+// almanac
+//   Daily Sun almanac for a site: sunrise, sunset, solar noon and civil,
+//   nautical and astronomical twilight.
+//
+//   Twilight               → Civil (−6°), Nautical (−12°), Astronomical (−18°)
+//   Crossings              → morning (rise, dawn) and evening (set, dusk) times
+//   AlmanacDay             → one local day
+//   almanac / almanac_with → consecutive days from a calendar date
+//
+//   Days run from local mean midnight (0h UTC − longitude), so every event
+//   of a date falls in it.  Sunrise and sunset use the standard horizon
+//   (upper limb, 34′ refraction); twilights use the Sun's centre at the
+//   geometric depression.  The Sun is sampled once per half hour for all
+//   four horizons, as in events::search.  A crossing that does not occur
+//   (polar day or night, white nights) is None; where there are two in a
+//   day, the first morning and last evening one is kept.  Sites on other
+//   ellipsoids come in through Site::on_ellipsoid.
+
+use crate::bodies::{Body, BodyEphemeris};
+use crate::events::{crossings, sample, topocentric, Horizon, Site, Target, Topocentric};
+use crate::G13_safe::Epv00;
+use crate::G19_safe::eraJd2cal_safe;
+use crate::G8_safe::eraCal2jd_safe;
+use crate::H1_safe::{ERFA_D2PI, ERFA_DD2R};
+
+pub use crate::H1_safe::ErfaResult;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Twilight {
+    Civil,
+    Nautical,
+    Astronomical,
+}
+
+impl Twilight {
+    pub const ALL: [Twilight; 3] = [Twilight::Civil, Twilight::Nautical, Twilight::Astronomical];
+
+    // Geometric altitude of the Sun's centre (radians).
+    pub fn altitude(self) -> f64 {
+        match self {
+            Twilight::Civil => -6.0 * ERFA_DD2R,
+            Twilight::Nautical => -12.0 * ERFA_DD2R,
+            Twilight::Astronomical => -18.0 * ERFA_DD2R,
+        }
+    }
+
+    pub fn horizon(self) -> Horizon {
+        Horizon::Altitude(self.altitude())
+    }
+}
+
+// Upward and downward crossings of one horizon, as UTC two-part JDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Crossings {
+    pub rise: Option<(f64, f64)>, // sunrise, or dawn
+    pub set: Option<(f64, f64)>,  // sunset, or dusk
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AlmanacDay {
+    pub iy: i32,
+    pub im: i32,
+    pub id: i32,
+    pub noon: Option<(f64, f64)>,
+    pub sun: Crossings,
+    pub civil: Crossings,
+    pub nautical: Crossings,
+    pub astronomical: Crossings,
+    pub sun_always_up: bool,
+    pub sun_always_down: bool,
+}
+
+impl AlmanacDay {
+    pub fn twilight(&self, t: Twilight) -> Crossings {
+        match t {
+            Twilight::Civil => self.civil,
+            Twilight::Nautical => self.nautical,
+            Twilight::Astronomical => self.astronomical,
+        }
+    }
+}
+
+// Almanac for `days` local days from iy-im-id, with the built-in
+// ephemerides.
+pub fn almanac(site: &Site, iy: i32, im: i32, id: i32, days: i32) -> ErfaResult<Vec<AlmanacDay>> {
+    almanac_with(&Epv00, site, iy, im, id, days)
+}
+
+// As almanac, with Earth and Sun positions from the given ephemeris.
+pub fn almanac_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    site: &Site,
+    iy: i32,
+    im: i32,
+    id: i32,
+    days: i32,
+) -> ErfaResult<Vec<AlmanacDay>> {
+    let (djm0, djm) = eraCal2jd_safe(iy, im, id)?;
+    let sun = Target::Body(Body::Sun);
+    let midnight = -site.elong / ERFA_D2PI;
+
+    let mut out = Vec::new();
+    for k in 0..days.max(0) {
+        let utc1 = djm0 + djm + k as f64;
+        let f = |t: f64| topocentric(eph, sun, site, utc1, t);
+        let samples = sample(&f, midnight, 1.0)?;
+
+        let cross = |horizon: Horizon| -> ErfaResult<Crossings> {
+            let mut c = Crossings::default();
+            for (t, rising) in crossings(&f, &samples, &|p: &Topocentric| p.above(horizon))? {
+                if rising && c.rise.is_none() {
+                    c.rise = Some((utc1, t));
+                } else if !rising {
+                    c.set = Some((utc1, t));
+                }
+            }
+            Ok(c)
+        };
+        let noon = crossings(&f, &samples, &|p: &Topocentric| p.ha)?
+            .first()
+            .map(|&(t, _)| (utc1, t));
+        let sun_c = cross(Horizon::Standard)?;
+        let up = |p: &Topocentric| p.above(Horizon::Standard) > 0.0;
+        let no_crossing = sun_c.rise.is_none() && sun_c.set.is_none();
+
+        let ((y, m, d), _) = eraJd2cal_safe(utc1, 0.0)?;
+        out.push(AlmanacDay {
+            iy: y,
+            im: m,
+            id: d,
+            noon,
+            sun: sun_c,
+            civil: cross(Twilight::Civil.horizon())?,
+            nautical: cross(Twilight::Nautical.horizon())?,
+            astronomical: cross(Twilight::Astronomical.horizon())?,
+            sun_always_up: no_crossing && samples.iter().all(|(_, p)| up(p)),
+            sun_always_down: no_crossing && samples.iter().all(|(_, p)| !up(p)),
+        });
+    }
+    Ok(out)
+}
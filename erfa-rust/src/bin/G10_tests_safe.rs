@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use erfa_rust::G10_safe;
+use erfa_rust::H1_safe::{ErfaError, ErfaResult};
 use libc::{c_char, snprintf};
 use std::ffi::CString;
 
@@ -40,6 +41,24 @@ fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
     }
 }
 
+fn ver<T>(result: ErfaResult<T>, errok: ErfaError, func: &str, test: &str, status: &mut i32) {
+    match result {
+        Err(err) if err == errok => {
+            if unsafe { VERBOSE } {
+                println!("{} passed: {} want {:?} got {:?}", func, test, errok, err);
+            }
+        }
+        Err(err) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got {:?}", func, test, errok, err);
+        }
+        Ok(_) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got Ok", func, test, errok);
+        }
+    }
+}
+
 fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
     let a = val - valok;
     if a != 0.0 && a.abs() > dval.abs() {
@@ -91,7 +110,31 @@ fn t_dtf2d(status: &mut i32) {
     match result {
         Ok(((u1, u2), j)) => {
             vvd(u1 + u2, 2449534.49999, 1e-6, "eraDtf2d_safe", "u", status);
-            viv(j, 0, "eraDtf2d_safe", "j", status);
+            viv(j.code(), 0, "eraDtf2d_safe", "j", status);
+        }
+        Err(code) => {
+            *status = 1;
+            println!("eraDtf2d_safe failed with error code: {}", code);
+        }
+    }
+
+    let result = G10_safe::eraDtf2d_safe("UTC", 1994, 6, 30, 24, 0, 0.0);
+    ver(result, ErfaError::BadHour, "eraDtf2d_safe", "j2", status);
+
+    let result = G10_safe::eraDtf2d_safe("TT", 1994, 2, 30, 12, 0, 0.0);
+    ver(result, ErfaError::BadDay, "eraDtf2d_safe", "j3", status);
+
+    let result = G10_safe::eraDtf2d_safe("TT", 1994, 6, 30, 23, 59, 60.5);
+    match result {
+        Ok((_, j)) => {
+            viv(
+                j.time_after_end_of_day as i32,
+                1,
+                "eraDtf2d_safe",
+                "j4",
+                status,
+            );
+            viv(j.code(), 2, "eraDtf2d_safe", "j4", status);
         }
         Err(code) => {
             *status = 1;
@@ -2,6 +2,7 @@
 
 use erfa_rust::G11_safe;
 use erfa_rust::H1_safe;
+use erfa_rust::H1_safe::{ErfaError, ErfaResult};
 use libc::{c_char, snprintf};
 use std::ffi::CString;
 
@@ -38,6 +39,24 @@ fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
     }
 }
 
+fn ver<T>(result: ErfaResult<T>, errok: ErfaError, func: &str, test: &str, status: &mut i32) {
+    match result {
+        Err(err) if err == errok => {
+            if unsafe { VERBOSE } {
+                println!("{} passed: {} want {:?} got {:?}", func, test, errok, err);
+            }
+        }
+        Err(err) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got {:?}", func, test, errok, err);
+        }
+        Ok(_) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got Ok", func, test, errok);
+        }
+    }
+}
+
 fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
     let a = val - valok;
     if a != 0.0 && a.abs() > dval.abs() {
@@ -295,20 +314,11 @@ fn t_eect00(status: &mut i32) {
 
 fn t_eform(status: &mut i32) {
     let result = G11_safe::eraEform_safe(0);
-    match result {
-        Ok(((_, _), j)) => {
-            viv(j, -1, "eraEform_safe", "j0", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraEform_safe failed: unexpected error");
-        }
-    }
+    ver(result, ErfaError::InvalidEllipsoid, "eraEform_safe", "j0", status);
 
     let result = G11_safe::eraEform_safe(H1_safe::ERFA_WGS84);
     match result {
-        Ok(((a, f), j)) => {
-            viv(j, 0, "eraEform_safe", "j1", status);
+        Ok((a, f)) => {
             vvd(a, 6378137.0, 1e-10, "eraEform_safe", "a1", status);
             vvd(
                 f,
@@ -327,8 +337,7 @@ fn t_eform(status: &mut i32) {
 
     let result = G11_safe::eraEform_safe(H1_safe::ERFA_GRS80);
     match result {
-        Ok(((a, f), j)) => {
-            viv(j, 0, "eraEform_safe", "j2", status);
+        Ok((a, f)) => {
             vvd(a, 6378137.0, 1e-10, "eraEform_safe", "a2", status);
             vvd(
                 f,
@@ -347,8 +356,7 @@ fn t_eform(status: &mut i32) {
 
     let result = G11_safe::eraEform_safe(H1_safe::ERFA_WGS72);
     match result {
-        Ok(((a, f), j)) => {
-            viv(j, 0, "eraEform_safe", "j2", status);
+        Ok((a, f)) => {
             vvd(a, 6378135.0, 1e-10, "eraEform_safe", "a3", status);
             vvd(
                 f,
@@ -366,15 +374,117 @@ fn t_eform(status: &mut i32) {
     }
 
     let result = G11_safe::eraEform_safe(4);
-    match result {
-        Ok(((_, _), j)) => {
-            viv(j, -1, "eraEform_safe", "j3", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraEform_safe failed: unexpected error");
+    ver(result, ErfaError::InvalidEllipsoid, "eraEform_safe", "j3", status);
+}
+
+fn t_ellipsoid(status: &mut i32) {
+    // The eraEform ellipsoids, by code and by name.
+    for (n, name) in [
+        (H1_safe::ERFA_WGS84, "WGS84"),
+        (H1_safe::ERFA_GRS80, "grs 80"),
+        (H1_safe::ERFA_WGS72, "wgs-72"),
+    ] {
+        match (
+            G11_safe::eraEform_safe(n),
+            G11_safe::Ellipsoid::from_code(n),
+            G11_safe::Ellipsoid::named(name),
+        ) {
+            (Ok((a, f)), Ok(e1), Ok(e2)) => {
+                vvd(e1.a, a, 0.0, "Ellipsoid::from_code", name, status);
+                vvd(e1.f, f, 0.0, "Ellipsoid::from_code", name, status);
+                vvd(e2.a, a, 0.0, "Ellipsoid::named", name, status);
+                vvd(e2.f, f, 0.0, "Ellipsoid::named", name, status);
+            }
+            _ => {
+                *status = 1;
+                println!("Ellipsoid failed: unexpected error for {}", name);
+            }
         }
     }
+    ver(
+        G11_safe::Ellipsoid::from_code(4),
+        ErfaError::InvalidEllipsoid,
+        "Ellipsoid::from_code",
+        "4",
+        status,
+    );
+
+    let e = G11_safe::Ellipsoid::named("Airy 1830").unwrap();
+    vvd(e.a, 6377563.396, 0.0, "Ellipsoid::named", "Airy a", status);
+    vvd(e.b(), 6356256.909, 1e-3, "Ellipsoid::b", "Airy", status);
+    vvd(e.e2(), 0.00667054, 1e-10, "Ellipsoid::e2", "Airy", status);
+    let e = G11_safe::Ellipsoid::named("CLARKE_1866").unwrap();
+    vvd(e.b(), 6356583.8, 1e-1, "Ellipsoid::b", "Clarke", status);
+    let e = G11_safe::Ellipsoid::named("mars").unwrap();
+    vvd(e.b(), 3376200.0, 1e-8, "Ellipsoid::b", "Mars", status);
+    ver(
+        G11_safe::Ellipsoid::named("Hayford"),
+        ErfaError::UnknownEllipsoid,
+        "Ellipsoid::named",
+        "unknown",
+        status,
+    );
+
+    // Registered ellipsoids, replaced by name; built-ins are fixed.
+    let venus = G11_safe::Ellipsoid::new(6_051_800.0, 0.0).unwrap();
+    viv(
+        G11_safe::Ellipsoid::register("Venus IAU", venus).is_ok() as i32,
+        1,
+        "Ellipsoid::register",
+        "new",
+        status,
+    );
+    let e = G11_safe::Ellipsoid::named("venus-iau").unwrap();
+    vvd(
+        e.a,
+        6_051_800.0,
+        0.0,
+        "Ellipsoid::named",
+        "registered",
+        status,
+    );
+    let venus = G11_safe::Ellipsoid {
+        a: 6_051_000.0,
+        ..venus
+    };
+    viv(
+        G11_safe::Ellipsoid::register("venus iau", venus).is_ok() as i32,
+        1,
+        "Ellipsoid::register",
+        "replace",
+        status,
+    );
+    let e = G11_safe::Ellipsoid::named("Venus IAU").unwrap();
+    vvd(
+        e.a,
+        6_051_000.0,
+        0.0,
+        "Ellipsoid::named",
+        "replaced",
+        status,
+    );
+    ver(
+        G11_safe::Ellipsoid::register("wgs 84", venus),
+        ErfaError::InvalidEllipsoid,
+        "Ellipsoid::register",
+        "built-in",
+        status,
+    );
+
+    ver(
+        G11_safe::Ellipsoid::new(6e6, 1.0),
+        ErfaError::InvalidFlattening,
+        "Ellipsoid::new",
+        "f",
+        status,
+    );
+    ver(
+        G11_safe::Ellipsoid::new(0.0, 0.0),
+        ErfaError::InvalidSemiMajorAxis,
+        "Ellipsoid::new",
+        "a",
+        status,
+    );
 }
 
 fn t_eo06a(status: &mut i32) {
@@ -457,6 +567,7 @@ fn main() {
     t_ee06a(&mut status);
     t_eect00(&mut status);
     t_eform(&mut status);
+    t_ellipsoid(&mut status);
     t_eo06a(&mut status);
     t_eors(&mut status);
 
@@ -169,7 +169,7 @@ fn t_epv00(status: &mut i32) {
                 status,
             );
 
-            viv(j, 0, "eraEpv00_safe", "j", status);
+            viv(j.code(), 0, "eraEpv00_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -1,8 +1,10 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
+use erfa_rust::G11_safe;
 use erfa_rust::G17_safe;
 use erfa_rust::H1_safe;
+use erfa_rust::H1_safe::{ErfaError, ErfaResult};
 use libc::{c_char, snprintf};
 use std::ffi::CString;
 
@@ -40,6 +42,24 @@ fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
     }
 }
 
+fn ver<T>(result: ErfaResult<T>, errok: ErfaError, func: &str, test: &str, status: &mut i32) {
+    match result {
+        Err(err) if err == errok => {
+            if unsafe { VERBOSE } {
+                println!("{} passed: {} want {:?} got {:?}", func, test, errok, err);
+            }
+        }
+        Err(err) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got {:?}", func, test, errok, err);
+        }
+        Ok(_) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got Ok", func, test, errok);
+        }
+    }
+}
+
 fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
     let a = val - valok;
     if a != 0.0 && a.abs() > dval.abs() {
@@ -99,20 +119,11 @@ fn t_gc2gd(status: &mut i32) {
     let xyz = [2e6, 3e6, 5.244e6];
 
     let result = G17_safe::eraGc2gd_safe(0, &xyz);
-    match result {
-        Ok((_, _, _, j)) => {
-            viv(j, -1, "eraGc2gd_safe", "j0", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraGc2gd_safe failed: unexpected error");
-        }
-    }
+    ver(result, ErfaError::InvalidEllipsoid, "eraGc2gd_safe", "j0", status);
 
     let result = G17_safe::eraGc2gd_safe(H1_safe::ERFA_WGS84, &xyz);
     match result {
-        Ok((e, p, h, j)) => {
-            viv(j, 0, "eraGc2gd_safe", "j1", status);
+        Ok((e, p, h)) => {
             vvd(
                 e,
                 0.9827937232473290680,
@@ -132,8 +143,7 @@ fn t_gc2gd(status: &mut i32) {
 
     let result = G17_safe::eraGc2gd_safe(H1_safe::ERFA_GRS80, &xyz);
     match result {
-        Ok((e, p, h, j)) => {
-            viv(j, 0, "eraGc2gd_safe", "j2", status);
+        Ok((e, p, h)) => {
             vvd(
                 e,
                 0.9827937232473290680,
@@ -153,8 +163,7 @@ fn t_gc2gd(status: &mut i32) {
 
     let result = G17_safe::eraGc2gd_safe(H1_safe::ERFA_WGS72, &xyz);
     match result {
-        Ok((e, p, h, j)) => {
-            viv(j, 0, "eraGc2gd_safe", "j3", status);
+        Ok((e, p, h)) => {
             vvd(
                 e,
                 0.9827937232473290680,
@@ -180,15 +189,7 @@ fn t_gc2gd(status: &mut i32) {
     }
 
     let result = G17_safe::eraGc2gd_safe(4, &xyz);
-    match result {
-        Ok((_, _, _, j)) => {
-            viv(j, -1, "eraGc2gd_safe", "j4", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraGc2gd_safe failed: unexpected error");
-        }
-    }
+    ver(result, ErfaError::InvalidEllipsoid, "eraGc2gd_safe", "j4", status);
 }
 
 fn t_gc2gde(status: &mut i32) {
@@ -198,8 +199,7 @@ fn t_gc2gde(status: &mut i32) {
 
     let result = G17_safe::eraGc2gde_safe(a, f, &xyz);
     match result {
-        Ok((e, p, h, j)) => {
-            viv(j, 0, "eraGc2gde_safe", "j", status);
+        Ok((e, p, h)) => {
             vvd(
                 e,
                 0.9827937232473290680,
@@ -225,26 +225,92 @@ fn t_gc2gde(status: &mut i32) {
     }
 }
 
-fn t_gd2gc(status: &mut i32) {
-    let e = 3.1;
-    let p = -0.5;
-    let h = 2500.0;
+fn t_gc2gdell(status: &mut i32) {
+    let xyz = [2e6, 3e6, 5.244e6];
 
-    let result = G17_safe::eraGd2gc_safe(0, e, p, h);
-    match result {
-        Ok((_, j)) => {
-            viv(j, -1, "eraGd2gc_safe", "j0", status);
+    // WGS84 by value matches the eraEform code.
+    match G17_safe::eraGc2gdEll_safe(&G11_safe::Ellipsoid::WGS84, &xyz) {
+        Ok((e, p, h)) => {
+            vvd(
+                e,
+                0.9827937232473290680,
+                1e-14,
+                "eraGc2gdEll_safe",
+                "e1",
+                status,
+            );
+            vvd(
+                p,
+                0.97160184819075459,
+                1e-14,
+                "eraGc2gdEll_safe",
+                "p1",
+                status,
+            );
+            vvd(
+                h,
+                331.4172461426059892,
+                1e-8,
+                "eraGc2gdEll_safe",
+                "h1",
+                status,
+            );
         }
         Err(_) => {
             *status = 1;
-            println!("eraGd2gc_safe failed: unexpected error");
+            println!("eraGc2gdEll_safe failed: unexpected error");
+        }
+    }
+
+    // A sphere gives geocentric latitude and radial height.
+    let moon = G11_safe::Ellipsoid::named("Moon").unwrap();
+    let xyz = [1e6, -1e6, 1.3e6];
+    match G17_safe::eraGc2gdEll_safe(&moon, &xyz) {
+        Ok((e, p, h)) => {
+            let r = (1e12_f64 + 1e12 + 1.69e12).sqrt();
+            vvd(
+                e,
+                -std::f64::consts::FRAC_PI_4,
+                1e-15,
+                "eraGc2gdEll_safe",
+                "e moon",
+                status,
+            );
+            vvd(
+                p,
+                (1.3e6 / r).asin(),
+                1e-14,
+                "eraGc2gdEll_safe",
+                "p moon",
+                status,
+            );
+            vvd(
+                h,
+                r - 1_737_400.0,
+                1e-8,
+                "eraGc2gdEll_safe",
+                "h moon",
+                status,
+            );
+        }
+        Err(_) => {
+            *status = 1;
+            println!("eraGc2gdEll_safe failed: unexpected error");
         }
     }
+}
+
+fn t_gd2gc(status: &mut i32) {
+    let e = 3.1;
+    let p = -0.5;
+    let h = 2500.0;
+
+    let result = G17_safe::eraGd2gc_safe(0, e, p, h);
+    ver(result, ErfaError::InvalidEllipsoid, "eraGd2gc_safe", "j0", status);
 
     let result = G17_safe::eraGd2gc_safe(H1_safe::ERFA_WGS84, e, p, h);
     match result {
-        Ok((xyz, j)) => {
-            viv(j, 0, "eraGd2gc_safe", "j1", status);
+        Ok(xyz) => {
             vvd(
                 xyz[0],
                 -5599000.5577049947,
@@ -278,8 +344,7 @@ fn t_gd2gc(status: &mut i32) {
 
     let result = G17_safe::eraGd2gc_safe(H1_safe::ERFA_GRS80, e, p, h);
     match result {
-        Ok((xyz, j)) => {
-            viv(j, 0, "eraGd2gc_safe", "j2", status);
+        Ok(xyz) => {
             vvd(
                 xyz[0],
                 -5599000.5577260984,
@@ -313,8 +378,7 @@ fn t_gd2gc(status: &mut i32) {
 
     let result = G17_safe::eraGd2gc_safe(H1_safe::ERFA_WGS72, e, p, h);
     match result {
-        Ok((xyz, j)) => {
-            viv(j, 0, "eraGd2gc_safe", "j3", status);
+        Ok(xyz) => {
             vvd(
                 xyz[0],
                 -5598998.7626301490,
@@ -347,15 +411,7 @@ fn t_gd2gc(status: &mut i32) {
     }
 
     let result = G17_safe::eraGd2gc_safe(4, e, p, h);
-    match result {
-        Ok((_, j)) => {
-            viv(j, -1, "eraGd2gc_safe", "j4", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraGd2gc_safe failed: unexpected error");
-        }
-    }
+    ver(result, ErfaError::InvalidEllipsoid, "eraGd2gc_safe", "j4", status);
 }
 
 fn t_gd2gce(status: &mut i32) {
@@ -367,8 +423,7 @@ fn t_gd2gce(status: &mut i32) {
 
     let result = G17_safe::eraGd2gce_safe(a, f, e, p, h);
     match result {
-        Ok((xyz, j)) => {
-            viv(j, 0, "eraGd2gce_safe", "j", status);
+        Ok(xyz) => {
             vvd(
                 xyz[0],
                 -5598999.6665116328,
@@ -401,6 +456,64 @@ fn t_gd2gce(status: &mut i32) {
     }
 }
 
+fn t_gd2gcell(status: &mut i32) {
+    let (e, p, h) = (3.1, -0.5, 2500.0);
+
+    // WGS84 by value matches the eraEform code.
+    match G17_safe::eraGd2gcEll_safe(&G11_safe::Ellipsoid::WGS84, e, p, h) {
+        Ok(xyz) => {
+            vvd(
+                xyz[0],
+                -5599000.5577049947,
+                1e-7,
+                "eraGd2gcEll_safe",
+                "0/1",
+                status,
+            );
+            vvd(
+                xyz[1],
+                233011.67223479203,
+                1e-7,
+                "eraGd2gcEll_safe",
+                "1/1",
+                status,
+            );
+            vvd(
+                xyz[2],
+                -3040909.4706983363,
+                1e-7,
+                "eraGd2gcEll_safe",
+                "2/1",
+                status,
+            );
+        }
+        Err(_) => {
+            *status = 1;
+            println!("eraGd2gcEll_safe failed: unexpected error");
+        }
+    }
+
+    // Round trips on the other built-in and a registered ellipsoid.
+    let io = G11_safe::Ellipsoid::new(1_821_490.0, 0.0).unwrap();
+    let _ = G11_safe::Ellipsoid::register("Io", io);
+    for name in ["Airy 1830", "Clarke 1866", "IERS 2010", "Mars", "Io"] {
+        let ell = G11_safe::Ellipsoid::named(name).unwrap();
+        match G17_safe::eraGd2gcEll_safe(&ell, e, p, h)
+            .and_then(|xyz| G17_safe::eraGc2gdEll_safe(&ell, &xyz))
+        {
+            Ok((e2, p2, h2)) => {
+                vvd(e2, e, 1e-14, "eraGd2gcEll_safe", name, status);
+                vvd(p2, p, 1e-14, "eraGd2gcEll_safe", name, status);
+                vvd(h2, h, 1e-8, "eraGd2gcEll_safe", name, status);
+            }
+            Err(_) => {
+                *status = 1;
+                println!("eraGd2gcEll_safe failed: unexpected error for {}", name);
+            }
+        }
+    }
+}
+
 fn t_gmst00(status: &mut i32) {
     let result = G17_safe::eraGmst00_safe(2400000.5, 53736.0, 2400000.5, 53736.0);
     match result {
@@ -592,8 +705,10 @@ fn main() {
     t_g2icrs(&mut status);
     t_gc2gd(&mut status);
     t_gc2gde(&mut status);
+    t_gc2gdell(&mut status);
     t_gd2gc(&mut status);
     t_gd2gce(&mut status);
+    t_gd2gcell(&mut status);
     t_gmst00(&mut status);
     t_gmst06(&mut status);
     t_gmst82(&mut status);
@@ -121,12 +121,11 @@ fn t_jd2cal(status: &mut i32) {
     let dj2 = 50123.9999;
 
     match G19_safe::eraJd2cal_safe(dj1, dj2) {
-        Ok(((iy, im, id), fd, j)) => {
+        Ok(((iy, im, id), fd)) => {
             viv(iy, 1996, "eraJd2cal_safe", "y", status);
             viv(im, 2, "eraJd2cal_safe", "m", status);
             viv(id, 10, "eraJd2cal_safe", "d", status);
             vvd(fd, 0.9999, 1e-7, "eraJd2cal_safe", "fd", status);
-            viv(j, 0, "eraJd2cal_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -145,7 +144,7 @@ fn t_jdcalf(status: &mut i32) {
             viv(iydmf[1], 2, "eraJdcalf_safe", "m", status);
             viv(iydmf[2], 10, "eraJdcalf_safe", "d", status);
             viv(iydmf[3], 9999, "eraJdcalf_safe", "f", status);
-            viv(j, 0, "eraJdcalf_safe", "j", status);
+            viv(j.code(), 0, "eraJdcalf_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -192,7 +192,7 @@ fn t_af2a(status: &mut i32) {
                 "a",
                 status,
             );
-            viv(j, 0, "eraAf2a_safe", "j", status);
+            viv(j.code(), 0, "eraAf2a_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use erfa_rust::G24_safe;
+use erfa_rust::H1_safe::{ErfaError, ErfaResult};
 use libc::{c_char, snprintf};
 use std::ffi::CString;
 
@@ -37,6 +38,24 @@ fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
     }
 }
 
+fn ver<T>(result: ErfaResult<T>, errok: ErfaError, func: &str, test: &str, status: &mut i32) {
+    match result {
+        Err(err) if err == errok => {
+            if unsafe { VERBOSE } {
+                println!("{} passed: {} want {:?} got {:?}", func, test, errok, err);
+            }
+        }
+        Err(err) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got {:?}", func, test, errok, err);
+        }
+        Ok(_) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got Ok", func, test, errok);
+        }
+    }
+}
+
 fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
     let a = val - valok;
     if a != 0.0 && a.abs() > dval.abs() {
@@ -418,32 +437,10 @@ fn t_pfw06(status: &mut i32) {
 
 fn t_plan94(status: &mut i32) {
     let result = G24_safe::eraPlan94_safe(2400000.5, 1e6, 0);
-    match result {
-        Ok((pv, j)) => {
-            vvd(pv[0][0], 0.0, 0.0, "eraPlan94_safe", "x 1", status);
-            vvd(pv[0][1], 0.0, 0.0, "eraPlan94_safe", "y 1", status);
-            vvd(pv[0][2], 0.0, 0.0, "eraPlan94_safe", "z 1", status);
-            vvd(pv[1][0], 0.0, 0.0, "eraPlan94_safe", "xd 1", status);
-            vvd(pv[1][1], 0.0, 0.0, "eraPlan94_safe", "yd 1", status);
-            vvd(pv[1][2], 0.0, 0.0, "eraPlan94_safe", "zd 1", status);
-            viv(j, -1, "eraPlan94_safe", "j 1", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraPlan94_safe failed: unexpected error");
-        }
-    }
+    ver(result, ErfaError::InvalidPlanet, "eraPlan94_safe", "j 1", status);
 
     let result = G24_safe::eraPlan94_safe(2400000.5, 1e6, 10);
-    match result {
-        Ok((_, j)) => {
-            viv(j, -1, "eraPlan94_safe", "j 2", status);
-        }
-        Err(_) => {
-            *status = 1;
-            println!("eraPlan94_safe failed: unexpected error");
-        }
-    }
+    ver(result, ErfaError::InvalidPlanet, "eraPlan94_safe", "j 2", status);
 
     let result = G24_safe::eraPlan94_safe(2400000.5, -320000.0, 3);
     match result {
@@ -496,7 +493,7 @@ fn t_plan94(status: &mut i32) {
                 "zd 3",
                 status,
             );
-            viv(j, 1, "eraPlan94_safe", "j 3", status);
+            viv(j.code(), 1, "eraPlan94_safe", "j 3", status);
         }
         Err(_) => {
             *status = 1;
@@ -555,7 +552,7 @@ fn t_plan94(status: &mut i32) {
                 "zd 4",
                 status,
             );
-            viv(j, 0, "eraPlan94_safe", "j 4", status);
+            viv(j.code(), 0, "eraPlan94_safe", "j 4", status);
         }
         Err(_) => {
             *status = 1;
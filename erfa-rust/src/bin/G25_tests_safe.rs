@@ -445,7 +445,7 @@ fn t_pmsafe(status: &mut i32) {
                 "rv2",
                 status,
             );
-            viv(j, 0, "eraPmsafe_safe", "j", status);
+            viv(j.code(), 0, "eraPmsafe_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -454,6 +454,62 @@ fn t_pmsafe(status: &mut i32) {
     }
 }
 
+fn t_pmsafecov(status: &mut i32) {
+    let (ra1, dec1, pmr1, pmd1, rv1) = (1.234, 0.789, 1e-5, -2e-5, 10.0);
+    let (ep1a, ep1b, ep2a, ep2b) = (2400000.5, 48348.5625, 2400000.5, 51544.5);
+    let mut cov = [[0.0; 6]; 6];
+    let sig = [1e-9, 1e-9, 1e-10, 1e-10, 1e-4, 0.5];
+    for i in 0..6 {
+        cov[i][i] = sig[i] * sig[i];
+    }
+
+    // Plausible parallax: same as eraStarpmCov.
+    let (s2, c2, j) = G25_safe::eraPmsafeCov_safe(
+        ra1, dec1, pmr1, pmd1, 1e-2, rv1, &cov, ep1a, ep1b, ep2a, ep2b,
+    )
+    .unwrap();
+    let (s1, c1, _) = erfa_rust::G30_safe::eraStarpmCov_safe(
+        ra1, dec1, pmr1, pmd1, 1e-2, rv1, &cov, ep1a, ep1b, ep2a, ep2b,
+    )
+    .unwrap();
+    vvd(
+        s2.0,
+        1.234087484501017061,
+        1e-12,
+        "eraPmsafeCov_safe",
+        "ra2",
+        status,
+    );
+    vvd(s2.1, s1.1, 0.0, "eraPmsafeCov_safe", "dec2", status);
+    vvd(c2[1][1], c1[1][1], 0.0, "eraPmsafeCov_safe", "cov", status);
+    viv(j.code(), 0, "eraPmsafeCov_safe", "j", status);
+
+    // Zero parallax: overridden, nominal values as eraPmsafe.
+    let (s2, c2, j) = G25_safe::eraPmsafeCov_safe(
+        ra1, dec1, pmr1, pmd1, 0.0, rv1, &cov, ep1a, ep1b, ep2a, ep2b,
+    )
+    .unwrap();
+    let (s1, _) =
+        G25_safe::eraPmsafe_safe(ra1, dec1, pmr1, pmd1, 0.0, rv1, ep1a, ep1b, ep2a, ep2b).unwrap();
+    vvd(s2.0, s1.0, 0.0, "eraPmsafeCov_safe", "ra2o", status);
+    vvd(s2.4, s1.4, 0.0, "eraPmsafeCov_safe", "px2o", status);
+    viv(
+        j.distance_overridden as i32,
+        1,
+        "eraPmsafeCov_safe",
+        "jo",
+        status,
+    );
+    vvd(
+        c2[4][4].sqrt(),
+        1e-4,
+        1e-6,
+        "eraPmsafeCov_safe",
+        "spx",
+        status,
+    );
+}
+
 fn t_pn(status: &mut i32) {
     let p = [0.3, 1.2, -2.5];
 
@@ -1698,6 +1754,7 @@ fn main() {
     t_pmp(&mut status);
     t_pmpx(&mut status);
     t_pmsafe(&mut status);
+    t_pmsafecov(&mut status);
     t_pn(&mut status);
     t_pn00(&mut status);
     t_pn00a(&mut status);
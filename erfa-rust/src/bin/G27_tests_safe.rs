@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use erfa_rust::G11_safe::Ellipsoid;
 use erfa_rust::G27_safe::*;
 use libc::{c_char, snprintf};
 use std::ffi::CString;
@@ -256,7 +257,7 @@ fn t_pvstar(status: &mut i32) {
     pv[1][1] = -0.6253919754866173866e-2;
     pv[1][2] = 0.1189353719774107189e-1;
 
-    let ((ra, dec, pmr, pmd, px, rv), j) = eraPvstar_safe(&pv).unwrap();
+    let (ra, dec, pmr, pmd, px, rv) = eraPvstar_safe(&pv).unwrap();
 
     vvd(ra, 0.1686756e-1, 1e-12, "eraPvstar_safe", "ra", status);
     vvd(dec, -1.093989828, 1e-12, "eraPvstar_safe", "dec", status);
@@ -285,7 +286,6 @@ fn t_pvstar(status: &mut i32) {
         "rv",
         status,
     );
-    viv(j, 0, "eraPvstar_safe", "j", status);
 }
 
 fn t_pvtob(status: &mut i32) {
@@ -342,6 +342,37 @@ fn t_pvtob(status: &mut i32) {
     vvd(pv[1][2], 0.0, 0.0, "eraPvtob_safe", "v(3)", status);
 }
 
+fn t_pvtobell(status: &mut i32) {
+    let (elong, phi, hm) = (2.0, 0.5, 3000.0);
+    let (xp, yp, sp, theta) = (1e-6, -0.5e-6, 1e-8, 5.0);
+
+    // WGS84 by value is eraPvtob_safe.
+    let pv0 = eraPvtob_safe(elong, phi, hm, xp, yp, sp, theta).unwrap();
+    let pv = eraPvtobEll_safe(&Ellipsoid::WGS84, elong, phi, hm, xp, yp, sp, theta).unwrap();
+    for i in 0..3 {
+        vvd(pv[0][i], pv0[0][i], 0.0, "eraPvtobEll_safe", "p", status);
+        vvd(pv[1][i], pv0[1][i], 0.0, "eraPvtobEll_safe", "v", status);
+    }
+
+    // On a sphere the site is at the radius plus height.
+    let pv = eraPvtobEll_safe(&Ellipsoid::MOON, elong, phi, hm, xp, yp, sp, theta).unwrap();
+    let r = (pv[0][0] * pv[0][0] + pv[0][1] * pv[0][1] + pv[0][2] * pv[0][2]).sqrt();
+    vvd(r, 1_740_400.0, 1e-8, "eraPvtobEll_safe", "r", status);
+
+    // GRS80 and WGS84 differ by 0.1 mm at most.
+    let pv = eraPvtobEll_safe(&Ellipsoid::GRS80, elong, phi, hm, xp, yp, sp, theta).unwrap();
+    for i in 0..3 {
+        vvd(
+            pv[0][i],
+            pv0[0][i],
+            1e-3,
+            "eraPvtobEll_safe",
+            "GRS80",
+            status,
+        );
+    }
+}
+
 fn t_pvu(status: &mut i32) {
     let mut pv = [[0.0; 3]; 2];
     pv[0][0] = 126668.5912743160734;
@@ -504,6 +535,7 @@ fn main() {
     t_pvppv(&mut status);
     t_pvstar(&mut status);
     t_pvtob(&mut status);
+    t_pvtobell(&mut status);
     t_pvu(&mut status);
     t_pvup(&mut status);
     t_pvxpv(&mut status);
@@ -624,7 +624,7 @@ fn t_apco13(status: &mut i32) {
                 "eo",
                 status,
             );
-            viv(j, 0, "eraApco13_safe", "j", status);
+            viv(j.code(), 0, "eraApco13_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -56,6 +56,76 @@ fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32
     }
 }
 
+// Central-difference Jacobian of f at x with steps h, and J C Jᵀ.
+fn fd_cov(
+    f: &dyn Fn(&[f64; 6]) -> [f64; 6],
+    x: &[f64; 6],
+    h: &[f64; 6],
+    cov: &[[f64; 6]; 6],
+) -> [[f64; 6]; 6] {
+    let mut jac = [[0.0; 6]; 6];
+    for k in 0..6 {
+        let (mut xp, mut xm) = (*x, *x);
+        xp[k] += h[k];
+        xm[k] -= h[k];
+        let (yp, ym) = (f(&xp), f(&xm));
+        for i in 0..6 {
+            jac[i][k] = (yp[i] - ym[i]) / (2.0 * h[k]);
+        }
+    }
+    let mut out = [[0.0; 6]; 6];
+    for i in 0..6 {
+        for j in 0..6 {
+            for k in 0..6 {
+                for l in 0..6 {
+                    out[i][j] += jac[i][k] * cov[k][l] * jac[j][l];
+                }
+            }
+        }
+    }
+    out
+}
+
+// Compare covariances element by element, scaled by the standard errors.
+fn vvcov(a: &[[f64; 6]; 6], b: &[[f64; 6]; 6], tol: f64, func: &str, status: &mut i32) {
+    let mut worst = 0.0_f64;
+    for i in 0..6 {
+        for j in 0..6 {
+            let s = (b[i][i] * b[j][j]).sqrt();
+            worst = worst.max((a[i][j] - b[i][j]).abs() / s);
+        }
+    }
+    vvd(worst, 0.0, tol, func, "cov", status);
+}
+
+// Gaia-like covariance (ra, dec, pmr, pmd, px, rv) for t_starpmcov/t_starpvcov.
+fn test_cov(dec: f64) -> [[f64; 6]; 6] {
+    let mas = 4.848136811095359935899141e-9;
+    let sig = [
+        0.2 * mas / dec.cos(),
+        0.15 * mas,
+        0.3 * mas / dec.cos(),
+        0.25 * mas,
+        0.2e-3,
+        1.5,
+    ];
+    let rho = [
+        [1.0, 0.1, -0.3, 0.05, 0.2, 0.0],
+        [0.1, 1.0, 0.05, -0.2, -0.1, 0.0],
+        [-0.3, 0.05, 1.0, 0.15, 0.1, 0.0],
+        [0.05, -0.2, 0.15, 1.0, -0.05, 0.0],
+        [0.2, -0.1, 0.1, -0.05, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+    ];
+    let mut c = [[0.0; 6]; 6];
+    for i in 0..6 {
+        for j in 0..6 {
+            c[i][j] = rho[i][j] * sig[i] * sig[j];
+        }
+    }
+    c
+}
+
 fn t_s06(status: &mut i32) {
     let x = 0.5791308486706011000e-3;
     let y = 0.4020579816732961219e-4;
@@ -143,7 +213,71 @@ fn t_starpm(status: &mut i32) {
     );
     vvd(px2, 0.7473533835317719243, 1e-13, "eraStarpm", "px", status);
     vvd(rv2, -21.59905170476417175, 1e-11, "eraStarpm", "rv", status);
-    viv(j, 0, "eraStarpm", "j", status);
+    viv(j.code(), 0, "eraStarpm", "j", status);
+}
+
+fn t_starpmcov(status: &mut i32) {
+    let x = [
+        0.01686756,
+        -1.093989828,
+        -1.78323516e-5,
+        2.336024047e-6,
+        0.74723,
+        -21.6,
+    ];
+    let (ep1, ep2) = (50083.0, 50083.0 + 100.0 * 365.25);
+    let cov = test_cov(x[1]);
+
+    let (s2, c2, j) = eraStarpmCov_safe(
+        x[0], x[1], x[2], x[3], x[4], x[5], &cov, 2400000.5, ep1, 2400000.5, ep2,
+    )
+    .unwrap();
+    let (s1, _) = eraStarpm_safe(
+        x[0], x[1], x[2], x[3], x[4], x[5], 2400000.5, ep1, 2400000.5, ep2,
+    )
+    .unwrap();
+    vvd(s2.0, s1.0, 0.0, "eraStarpmCov", "ra", status);
+    vvd(s2.4, s1.4, 0.0, "eraStarpmCov", "px", status);
+    viv(j.code(), 0, "eraStarpmCov", "j", status);
+    vvd(c2[0][3], c2[3][0], 0.0, "eraStarpmCov", "sym", status);
+
+    // Against finite differences of the full model, light time included.
+    let f = |y: &[f64; 6]| {
+        let (s, _) = eraStarpm_safe(
+            y[0], y[1], y[2], y[3], y[4], y[5], 2400000.5, ep1, 2400000.5, ep2,
+        )
+        .unwrap();
+        [s.0, s.1, s.2, s.3, s.4, s.5]
+    };
+    let h = [1e-8, 1e-8, 1e-10, 1e-10, 1e-5, 1e-3];
+    // The order of eraStarpm's arguments is (ra, dec, pmr, pmd, px, rv).
+    vvcov(&c2, &fd_cov(&f, &x, &h, &cov), 1e-3, "eraStarpmCov", status);
+
+    // Zero interval leaves the covariance unchanged.
+    let (_, c0, _) = eraStarpmCov_safe(
+        x[0], x[1], x[2], x[3], x[4], x[5], &cov, 2400000.5, ep1, 2400000.5, ep1,
+    )
+    .unwrap();
+    vvcov(&c0, &cov, 1e-9, "eraStarpmCov", status);
+
+    // Uncorrelated, small parallax: Dec variance grows as σδ² + t² σμδ².
+    let mut d = [[0.0; 6]; 6];
+    let sig = [1e-9, 1e-9, 1e-9, 2e-9, 1e-4, 1.0];
+    for i in 0..6 {
+        d[i][i] = sig[i] * sig[i];
+    }
+    let (_, cd, _) = eraStarpmCov_safe(
+        0.5, 0.3, 0.0, 0.0, 1e-3, 0.0, &d, 2400000.5, ep1, 2400000.5, ep2,
+    )
+    .unwrap();
+    vvd(
+        cd[1][1].sqrt(),
+        (1e-18 + 1e4 * 4e-18_f64).sqrt(),
+        1e-15,
+        "eraStarpmCov",
+        "sdec",
+        status,
+    );
 }
 
 fn t_starpv(status: &mut i32) {
@@ -204,7 +338,34 @@ fn t_starpv(status: &mut i32) {
         "23",
         status,
     );
-    viv(j, 0, "eraStarpv", "j", status);
+    viv(j.code(), 0, "eraStarpv", "j", status);
+}
+
+fn t_starpvcov(status: &mut i32) {
+    let x = [
+        0.01686756,
+        -1.093989828,
+        -1.78323516e-5,
+        2.336024047e-6,
+        0.74723,
+        -21.6,
+    ];
+    let cov = test_cov(x[1]);
+
+    let (pv, c, j) = eraStarpvCov_safe(x[0], x[1], x[2], x[3], x[4], x[5], &cov).unwrap();
+    let (pv0, _) = eraStarpv_safe(x[0], x[1], x[2], x[3], x[4], x[5]).unwrap();
+    vvd(pv[0][0], pv0[0][0], 0.0, "eraStarpvCov", "p", status);
+    vvd(pv[1][2], pv0[1][2], 0.0, "eraStarpvCov", "v", status);
+    viv(j.code(), 0, "eraStarpvCov", "j", status);
+
+    let f = |y: &[f64; 6]| {
+        let (pv, _) = eraStarpv_safe(y[0], y[1], y[2], y[3], y[4], y[5]).unwrap();
+        [pv[0][0], pv[0][1], pv[0][2], pv[1][0], pv[1][1], pv[1][2]]
+    };
+    let h = [1e-8, 1e-8, 1e-10, 1e-10, 1e-5, 1e-3];
+    // The Jacobian omits the relativistic terms, which couple pmr into the
+    // radial velocity at the 1e-2 level of the standard errors.
+    vvcov(&c, &fd_cov(&f, &x, &h, &cov), 1e-2, "eraStarpvCov", status);
 }
 
 fn t_sxp(status: &mut i32) {
@@ -246,7 +407,9 @@ fn main() {
     t_seps(&mut status);
     t_sp00(&mut status);
     t_starpm(&mut status);
+    t_starpmcov(&mut status);
     t_starpv(&mut status);
+    t_starpvcov(&mut status);
     t_sxp(&mut status);
     t_sxpv(&mut status);
 
@@ -192,6 +192,19 @@ fn main() {
         "G33_tests_safe",
         "G34_tests_safe",
         "G35_tests_safe",
+        "time_tests_safe",
+        "eop_tests_safe",
+        "sexagesimal_tests_safe",
+        "spk_tests_safe",
+        "bodies_tests_safe",
+        "events_tests_safe",
+        "almanac_tests_safe",
+        "phenomena_tests_safe",
+        "eclipses_tests_safe",
+        "sgp4_tests_safe",
+        "refraction_tests_safe",
+        "tangent_tests_safe",
+        "dual_tests_safe",
         "t_erfa_c_extra_safe",
     ];
 
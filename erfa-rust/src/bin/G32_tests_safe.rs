@@ -58,64 +58,58 @@ fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32
 }
 
 fn t_taitt(status: &mut i32) {
-    let ((t1, t2), j) = eraTaitt_safe(2453750.5, 0.892482639).unwrap();
+    let (t1, t2) = eraTaitt_safe(2453750.5, 0.892482639).unwrap();
     vvd(t1, 2453750.5, 1e-6, "eraTaitt", "t1", status);
     vvd(t2, 0.892855139, 1e-12, "eraTaitt", "t2", status);
-    viv(j, 0, "eraTaitt", "j", status);
 }
 
 fn t_taiut1(status: &mut i32) {
-    let ((u1, u2), j) = eraTaiut1_safe(2453750.5, 0.892482639, -32.6659).unwrap();
+    let (u1, u2) = eraTaiut1_safe(2453750.5, 0.892482639, -32.6659).unwrap();
     vvd(u1, 2453750.5, 1e-6, "eraTaiut1", "u1", status);
     vvd(u2, 0.8921045614537037037, 1e-12, "eraTaiut1", "u2", status);
-    viv(j, 0, "eraTaiut1", "j", status);
 }
 
 fn t_taiutc(status: &mut i32) {
     let ((u1, u2), j) = eraTaiutc_safe(2453750.5, 0.892482639).unwrap();
     vvd(u1, 2453750.5, 1e-6, "eraTaiutc", "u1", status);
     vvd(u2, 0.8921006945555555556, 1e-12, "eraTaiutc", "u2", status);
-    viv(j, 0, "eraTaiutc", "j", status);
+    viv(j.code(), 0, "eraTaiutc", "j", status);
 }
 
 fn t_tcbtdb(status: &mut i32) {
-    let ((b1, b2), j) = eraTcbtdb_safe(2453750.5, 0.893019599).unwrap();
+    let (b1, b2) = eraTcbtdb_safe(2453750.5, 0.893019599).unwrap();
     vvd(b1, 2453750.5, 1e-6, "eraTcbtdb", "b1", status);
     vvd(b2, 0.8928551362746343397, 1e-12, "eraTcbtdb", "b2", status);
-    viv(j, 0, "eraTcbtdb", "j", status);
 }
 
 fn t_tcgtt(status: &mut i32) {
-    let ((t1, t2), j) = eraTcgtt_safe(2453750.5, 0.892862531).unwrap();
+    let (t1, t2) = eraTcgtt_safe(2453750.5, 0.892862531).unwrap();
     vvd(t1, 2453750.5, 1e-6, "eraTcgtt", "t1", status);
     vvd(t2, 0.8928551387488816828, 1e-12, "eraTcgtt", "t2", status);
-    viv(j, 0, "eraTcgtt", "j", status);
 }
 
 fn t_tdbtcb(status: &mut i32) {
-    let ((b1, b2), j) = eraTdbtcb_safe(2453750.5, 0.892855137).unwrap();
+    let (b1, b2) = eraTdbtcb_safe(2453750.5, 0.892855137).unwrap();
     vvd(b1, 2453750.5, 1e-6, "eraTdbtcb", "b1", status);
     vvd(b2, 0.8930195997253656716, 1e-12, "eraTdbtcb", "b2", status);
-    viv(j, 0, "eraTdbtcb", "j", status);
 }
 
 fn t_tdbtt(status: &mut i32) {
-    let ((t1, t2), j) = eraTdbtt_safe(2453750.5, 0.892855137, -0.000201).unwrap();
+    let (t1, t2) = eraTdbtt_safe(2453750.5, 0.892855137, -0.000201).unwrap();
     vvd(t1, 2453750.5, 1e-6, "eraTdbtt", "t1", status);
     vvd(t2, 0.8928551393263888889, 1e-12, "eraTdbtt", "t2", status);
-    viv(j, 0, "eraTdbtt", "j", status);
 }
 
 fn t_tf2a(status: &mut i32) {
     let (a, j) = eraTf2a_safe('+', 4, 58, 20.2).unwrap();
     vvd(a, 1.301739278189537429, 1e-12, "eraTf2a", "a", status);
-    viv(j, 0, "eraTf2a", "j", status);
+    viv(j.code(), 0, "eraTf2a", "j", status);
 }
 
 fn t_tf2d(status: &mut i32) {
     let (d, j) = eraTf2d_safe(' ', 23, 55, 10.9).unwrap();
     vvd(d, 0.9966539351851851852, 1e-12, "eraTf2d", "d", status);
-    viv(j, 0, "eraTf2d", "j", status);
+    viv(j.code(), 0, "eraTf2d", "j", status);
 }
 
 fn t_tpors(status: &mut i32) {
@@ -124,7 +118,7 @@ fn t_tpors(status: &mut i32) {
     vvd(bz1, 1.436736561844090323, 1e-13, "eraTpors", "bz1", status);
     vvd(az2, 4.004971075806584490, 1e-13, "eraTpors", "az2", status);
     vvd(bz2, 1.565084088476417917, 1e-13, "eraTpors", "bz2", status);
-    viv(n, 2, "eraTpors", "n", status);
+    viv(n.code(), 2, "eraTpors", "n", status);
 }
 
 fn t_tporv(status: &mut i32) {
@@ -178,7 +172,7 @@ fn t_tporv(status: &mut i32) {
         "z2",
         status,
     );
-    viv(n, 2, "eraTporv", "n", status);
+    viv(n.code(), 2, "eraTporv", "n", status);
 }
 
 fn t_tpsts(status: &mut i32) {
@@ -206,7 +200,7 @@ fn t_tpxes(status: &mut i32) {
         "eta",
         status,
     );
-    viv(j, 0, "eraTpxes", "j", status);
+    viv(j.code(), 0, "eraTpxes", "j", status);
 }
 
 fn t_tpxev(status: &mut i32) {
@@ -222,7 +216,7 @@ fn t_tpxev(status: &mut i32) {
         "eta",
         status,
     );
-    viv(j, 0, "eraTpxev", "j", status);
+    viv(j.code(), 0, "eraTpxev", "j", status);
 }
 
 fn main() {
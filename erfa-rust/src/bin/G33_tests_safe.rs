@@ -92,14 +92,13 @@ fn t_trxpv(status: &mut i32) {
 }
 
 fn t_tttai(status: &mut i32) {
-    let ((a1, a2), j) = eraTttai_safe(2453750.5, 0.892482639).unwrap();
+    let (a1, a2) = eraTttai_safe(2453750.5, 0.892482639).unwrap();
     vvd(a1, 2453750.5, 1e-6, "eraTttai_safe", "a1", status);
     vvd(a2, 0.892110139, 1e-12, "eraTttai_safe", "a2", status);
-    viv(j, 0, "eraTttai_safe", "j", status);
 }
 
 fn t_tttcg(status: &mut i32) {
-    let ((g1, g2), j) = eraTttcg_safe(2453750.5, 0.892482639).unwrap();
+    let (g1, g2) = eraTttcg_safe(2453750.5, 0.892482639).unwrap();
     vvd(g1, 2453750.5, 1e-6, "eraTttcg_safe", "g1", status);
     vvd(
         g2,
@@ -109,11 +108,10 @@ fn t_tttcg(status: &mut i32) {
         "g2",
         status,
     );
-    viv(j, 0, "eraTttcg_safe", "j", status);
 }
 
 fn t_tttdb(status: &mut i32) {
-    let ((b1, b2), j) = eraTttdb_safe(2453750.5, 0.892855139, -0.000201).unwrap();
+    let (b1, b2) = eraTttdb_safe(2453750.5, 0.892855139, -0.000201).unwrap();
     vvd(b1, 2453750.5, 1e-6, "eraTttdb_safe", "b1", status);
     vvd(
         b2,
@@ -123,11 +121,10 @@ fn t_tttdb(status: &mut i32) {
         "b2",
         status,
     );
-    viv(j, 0, "eraTttdb_safe", "j", status);
 }
 
 fn t_ttut1(status: &mut i32) {
-    let ((u1, u2), j) = eraTtut1_safe(2453750.5, 0.892855139, 64.8499).unwrap();
+    let (u1, u2) = eraTtut1_safe(2453750.5, 0.892855139, 64.8499).unwrap();
     vvd(u1, 2453750.5, 1e-6, "eraTtut1_safe", "u1", status);
     vvd(
         u2,
@@ -137,11 +134,10 @@ fn t_ttut1(status: &mut i32) {
         "u2",
         status,
     );
-    viv(j, 0, "eraTtut1_safe", "j", status);
 }
 
 fn t_ut1tai(status: &mut i32) {
-    let ((a1, a2), j) = eraUt1tai_safe(2453750.5, 0.892104561, -32.6659).unwrap();
+    let (a1, a2) = eraUt1tai_safe(2453750.5, 0.892104561, -32.6659).unwrap();
     vvd(a1, 2453750.5, 1e-6, "eraUt1tai_safe", "a1", status);
     vvd(
         a2,
@@ -151,11 +147,10 @@ fn t_ut1tai(status: &mut i32) {
         "a2",
         status,
     );
-    viv(j, 0, "eraUt1tai_safe", "j", status);
 }
 
 fn t_ut1tt(status: &mut i32) {
-    let ((t1, t2), j) = eraUt1tt_safe(2453750.5, 0.892104561, 64.8499).unwrap();
+    let (t1, t2) = eraUt1tt_safe(2453750.5, 0.892104561, 64.8499).unwrap();
     vvd(t1, 2453750.5, 1e-6, "eraUt1tt_safe", "t1", status);
     vvd(
         t2,
@@ -165,7 +160,6 @@ fn t_ut1tt(status: &mut i32) {
         "t2",
         status,
     );
-    viv(j, 0, "eraUt1tt_safe", "j", status);
 }
 
 fn t_ut1utc(status: &mut i32) {
@@ -179,7 +173,7 @@ fn t_ut1utc(status: &mut i32) {
         "u2",
         status,
     );
-    viv(j, 0, "eraUt1utc_safe", "j", status);
+    viv(j.code(), 0, "eraUt1utc_safe", "j", status);
 }
 
 fn t_utctai(status: &mut i32) {
@@ -193,7 +187,7 @@ fn t_utctai(status: &mut i32) {
         "u2",
         status,
     );
-    viv(j, 0, "eraUtctai_safe", "j", status);
+    viv(j.code(), 0, "eraUtctai_safe", "j", status);
 }
 
 fn t_utcut1(status: &mut i32) {
@@ -207,7 +201,7 @@ fn t_utcut1(status: &mut i32) {
         "u2",
         status,
     );
-    viv(j, 0, "eraUtcut1_safe", "j", status);
+    viv(j.code(), 0, "eraUtcut1_safe", "j", status);
 }
 
 fn main() {
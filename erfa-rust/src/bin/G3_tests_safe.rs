@@ -63,6 +63,30 @@ fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32
     }
 }
 
+// Worst difference between jac and central differences of f at x with
+// steps h, each column scaled by its largest finite difference.
+fn fd_check<const N: usize, const M: usize>(
+    f: &dyn Fn(&[f64; N]) -> [f64; M],
+    x: &[f64; N],
+    h: &[f64; N],
+    jac: &[[f64; N]; M],
+) -> f64 {
+    let mut worst = 0.0_f64;
+    for k in 0..N {
+        let (mut xp, mut xm) = (*x, *x);
+        xp[k] += h[k];
+        xm[k] -= h[k];
+        let (yp, ym) = (f(&xp), f(&xm));
+        let fd: Vec<f64> = (0..M).map(|i| (yp[i] - ym[i]) / (2.0 * h[k])).collect();
+        let scale = fd.iter().fold(0.0_f64, |a, b| a.max(b.abs()));
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+        for i in 0..M {
+            worst = worst.max((jac[i][k] - fd[i]).abs() / scale);
+        }
+    }
+    worst
+}
+
 fn t_aper13(status: &mut i32) {
     let ut11 = 2456165.5;
     let ut12 = 0.401182685;
@@ -185,6 +209,77 @@ fn t_apio(status: &mut i32) {
     }
 }
 
+fn t_apiojac(status: &mut i32) {
+    let sp = -3.01974337e-11;
+    let x = [
+        -0.527800806,
+        -1.2345856,
+        2.47230737e-7,
+        1.82640464e-6,
+        3.14540971,
+    ];
+    let (hm, refa, refb) = (2738.0, 0.000201418779, -2.36140831e-7);
+    let mut astrom: H1_safe::eraASTROM = Default::default();
+
+    let jac = G3_safe::eraApioJac_safe(
+        sp,
+        x[4],
+        x[0],
+        x[1],
+        hm,
+        x[2],
+        x[3],
+        refa,
+        refb,
+        &mut astrom,
+    )
+    .unwrap();
+    vvd(
+        astrom.xpl,
+        0.1133427418130752958e-5,
+        1e-17,
+        "eraApioJac_safe",
+        "xpl",
+        status,
+    );
+
+    let f = |y: &[f64; 5]| {
+        let mut a: H1_safe::eraASTROM = Default::default();
+        G3_safe::eraApio_safe(sp, y[4], y[0], y[1], hm, y[2], y[3], refa, refb, &mut a).unwrap();
+        [a.eral, a.xpl, a.ypl, a.sphi.atan2(a.cphi)]
+    };
+    let h = [1e-6, 1e-6, 1e-6, 1e-6, 1e-6];
+    vvd(
+        fd_check(&f, &x, &h, &jac),
+        0.0,
+        1e-8,
+        "eraApioJac_safe",
+        "jac",
+        status,
+    );
+
+    // DUT1 (s) through the theta column.
+    let (utc1, utc2, dut1) = (2456384.5, 0.969254051, 0.1550675);
+    let eral = |dut1: f64| {
+        let mut a: H1_safe::eraASTROM = Default::default();
+        G3_safe::eraApio13_safe(
+            utc1, utc2, dut1, x[0], x[1], hm, x[2], x[3], 0.0, 0.0, 0.0, 0.0, &mut a,
+        )
+        .unwrap();
+        a.eral
+    };
+    let fd = (eral(dut1 + 0.1) - eral(dut1 - 0.1)) / 0.2;
+    let rate = 2.0 * std::f64::consts::PI * 1.00273781191135448 / 86400.0;
+    vvd(
+        jac[0][4] * rate,
+        fd,
+        1e-13,
+        "eraApioJac_safe",
+        "dut1",
+        status,
+    );
+}
+
 fn t_apio13(status: &mut i32) {
     let utc1 = 2456384.5;
     let utc2 = 0.969254051;
@@ -290,7 +385,7 @@ fn t_apio13(status: &mut i32) {
                 "refb",
                 status,
             );
-            viv(j, 0, "eraApio13_safe", "j", status);
+            viv(j.code(), 0, "eraApio13_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -398,6 +493,7 @@ fn main() {
 
     t_aper13(&mut status);
     t_apio(&mut status);
+    t_apiojac(&mut status);
     t_apio13(&mut status);
     t_atcc13(&mut status);
     t_atccq(&mut status);
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
+use erfa_rust::refraction;
 use erfa_rust::G1_safe;
+use erfa_rust::G28_safe;
 use erfa_rust::G3_safe;
 use erfa_rust::G4_safe;
 use erfa_rust::H1_safe;
@@ -67,6 +69,30 @@ fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32
     }
 }
 
+// Worst difference between jac and central differences of f at x with
+// steps h, each column scaled by its largest finite difference.
+fn fd_check<const N: usize, const M: usize>(
+    f: &dyn Fn(&[f64; N]) -> [f64; M],
+    x: &[f64; N],
+    h: &[f64; N],
+    jac: &[[f64; N]; M],
+) -> f64 {
+    let mut worst = 0.0_f64;
+    for k in 0..N {
+        let (mut xp, mut xm) = (*x, *x);
+        xp[k] += h[k];
+        xm[k] -= h[k];
+        let (yp, ym) = (f(&xp), f(&xm));
+        let fd: Vec<f64> = (0..M).map(|i| (yp[i] - ym[i]) / (2.0 * h[k])).collect();
+        let scale = fd.iter().fold(0.0_f64, |a, b| a.max(b.abs()));
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+        for i in 0..M {
+            worst = worst.max((jac[i][k] - fd[i]).abs() / scale);
+        }
+    }
+    worst
+}
+
 fn t_atci13(status: &mut i32) {
     let rc = 2.71;
     let dc = 0.174;
@@ -159,6 +185,32 @@ fn t_atciq(status: &mut i32) {
     }
 }
 
+fn t_atciqjac(status: &mut i32) {
+    let x = [2.71, 0.174, 1e-5, 5e-6, 0.1, 55.0];
+    let mut astrom = H1_safe::eraASTROM::default();
+    G1_safe::eraApci13_safe(2456165.5, 0.401182685, &mut astrom).unwrap();
+
+    let ((ri, di), jac) =
+        G4_safe::eraAtciqJac_safe(x[0], x[1], x[2], x[3], x[4], x[5], &astrom).unwrap();
+    let (ri0, di0) = G4_safe::eraAtciq_safe(x[0], x[1], x[2], x[3], x[4], x[5], &astrom).unwrap();
+    vvd(ri, ri0, 0.0, "eraAtciqJac_safe", "ri", status);
+    vvd(di, di0, 0.0, "eraAtciqJac_safe", "di", status);
+
+    let f = |y: &[f64; 6]| {
+        let (ri, di) = G4_safe::eraAtciq_safe(y[0], y[1], y[2], y[3], y[4], y[5], &astrom).unwrap();
+        [ri, di]
+    };
+    let h = [1e-6, 1e-6, 1e-8, 1e-8, 1e-2, 10.0];
+    vvd(
+        fd_check(&f, &x, &h, &jac),
+        0.0,
+        1e-6,
+        "eraAtciqJac_safe",
+        "jac",
+        status,
+    );
+}
+
 fn t_atciqn(status: &mut i32) {
     let mut b: [H1_safe::eraLDBODY; 3] = [H1_safe::eraLDBODY::default(); 3];
     let date1 = 2456165.5;
@@ -347,7 +399,7 @@ fn t_atco13(status: &mut i32) {
                 "eo",
                 status,
             );
-            viv(j, 0, "eraAtco13_safe", "j", status);
+            viv(j.code(), 0, "eraAtco13_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -570,7 +622,7 @@ fn t_atio13(status: &mut i32) {
                 "rob",
                 status,
             );
-            viv(j, 0, "eraAtio13_safe", "j", status);
+            viv(j.code(), 0, "eraAtio13_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -669,6 +721,165 @@ fn t_atioq(status: &mut i32) {
     }
 }
 
+fn t_atioqjac(status: &mut i32) {
+    let mut astrom = H1_safe::eraASTROM::default();
+    G3_safe::eraApio13_safe(
+        2456384.5,
+        0.969254051,
+        0.1550675,
+        -0.527800806,
+        -1.2345856,
+        2738.0,
+        2.47230737e-7,
+        1.82640464e-6,
+        731.0,
+        12.8,
+        0.59,
+        0.55,
+        &mut astrom,
+    )
+    .unwrap();
+    let (ri, di) = (2.710121572969038991, 0.1729371367218230438);
+
+    let (obs, jac) = G4_safe::eraAtioqJac_safe(ri, di, &astrom).unwrap();
+    let obs0 = G4_safe::eraAtioq_safe(ri, di, &astrom).unwrap();
+    vvd(obs.1, obs0.1, 0.0, "eraAtioqJac_safe", "zob", status);
+    vvd(obs.4, obs0.4, 0.0, "eraAtioqJac_safe", "rob", status);
+
+    let x = [
+        ri,
+        di,
+        astrom.eral,
+        astrom.xpl,
+        astrom.ypl,
+        astrom.sphi.atan2(astrom.cphi),
+    ];
+    let f = |y: &[f64; 6]| {
+        let mut a = astrom;
+        a.eral = y[2];
+        a.xpl = y[3];
+        a.ypl = y[4];
+        (a.sphi, a.cphi) = y[5].sin_cos();
+        let (aob, zob, hob, dob, rob) = G4_safe::eraAtioq_safe(y[0], y[1], &a).unwrap();
+        [aob, zob, hob, dob, rob]
+    };
+    let h = [1e-5; 6];
+    vvd(
+        fd_check(&f, &x, &h, &jac),
+        0.0,
+        1e-6,
+        "eraAtioqJac_safe",
+        "jac",
+        status,
+    );
+}
+
+fn t_atioqref(status: &mut i32) {
+    let ri = 2.710121572969038991;
+    let di = 0.1729371367218230438;
+    let mut astrom = H1_safe::eraASTROM::default();
+    if G3_safe::eraApio13_safe(
+        2456384.5,
+        0.969254051,
+        0.1550675,
+        -0.527800806,
+        -1.2345856,
+        2738.0,
+        2.47230737e-7,
+        1.82640464e-6,
+        731.0,
+        12.8,
+        0.59,
+        0.55,
+        &mut astrom,
+    )
+    .is_err()
+    {
+        *status = 1;
+        println!("eraApio13_safe failed in t_atioqref");
+        return;
+    }
+
+    // The two-term model reproduces eraAtioq_safe, which applies the
+    // refraction as a small rotation: to well under 1 mas here.
+    let model = G28_safe::TwoTerm::from_astrom(&astrom);
+    match G4_safe::eraAtioqRef_safe(ri, di, &astrom, &model) {
+        Ok((aob, zob, hob, dob, rob)) => {
+            vvd(
+                aob,
+                0.9233952224895122499e-1,
+                1e-12,
+                "eraAtioqRef_safe",
+                "aob",
+                status,
+            );
+            vvd(
+                zob,
+                1.407758704513549991,
+                1e-9,
+                "eraAtioqRef_safe",
+                "zob",
+                status,
+            );
+            vvd(
+                hob,
+                -0.9247619879881698140e-1,
+                1e-9,
+                "eraAtioqRef_safe",
+                "hob",
+                status,
+            );
+            vvd(
+                dob,
+                0.1717653435756234676,
+                1e-9,
+                "eraAtioqRef_safe",
+                "dob",
+                status,
+            );
+            vvd(
+                rob,
+                2.710085107988480746,
+                1e-9,
+                "eraAtioqRef_safe",
+                "rob",
+                status,
+            );
+        }
+        Err(_) => {
+            *status = 1;
+            println!("eraAtioqRef_safe failed: unexpected error");
+        }
+    }
+
+    // Ray tracing agrees with it 7.4° above the horizon to about 1″.
+    let model = refraction::RayTrace::new(731.0, 12.8, 0.59, 0.55, 2738.0, -1.2345856);
+    match G4_safe::eraAtioqRef_safe(ri, di, &astrom, &model) {
+        Ok((aob, zob, _, _, _)) => {
+            vvd(
+                aob,
+                0.9233952224895122499e-1,
+                1e-12,
+                "eraAtioqRef_safe",
+                "ray aob",
+                status,
+            );
+            vvd(
+                zob,
+                1.407758704513549991,
+                5e-6,
+                "eraAtioqRef_safe",
+                "ray zob",
+                status,
+            );
+        }
+        Err(_) => {
+            *status = 1;
+            println!("eraAtioqRef_safe failed: unexpected error");
+        }
+    }
+}
+
 fn main() {
     let args: Vec<String> = std::env::args().collect();
     if args.len() > 1 {
@@ -681,6 +892,7 @@ fn main() {
 
     t_atci13(&mut status);
     t_atciq(&mut status);
+    t_atciqjac(&mut status);
     t_atciqn(&mut status);
     t_atciqz(&mut status);
     t_atco13(&mut status);
@@ -689,6 +901,8 @@ fn main() {
     t_aticqn(&mut status);
     t_atio13(&mut status);
     t_atioq(&mut status);
+    t_atioqjac(&mut status);
+    t_atioqref(&mut status);
 
     if status != 0 {
         println!("t_erfa_c validation failed!");
@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
+use erfa_rust::refraction;
+use erfa_rust::G28_safe;
 use erfa_rust::G3_safe;
+use erfa_rust::G4_safe;
 use erfa_rust::G5_safe;
 use erfa_rust::H1_safe;
 use libc::{c_char, snprintf};
@@ -65,6 +68,30 @@ fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32
     }
 }
 
+// Worst difference between jac and central differences of f at x with
+// steps h, each column scaled by its largest finite difference.
+fn fd_check<const N: usize, const M: usize>(
+    f: &dyn Fn(&[f64; N]) -> [f64; M],
+    x: &[f64; N],
+    h: &[f64; N],
+    jac: &[[f64; N]; M],
+) -> f64 {
+    let mut worst = 0.0_f64;
+    for k in 0..N {
+        let (mut xp, mut xm) = (*x, *x);
+        xp[k] += h[k];
+        xm[k] -= h[k];
+        let (yp, ym) = (f(&xp), f(&xm));
+        let fd: Vec<f64> = (0..M).map(|i| (yp[i] - ym[i]) / (2.0 * h[k])).collect();
+        let scale = fd.iter().fold(0.0_f64, |a, b| a.max(b.abs()));
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+        for i in 0..M {
+            worst = worst.max((jac[i][k] - fd[i]).abs() / scale);
+        }
+    }
+    worst
+}
+
 fn t_atoc13(status: &mut i32) {
     let utc1 = 2456384.5;
     let utc2 = 0.969254051;
@@ -107,7 +134,7 @@ fn t_atoc13(status: &mut i32) {
                 *status = 1;
                 println!("eraAtoc13_safe failed: invalid eo value");
             }
-            viv(j, 0, "eraAtoc13_safe", "R/j", status);
+            viv(j.code(), 0, "eraAtoc13_safe", "R/j", status);
         }
         Err(_) => {
             *status = 1;
@@ -143,7 +170,7 @@ fn t_atoc13(status: &mut i32) {
                 *status = 1;
                 println!("eraAtoc13_safe failed: invalid eo value");
             }
-            viv(j, 0, "eraAtoc13_safe", "H/j", status);
+            viv(j.code(), 0, "eraAtoc13_safe", "H/j", status);
         }
         Err(_) => {
             *status = 1;
@@ -179,7 +206,7 @@ fn t_atoc13(status: &mut i32) {
                 *status = 1;
                 println!("eraAtoc13_safe failed: invalid eo value");
             }
-            viv(j, 0, "eraAtoc13_safe", "A/j", status);
+            viv(j.code(), 0, "eraAtoc13_safe", "A/j", status);
         }
         Err(_) => {
             *status = 1;
@@ -226,7 +253,7 @@ fn t_atoi13(status: &mut i32) {
                 "R/di",
                 status,
             );
-            viv(j, 0, "eraAtoi13_safe", "R/J", status);
+            viv(j.code(), 0, "eraAtoi13_safe", "R/J", status);
         }
         Err(_) => {
             *status = 1;
@@ -258,7 +285,7 @@ fn t_atoi13(status: &mut i32) {
                 "H/di",
                 status,
             );
-            viv(j, 0, "eraAtoi13_safe", "H/J", status);
+            viv(j.code(), 0, "eraAtoi13_safe", "H/J", status);
         }
         Err(_) => {
             *status = 1;
@@ -290,7 +317,7 @@ fn t_atoi13(status: &mut i32) {
                 "A/di",
                 status,
             );
-            viv(j, 0, "eraAtoi13_safe", "A/J", status);
+            viv(j.code(), 0, "eraAtoi13_safe", "A/J", status);
         }
         Err(_) => {
             *status = 1;
@@ -426,6 +453,144 @@ fn t_atoiq(status: &mut i32) {
     }
 }
 
+fn t_atoiqjac(status: &mut i32) {
+    let mut astrom = H1_safe::eraASTROM::default();
+    G3_safe::eraApio13_safe(
+        2456384.5,
+        0.969254051,
+        0.1550675,
+        -0.527800806,
+        -1.2345856,
+        2738.0,
+        2.47230737e-7,
+        1.82640464e-6,
+        731.0,
+        12.8,
+        0.59,
+        0.55,
+        &mut astrom,
+    )
+    .unwrap();
+    let phi = astrom.sphi.atan2(astrom.cphi);
+
+    for (type_, ob1, ob2) in [
+        ("R", 2.710085107986886201, 0.1717653435758265198),
+        ("H", -0.09247619879782006106, 0.1717653435758265198),
+        ("A", 0.09233952224794989993, 1.407758704513722461),
+    ] {
+        let ((ri, di), jac) = G5_safe::eraAtoiqJac_safe(type_, ob1, ob2, &astrom).unwrap();
+        let (ri0, di0) = G5_safe::eraAtoiq_safe(type_, ob1, ob2, &astrom).unwrap();
+        vvd(ri, ri0, 0.0, "eraAtoiqJac_safe", type_, status);
+        vvd(di, di0, 0.0, "eraAtoiqJac_safe", type_, status);
+
+        let x = [ob1, ob2, astrom.eral, astrom.xpl, astrom.ypl, phi];
+        let f = |y: &[f64; 6]| {
+            let mut a = astrom;
+            a.eral = y[2];
+            a.xpl = y[3];
+            a.ypl = y[4];
+            (a.sphi, a.cphi) = y[5].sin_cos();
+            let (ri, di) = G5_safe::eraAtoiq_safe(type_, y[0], y[1], &a).unwrap();
+            [ri, di]
+        };
+        let h = [1e-5; 6];
+        vvd(
+            fd_check(&f, &x, &h, &jac),
+            0.0,
+            1e-6,
+            "eraAtoiqJac_safe",
+            type_,
+            status,
+        );
+    }
+}
+
+fn t_atoiqref(status: &mut i32) {
+    let mut astrom = H1_safe::eraASTROM::default();
+    if G3_safe::eraApio13_safe(
+        2456384.5,
+        0.969254051,
+        0.1550675,
+        -0.527800806,
+        -1.2345856,
+        2738.0,
+        2.47230737e-7,
+        1.82640464e-6,
+        731.0,
+        12.8,
+        0.59,
+        0.55,
+        &mut astrom,
+    )
+    .is_err()
+    {
+        *status = 1;
+        println!("eraApio13_safe failed in t_atoiqref");
+        return;
+    }
+
+    // The two-term model reproduces eraAtoiq_safe for each coordinate type.
+    let model = G28_safe::TwoTerm::from_astrom(&astrom);
+    let cases = [
+        ("R", 2.710085107986886201, 0.1717653435758265198),
+        ("H", -0.09247619879782006106, 0.1717653435758265198),
+        ("A", 0.09233952224794989993, 1.407758704513722461),
+    ];
+    for (type_, ob1, ob2) in cases {
+        match (
+            G5_safe::eraAtoiqRef_safe(type_, ob1, ob2, &astrom, &model),
+            G5_safe::eraAtoiq_safe(type_, ob1, ob2, &astrom),
+        ) {
+            (Ok((ri, di)), Ok((ri0, di0))) => {
+                vvd(ri, ri0, 1e-12, "eraAtoiqRef_safe", type_, status);
+                vvd(di, di0, 1e-12, "eraAtoiqRef_safe", type_, status);
+            }
+            _ => {
+                *status = 1;
+                println!("eraAtoiqRef_safe failed: unexpected error for {}", type_);
+            }
+        }
+    }
+
+    // Each model inverts eraAtioqRef_safe, down to the horizon.
+    let models: [(&str, &dyn G28_safe::Refraction); 3] = [
+        (
+            "ray",
+            &refraction::RayTrace::new(731.0, 12.8, 0.59, 0.55, 2738.0, -1.2345856),
+        ),
+        (
+            "bennett",
+            &refraction::Bennett {
+                phpa: 731.0,
+                tc: 12.8,
+            },
+        ),
+        ("itu", &refraction::ItuR { hm: 2738.0 }),
+    ];
+    for (name, model) in models {
+        for zob in [1.3, 1.5, 1.56, 1.5708] {
+            let (ri, di) = match G5_safe::eraAtoiqRef_safe("A", 2.5, zob, &astrom, model) {
+                Ok(r) => r,
+                Err(_) => {
+                    *status = 1;
+                    println!("eraAtoiqRef_safe failed: unexpected error for {}", name);
+                    continue;
+                }
+            };
+            match G4_safe::eraAtioqRef_safe(ri, di, &astrom, model) {
+                Ok((aob, zob2, _, _, _)) => {
+                    vvd(aob, 2.5, 1e-11, "eraAtoiqRef_safe", name, status);
+                    vvd(zob2, zob, 1e-11, "eraAtoiqRef_safe", name, status);
+                }
+                Err(_) => {
+                    *status = 1;
+                    println!("eraAtioqRef_safe failed: unexpected error for {}", name);
+                }
+            }
+        }
+    }
+}
+
 fn main() {
     let args: Vec<String> = std::env::args().collect();
     if args.len() > 1 {
@@ -439,6 +604,8 @@ fn main() {
     t_atoc13(&mut status);
     t_atoi13(&mut status);
     t_atoiq(&mut status);
+    t_atoiqjac(&mut status);
+    t_atoiqref(&mut status);
 
     if status != 0 {
         println!("t_erfa_c validation failed!");
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use erfa_rust::G9_safe;
+use erfa_rust::H1_safe::{DatStatus, ErfaError, ErfaResult};
 use libc::{c_char, snprintf};
 use std::ffi::CString;
 
@@ -40,6 +41,24 @@ fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
     }
 }
 
+fn ver<T>(result: ErfaResult<T>, errok: ErfaError, func: &str, test: &str, status: &mut i32) {
+    match result {
+        Err(err) if err == errok => {
+            if unsafe { VERBOSE } {
+                println!("{} passed: {} want {:?} got {:?}", func, test, errok, err);
+            }
+        }
+        Err(err) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got {:?}", func, test, errok, err);
+        }
+        Ok(_) => {
+            *status = 1;
+            println!("{} failed: {} want {:?} got Ok", func, test, errok);
+        }
+    }
+}
+
 fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
     let a = val - valok;
     if a != 0.0 && a.abs() > dval.abs() {
@@ -76,7 +95,7 @@ fn t_d2dtf(status: &mut i32) {
             viv(ihmsf[1], 59, "eraD2dtf_safe", "m", status);
             viv(ihmsf[2], 60, "eraD2dtf_safe", "s", status);
             viv(ihmsf[3], 13599, "eraD2dtf_safe", "f", status);
-            viv(j, 0, "eraD2dtf_safe", "j", status);
+            viv(j.code(), 0, "eraD2dtf_safe", "j", status);
         }
         Err(_) => {
             *status = 1;
@@ -108,7 +127,7 @@ fn t_dat(status: &mut i32) {
     match result1 {
         Ok((deltat, j)) => {
             vvd(deltat, 32.0, 0.0, "eraDat_safe", "d1", status);
-            viv(j, 0, "eraDat_safe", "j1", status);
+            viv(j.code(), 0, "eraDat_safe", "j1", status);
         }
         Err(_) => {
             *status = 1;
@@ -120,7 +139,7 @@ fn t_dat(status: &mut i32) {
     match result2 {
         Ok((deltat, j)) => {
             vvd(deltat, 33.0, 0.0, "eraDat_safe", "d2", status);
-            viv(j, 0, "eraDat_safe", "j2", status);
+            viv(j.code(), 0, "eraDat_safe", "j2", status);
         }
         Err(_) => {
             *status = 1;
@@ -132,13 +151,37 @@ fn t_dat(status: &mut i32) {
     match result3 {
         Ok((deltat, j)) => {
             vvd(deltat, 37.0, 0.0, "eraDat_safe", "d3", status);
-            viv(j, 0, "eraDat_safe", "j3", status);
+            viv(j.code(), 0, "eraDat_safe", "j3", status);
         }
         Err(_) => {
             *status = 1;
             println!("eraDat_safe failed: unexpected error for 2017-09-01");
         }
     }
+
+    let result4 = G9_safe::eraDat_safe(2017, 9, 1, 1.5);
+    ver(result4, ErfaError::BadFraction, "eraDat_safe", "j4", status);
+
+    let result5 = G9_safe::eraDat_safe(2017, 13, 1, 0.0);
+    ver(result5, ErfaError::BadMonth, "eraDat_safe", "j5", status);
+
+    let result6 = G9_safe::eraDat_safe(1959, 6, 1, 0.0);
+    match result6 {
+        Ok((deltat, j)) => {
+            vvd(deltat, 0.0, 0.0, "eraDat_safe", "d6", status);
+            viv(
+                (j == DatStatus::DubiousYear) as i32,
+                1,
+                "eraDat_safe",
+                "j6",
+                status,
+            );
+        }
+        Err(_) => {
+            *status = 1;
+            println!("eraDat_safe failed: unexpected error for 1959-06-01");
+        }
+    }
 }
 
 fn main() {
@@ -0,0 +1,341 @@
+#![allow(dead_code)]
+
+use erfa_rust::almanac::*;
+use erfa_rust::events::Site;
+use erfa_rust::G11_safe::Ellipsoid;
+use erfa_rust::G17_safe::eraGd2gcEll_safe;
+use erfa_rust::H1_safe::ERFA_DD2R;
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+const MINUTE: f64 = 1.0 / 1440.0;
+
+fn hm(h: f64, m: f64) -> f64 {
+    (60.0 * h + m) * MINUTE
+}
+
+fn greenwich() -> Site {
+    Site {
+        elong: -0.0015 * ERFA_DD2R,
+        phi: 51.4769 * ERFA_DD2R,
+        hm: 46.0,
+        ..Site::default()
+    }
+}
+
+fn t_greenwich(status: &mut i32) {
+    // Midsummer: no astronomical darkness; the rest in order.
+    let d = almanac(&greenwich(), 2024, 6, 21, 1).unwrap()[0];
+    viv(d.id, 21, "almanac", "date", status);
+    vvd(
+        d.sun.rise.unwrap().1,
+        hm(3.0, 43.0),
+        1.0 * MINUTE,
+        "almanac",
+        "sunrise",
+        status,
+    );
+    vvd(
+        d.sun.set.unwrap().1,
+        hm(20.0, 21.0),
+        1.0 * MINUTE,
+        "almanac",
+        "sunset",
+        status,
+    );
+    vvd(
+        d.noon.unwrap().1,
+        hm(12.0, 2.0),
+        1.0 * MINUTE,
+        "almanac",
+        "noon",
+        status,
+    );
+    vvd(d.noon.unwrap().0, 2460482.5, 0.0, "almanac", "utc1", status);
+    viv(
+        d.astronomical.rise.is_none() as i32,
+        1,
+        "almanac",
+        "no astro dawn",
+        status,
+    );
+    viv(
+        d.astronomical.set.is_none() as i32,
+        1,
+        "almanac",
+        "no astro dusk",
+        status,
+    );
+    let t = [
+        d.nautical.rise.unwrap().1,
+        d.civil.rise.unwrap().1,
+        d.sun.rise.unwrap().1,
+        d.noon.unwrap().1,
+        d.sun.set.unwrap().1,
+        d.civil.set.unwrap().1,
+        d.nautical.set.unwrap().1,
+    ];
+    viv(
+        t.windows(2).all(|w| w[0] < w[1]) as i32,
+        1,
+        "almanac",
+        "order",
+        status,
+    );
+    viv(d.sun_always_up as i32, 0, "almanac", "always up", status);
+
+    // Midwinter, with all three twilights.
+    let d = almanac(&greenwich(), 2024, 12, 21, 1).unwrap()[0];
+    vvd(
+        d.sun.rise.unwrap().1,
+        hm(8.0, 4.0),
+        1.5 * MINUTE,
+        "almanac",
+        "winter sunrise",
+        status,
+    );
+    vvd(
+        d.sun.set.unwrap().1,
+        hm(15.0, 54.0),
+        1.5 * MINUTE,
+        "almanac",
+        "winter sunset",
+        status,
+    );
+    vvd(
+        d.astronomical.rise.unwrap().1,
+        hm(5.0, 59.0),
+        2.0 * MINUTE,
+        "almanac",
+        "astro dawn",
+        status,
+    );
+    vvd(
+        d.twilight(Twilight::Civil).set.unwrap().1,
+        hm(16.0, 34.0),
+        2.0 * MINUTE,
+        "almanac",
+        "civil dusk",
+        status,
+    );
+}
+
+fn t_polar(status: &mut i32) {
+    let tromso = Site {
+        elong: 18.96 * ERFA_DD2R,
+        phi: 69.65 * ERFA_DD2R,
+        ..Site::default()
+    };
+    let d = almanac(&tromso, 2024, 6, 21, 1).unwrap()[0];
+    viv(d.sun_always_up as i32, 1, "almanac", "midnight sun", status);
+    viv(
+        d.sun.rise.is_none() as i32,
+        1,
+        "almanac",
+        "midnight sun rise",
+        status,
+    );
+    viv(
+        d.noon.is_some() as i32,
+        1,
+        "almanac",
+        "midnight sun noon",
+        status,
+    );
+
+    // Polar night: the Sun stays below the horizon but reaches civil
+    // twilight around noon.
+    let d = almanac(&tromso, 2024, 12, 21, 1).unwrap()[0];
+    viv(
+        d.sun_always_down as i32,
+        1,
+        "almanac",
+        "polar night",
+        status,
+    );
+    viv(
+        d.sun_always_up as i32,
+        0,
+        "almanac",
+        "polar night up",
+        status,
+    );
+    let (dawn, dusk) = (d.civil.rise.unwrap().1, d.civil.set.unwrap().1);
+    let noon = d.noon.unwrap().1;
+    viv(
+        (dawn < noon && noon < dusk) as i32,
+        1,
+        "almanac",
+        "polar civil",
+        status,
+    );
+}
+
+fn t_local_day(status: &mut i32) {
+    // West of Greenwich the local day starts after 0h UTC and holds all
+    // the events of the date.
+    let site = Site {
+        elong: -70.73 * ERFA_DD2R,
+        phi: -29.26 * ERFA_DD2R,
+        hm: 2400.0,
+        ..Site::default()
+    };
+    let days = almanac(&site, 2024, 3, 1, 3).unwrap();
+    viv(days.len() as i32, 3, "almanac", "days", status);
+    viv(days[2].id, 3, "almanac", "third date", status);
+    let start = 70.73 / 360.0;
+    for d in &days {
+        for t in [
+            d.astronomical.rise,
+            d.sun.rise,
+            d.noon,
+            d.sun.set,
+            d.astronomical.set,
+        ] {
+            let t = t.unwrap().1;
+            viv(
+                (t > start && t < start + 1.0) as i32,
+                1,
+                "almanac",
+                "in local day",
+                status,
+            );
+        }
+        // Night follows the evening: astronomical dusk after sunset.
+        viv(
+            (d.astronomical.set.unwrap().1 > d.sun.set.unwrap().1) as i32,
+            1,
+            "almanac",
+            "dusk",
+            status,
+        );
+    }
+}
+
+fn t_ellipsoid(status: &mut i32) {
+    let (elong, phi, h) = (-0.5278, -1.2346, 2738.0);
+    let s = Site::on_ellipsoid(&Ellipsoid::WGS84, elong, phi, h).unwrap();
+    vvd(
+        s.elong,
+        elong,
+        1e-14,
+        "Site::on_ellipsoid",
+        "WGS84 elong",
+        status,
+    );
+    vvd(s.phi, phi, 1e-14, "Site::on_ellipsoid", "WGS84 phi", status);
+    vvd(s.hm, h, 1e-6, "Site::on_ellipsoid", "WGS84 hm", status);
+    // GRS80 and WGS84 differ by 0.1 mm in the semi-minor axis.
+    let s = Site::on_ellipsoid(&Ellipsoid::GRS80, elong, phi, h).unwrap();
+    vvd(s.phi, phi, 1e-10, "Site::on_ellipsoid", "GRS80 phi", status);
+    vvd(s.hm, h, 1e-3, "Site::on_ellipsoid", "GRS80 hm", status);
+    // WGS72 is 2 m smaller at the equator.
+    let s = Site::on_ellipsoid(&Ellipsoid::WGS72, elong, phi, h).unwrap();
+    vvd(
+        s.hm - h,
+        -2.0,
+        1.0,
+        "Site::on_ellipsoid",
+        "WGS72 hm",
+        status,
+    );
+    // Clarke 1866, IERS 2010 by name and a registered ellipsoid: the site
+    // is the same point in space, expressed on WGS84.
+    let custom = Ellipsoid::new(6_378_000.0, 1.0 / 300.0).unwrap();
+    Ellipsoid::register("almanac test", custom).unwrap();
+    for (e, name) in [
+        (Ellipsoid::CLARKE1866, "Clarke 1866"),
+        (Ellipsoid::named("IERS 2010").unwrap(), "IERS 2010"),
+        (Ellipsoid::named("Almanac-Test").unwrap(), "registered"),
+    ] {
+        let s = Site::on_ellipsoid(&e, elong, phi, h).unwrap();
+        let p0 = eraGd2gcEll_safe(&e, elong, phi, h).unwrap();
+        let p1 = eraGd2gcEll_safe(&Ellipsoid::WGS84, s.elong, s.phi, s.hm).unwrap();
+        let d =
+            ((p0[0] - p1[0]).powi(2) + (p0[1] - p1[1]).powi(2) + (p0[2] - p1[2]).powi(2)).sqrt();
+        vvd(d, 0.0, 1e-6, "Site::on_ellipsoid", name, status);
+    }
+    let s = Site::on_ellipsoid(&Ellipsoid::CLARKE1866, elong, phi, h).unwrap();
+    viv(
+        ((s.hm - h).abs() > 10.0) as i32,
+        1,
+        "Site::on_ellipsoid",
+        "Clarke 1866 hm",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_greenwich(&mut status);
+    t_polar(&mut status);
+    t_local_day(&mut status);
+    t_ellipsoid(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
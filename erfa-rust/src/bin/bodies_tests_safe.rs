@@ -0,0 +1,274 @@
+#![allow(dead_code)]
+
+use erfa_rust::bodies::*;
+use erfa_rust::G13_safe::{eraEpv00_safe, Ephemeris, Epv00};
+use erfa_rust::G1_safe::eraApci13_safe;
+use erfa_rust::G24_safe::eraPlan94_safe;
+use erfa_rust::G29_safe::eraS2c_safe;
+use erfa_rust::G30_safe::eraSeps_safe;
+use erfa_rust::G32_safe::eraTaitt_safe;
+use erfa_rust::G33_safe::eraUtctai_safe;
+use erfa_rust::G4_safe::eraAtco13_safe;
+use erfa_rust::H1_safe::{eraASTROM, ErfaResult, ERFA_AULT, ERFA_DAU, ERFA_DAYSEC, ERFA_DPI};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+// Site and weather of the eraAtco13 test.
+const UTC1: f64 = 2456384.5;
+const UTC2: f64 = 0.969254051;
+const DUT1: f64 = 0.1550675;
+const ELONG: f64 = -0.527800806;
+const PHI: f64 = -1.2345856;
+const HM: f64 = 2738.0;
+const XP: f64 = 2.47230737e-7;
+const YP: f64 = 1.82640464e-6;
+const PHPA: f64 = 731.0;
+const TC: f64 = 12.8;
+const RH: f64 = 0.59;
+const WL: f64 = 0.55;
+
+// Epv00, with every body replaced by a fixed point 1e12 au away.
+struct FarPoint([f64; 3]);
+
+impl Ephemeris for FarPoint {
+    fn earth_pv(&self, date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2])> {
+        Epv00.earth_pv(date1, date2)
+    }
+}
+
+impl BodyEphemeris for FarPoint {
+    fn body_pv(&self, body: Body, date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        if body == Body::Sun {
+            return Epv00.body_pv(body, date1, date2);
+        }
+        Ok([self.0.map(|x| 1e12 * x), [0.0; 3]])
+    }
+}
+
+fn tt(utc1: f64, utc2: f64) -> (f64, f64) {
+    let ((tai1, tai2), _) = eraUtctai_safe(utc1, utc2).unwrap();
+    eraTaitt_safe(tai1, tai2).unwrap()
+}
+
+fn t_star_limit(status: &mut i32) {
+    // A very distant body must land where eraAtco13 puts a star.
+    let (rc, dc) = (2.71, 0.174);
+    let eph = FarPoint(eraS2c_safe(rc, dc).unwrap());
+    let (p, j) = place_with(
+        &eph,
+        Body::Mars,
+        UTC1,
+        UTC2,
+        DUT1,
+        ELONG,
+        PHI,
+        HM,
+        XP,
+        YP,
+        PHPA,
+        TC,
+        RH,
+        WL,
+    )
+    .unwrap();
+    let (aob, zob, hob, dob, rob, _, _) = eraAtco13_safe(
+        rc, dc, 0.0, 0.0, 0.0, 0.0, UTC1, UTC2, DUT1, ELONG, PHI, HM, XP, YP, PHPA, TC, RH, WL,
+    )
+    .unwrap();
+    viv(j.code(), 0, "place_with", "j", status);
+    vvd(p.rc, rc, 1e-12, "place_with", "rc", status);
+    vvd(p.dc, dc, 1e-12, "place_with", "dc", status);
+    vvd(p.az, aob, 1e-11, "place_with", "az", status);
+    vvd(
+        p.el,
+        ERFA_DPI / 2.0 - zob,
+        1e-11,
+        "place_with",
+        "el",
+        status,
+    );
+    vvd(p.ha, hob, 1e-11, "place_with", "ha", status);
+    vvd(p.dob, dob, 1e-11, "place_with", "dob", status);
+    vvd(p.rob, rob, 1e-11, "place_with", "rob", status);
+}
+
+fn t_sun(status: &mut i32) {
+    // March equinox 2024-03-20 03:06 UTC: apparent RA and Dec near zero.
+    let (p, _) = place(
+        Body::Sun,
+        2460389.5,
+        (3.0 + 6.0 / 60.0) / 24.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.55,
+    )
+    .unwrap();
+    let ra = if p.ra > ERFA_DPI {
+        p.ra - 2.0 * ERFA_DPI
+    } else {
+        p.ra
+    };
+    vvd(ra, 0.0, 5e-5, "place", "Sun ra", status);
+    vvd(p.di, 0.0, 5e-5, "place", "Sun dec", status);
+    vvd(p.distance, 0.996, 2e-3, "place", "Sun distance", status);
+    vvd(
+        p.light_time,
+        p.distance * ERFA_AULT / ERFA_DAYSEC,
+        1e-12,
+        "place",
+        "Sun light time",
+        status,
+    );
+}
+
+fn t_moon(status: &mut i32) {
+    // Topocentric Moon is displaced from the geocentric by the parallax,
+    // sin p = (ρ/d) sin z.
+    let (p, _) = place(
+        Body::Moon,
+        UTC1,
+        UTC2,
+        DUT1,
+        ELONG,
+        PHI,
+        HM,
+        XP,
+        YP,
+        0.0,
+        0.0,
+        0.0,
+        WL,
+    )
+    .unwrap();
+    viv(
+        (p.distance > 0.0023 && p.distance < 0.0028) as i32,
+        1,
+        "place",
+        "Moon distance",
+        status,
+    );
+
+    let (tt1, tt2) = tt(UTC1, UTC2);
+    let mut astrom = eraASTROM::default();
+    let eo = eraApci13_safe(tt1, tt2, &mut astrom).unwrap();
+    let g = place_astrom(&Epv00, Body::Moon, tt1, tt2, &astrom, eo).unwrap();
+    let sep = eraSeps_safe(p.ri, p.di, g.ri, g.di).unwrap();
+    let rho = (6_378_137.0 - 21_385.0 * PHI.sin().powi(2) + HM) / ERFA_DAU;
+    let want = (rho / g.distance * (ERFA_DPI / 2.0 - p.el).sin()).asin();
+    vvd(sep, want, 0.01 * want, "place", "Moon parallax", status);
+    vvd(
+        g.distance - p.distance,
+        rho * p.el.sin(),
+        0.05 * rho,
+        "place",
+        "Moon range",
+        status,
+    );
+}
+
+fn t_planets(status: &mut i32) {
+    let (tt1, tt2) = tt(UTC1, UTC2);
+    let (pvh, _, _) = eraEpv00_safe(tt1, tt2).unwrap();
+    for body in Body::ALL {
+        let Some(np) = body.plan94() else { continue };
+        let (p, _) = place(
+            body, UTC1, UTC2, DUT1, ELONG, PHI, HM, XP, YP, 0.0, 0.0, 0.0, WL,
+        )
+        .unwrap();
+        let (pv, _) = eraPlan94_safe(tt1, tt2, np).unwrap();
+        let d: f64 = (0..3)
+            .map(|i| (pv[0][i] - pvh[0][i]).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        vvd(p.distance, d, 1e-3, "place", "planet distance", status);
+        let (p2, _) = place(
+            body, UTC1, UTC2, DUT1, ELONG, PHI, HM, XP, YP, PHPA, TC, RH, WL,
+        )
+        .unwrap();
+        // Refraction only raises the body.
+        viv((p2.el >= p.el) as i32, 1, "place", "refraction", status);
+        vvd(p2.ri, p.ri, 0.0, "place", "refraction ri", status);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_star_limit(&mut status);
+    t_sun(&mut status);
+    t_moon(&mut status);
+    t_planets(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,171 @@
+#![allow(dead_code)]
+
+use erfa_rust::dual::*;
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+fn t_arithmetic(status: &mut i32) {
+    let [x, y] = Dual::<2>::variables([0.7, -1.3]);
+
+    // f = (x y + sin x) / (1 + y²) − 2 / x
+    let f = (x * y + x.sin()) / (1.0 + y * y) - 2.0 / x;
+    let (xv, yv) = (0.7_f64, -1.3_f64);
+    let den = 1.0 + yv * yv;
+    let num = xv * yv + xv.sin();
+    vvd(f.v, num / den - 2.0 / xv, 1e-15, "Dual", "f", status);
+    vvd(
+        f.d[0],
+        (yv + xv.cos()) / den + 2.0 / (xv * xv),
+        1e-14,
+        "Dual",
+        "df/dx",
+        status,
+    );
+    vvd(
+        f.d[1],
+        xv / den - num * 2.0 * yv / (den * den),
+        1e-14,
+        "Dual",
+        "df/dy",
+        status,
+    );
+
+    // atan2 and sqrt.
+    let g = y.atan2(x);
+    vvd(
+        g.d[0],
+        -yv / (xv * xv + yv * yv),
+        1e-15,
+        "Dual",
+        "atan2x",
+        status,
+    );
+    vvd(
+        g.d[1],
+        xv / (xv * xv + yv * yv),
+        1e-15,
+        "Dual",
+        "atan2y",
+        status,
+    );
+    let h = (x * x * 4.0).sqrt();
+    vvd(h.d[0], 2.0, 1e-15, "Dual", "sqrt", status);
+    vvd((x - 0.7).sqrt().d[0], 0.0, 0.0, "Dual", "sqrt0", status);
+
+    // Constants carry no partials.
+    let c = Dual::<2>::constant(3.0) * x - x * 3.0;
+    vvd(c.v, 0.0, 0.0, "Dual", "c", status);
+    vvd(c.d[0], 0.0, 0.0, "Dual", "dc", status);
+}
+
+fn t_vectors(status: &mut i32) {
+    let [a, b] = Dual::<2>::variables([2.1, -0.4]);
+
+    // c2s(s2c) is the identity.
+    let (t, p) = c2s(&s2c(a, b));
+    let jac = jacobian(&[t, p]);
+    vvd(t.v, 2.1, 1e-15, "Dual", "c2s", status);
+    vvd(jac[0][0], 1.0, 1e-15, "Dual", "dt/da", status);
+    vvd(jac[0][1], 0.0, 1e-15, "Dual", "dt/db", status);
+    vvd(jac[1][0], 0.0, 1e-15, "Dual", "dp/da", status);
+    vvd(jac[1][1], 1.0, 1e-15, "Dual", "dp/db", status);
+
+    // Scaling then normalizing: still a unit vector, partials tangent.
+    let u = pn(&s2c(a, b).map(|x| x * (a + 2.0)));
+    vvd(pdp(&u, &u).v, 1.0, 1e-15, "Dual", "pn", status);
+    let du: [f64; 3] = std::array::from_fn(|i| u[i].d[0]);
+    vvd(
+        u[0].v * du[0] + u[1].v * du[1] + u[2].v * du[2],
+        0.0,
+        1e-15,
+        "Dual",
+        "dpn",
+        status,
+    );
+
+    // Cross product and rotation by a constant matrix.
+    let e = s2c(a, Dual::constant(0.0));
+    let z = [0.0, 0.0, 1.0].map(Dual::constant);
+    let w = pxp(&z, &e);
+    vvd(w[0].d[0], -e[1].d[0], 1e-15, "Dual", "pxp", status);
+    let r = [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+    vvd(rxp(&r, &e)[1].d[0], -e[0].d[0], 0.0, "Dual", "rxp", status);
+
+    // For a null vector the angles and their partials are zero.
+    let (t, _) = c2s(&s2c(a, b).map(|x| x * 0.0));
+    vvd(t.v, 0.0, 0.0, "Dual", "null", status);
+    vvd(t.d[0], 0.0, 0.0, "Dual", "dnull", status);
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_arithmetic(&mut status);
+    t_vectors(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,438 @@
+#![allow(dead_code)]
+
+use erfa_rust::eclipses::*;
+use erfa_rust::events::Site;
+use erfa_rust::G8_safe::eraCal2jd_safe;
+use erfa_rust::H1_safe::ERFA_DD2R;
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+const MINUTE: f64 = 1.0 / 1440.0;
+
+// TT−UTC from 2017 (37 s + 32.184 s).
+const TT_UTC: f64 = 69.184 / 86400.0;
+
+// Two-part JD of a date and time.
+fn jd(iy: i32, im: i32, id: i32, h: f64, m: f64, s: f64) -> (f64, f64) {
+    let (d1, d2) = eraCal2jd_safe(iy, im, id).unwrap();
+    (d1 + d2, (3600.0 * h + 60.0 * m + s) / 86400.0)
+}
+
+// Difference in days between two two-part JDs.
+fn dt(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0) + (a.1 - b.1)
+}
+
+fn t_obscuration(status: &mut i32) {
+    vvd(
+        obscuration(1.0, 1.0, 2.5),
+        0.0,
+        0.0,
+        "obscuration",
+        "apart",
+        status,
+    );
+    vvd(
+        obscuration(1.0, 1.1, 0.05),
+        1.0,
+        0.0,
+        "obscuration",
+        "total",
+        status,
+    );
+    vvd(
+        obscuration(1.0, 0.9, 0.05),
+        0.81,
+        1e-15,
+        "obscuration",
+        "annular",
+        status,
+    );
+    // Equal discs half a radius apart overlap by 2acos(1/4) − √15/8 over π.
+    let want = (2.0 * 0.25_f64.acos() - 15.0_f64.sqrt() / 8.0) / std::f64::consts::PI;
+    vvd(
+        obscuration(1.0, 1.0, 0.5),
+        want,
+        1e-15,
+        "obscuration",
+        "lens",
+        status,
+    );
+}
+
+fn t_solar(status: &mut i32) {
+    // 2023–2025, with greatest eclipse (TD) and γ from the NASA canon.
+    let (t1, t2) = jd(2023, 1, 1, 0.0, 0.0, 0.0);
+    let found = solar_eclipses(t1, t2, 3.0 * 365.25).unwrap();
+    let want = [
+        (
+            EclipseKind::Total,
+            jd(2023, 4, 20, 4.0, 17.0, 55.0),
+            -0.3952,
+        ),
+        (
+            EclipseKind::Annular,
+            jd(2023, 10, 14, 18.0, 0.0, 41.0),
+            0.3753,
+        ),
+        (EclipseKind::Total, jd(2024, 4, 8, 18.0, 18.0, 25.0), 0.3431),
+        (
+            EclipseKind::Annular,
+            jd(2024, 10, 2, 18.0, 46.0, 13.0),
+            -0.3509,
+        ),
+        (
+            EclipseKind::Partial,
+            jd(2025, 3, 29, 10.0, 48.0, 36.0),
+            1.0405,
+        ),
+        (
+            EclipseKind::Partial,
+            jd(2025, 9, 21, 19.0, 43.0, 4.0),
+            -1.0651,
+        ),
+    ];
+    viv(found.len() as i32, 6, "solar_eclipses", "count", status);
+    for (e, (kind, t, gamma)) in found.iter().zip(want) {
+        viv((e.kind == kind) as i32, 1, "solar_eclipses", "kind", status);
+        vvd(
+            dt((e.tt1, e.tt2), t),
+            0.0,
+            1.0 * MINUTE,
+            "solar_eclipses",
+            "greatest",
+            status,
+        );
+        vvd(e.gamma, gamma, 2e-3, "solar_eclipses", "gamma", status);
+    }
+}
+
+fn t_solar_local(status: &mut i32) {
+    // 2024 April 8 from Dallas: totality about 18:40:43–18:44:35 UTC.
+    let (t1, t2) = jd(2024, 4, 8, 0.0, 0.0, 0.0);
+    let e = solar_eclipses(t1, t2, 1.0).unwrap()[0];
+    let dallas = Site {
+        elong: -96.7970 * ERFA_DD2R,
+        phi: 32.7767 * ERFA_DD2R,
+        hm: 131.0,
+        ..Site::default()
+    };
+    let l = solar_local(&e, &dallas).unwrap().unwrap();
+    viv(
+        (l.kind == EclipseKind::Total) as i32,
+        1,
+        "solar_local",
+        "kind",
+        status,
+    );
+    let utc = |c: &Contact| (c.tt1, c.tt2 - TT_UTC);
+    let (c2, c3) = (l.c2.unwrap(), l.c3.unwrap());
+    vvd(
+        dt(utc(&c2), jd(2024, 4, 8, 18.0, 40.0, 43.0)),
+        0.0,
+        1.0 * MINUTE,
+        "solar_local",
+        "c2",
+        status,
+    );
+    vvd(
+        dt(utc(&c3), jd(2024, 4, 8, 18.0, 44.0, 35.0)),
+        0.0,
+        1.0 * MINUTE,
+        "solar_local",
+        "c3",
+        status,
+    );
+    vvd(
+        dt(utc(&l.c1), jd(2024, 4, 8, 17.0, 23.0, 0.0)),
+        0.0,
+        2.0 * MINUTE,
+        "solar_local",
+        "c1",
+        status,
+    );
+    vvd(
+        dt(utc(&l.c4), jd(2024, 4, 8, 20.0, 2.0, 0.0)),
+        0.0,
+        2.0 * MINUTE,
+        "solar_local",
+        "c4",
+        status,
+    );
+    vvd(
+        l.obscuration,
+        1.0,
+        0.0,
+        "solar_local",
+        "obscuration",
+        status,
+    );
+    viv(
+        (l.magnitude > 1.0 && l.magnitude < 1.02) as i32,
+        1,
+        "solar_local",
+        "magnitude",
+        status,
+    );
+    vvd(
+        l.greatest.el,
+        65.0 * ERFA_DD2R,
+        2.0 * ERFA_DD2R,
+        "solar_local",
+        "el",
+        status,
+    );
+    let order = l.c1.tt2 < c2.tt2
+        && c2.tt2 < l.greatest.tt2
+        && l.greatest.tt2 < c3.tt2
+        && c3.tt2 < l.c4.tt2;
+    viv(order as i32, 1, "solar_local", "order", status);
+
+    // From New York the same eclipse is partial, about 90% of the disc.
+    let nyc = Site {
+        elong: -74.006 * ERFA_DD2R,
+        phi: 40.7128 * ERFA_DD2R,
+        ..Site::default()
+    };
+    let l = solar_local(&e, &nyc).unwrap().unwrap();
+    viv(
+        (l.kind == EclipseKind::Partial) as i32,
+        1,
+        "solar_local",
+        "nyc kind",
+        status,
+    );
+    viv(l.c2.is_none() as i32, 1, "solar_local", "nyc c2", status);
+    vvd(
+        l.obscuration,
+        0.90,
+        0.02,
+        "solar_local",
+        "nyc obscuration",
+        status,
+    );
+
+    // And not seen at all from Santiago, in daylight south of the penumbra.
+    let santiago = Site {
+        elong: -70.67 * ERFA_DD2R,
+        phi: -33.45 * ERFA_DD2R,
+        ..Site::default()
+    };
+    viv(
+        solar_local(&e, &santiago).unwrap().is_none() as i32,
+        1,
+        "solar_local",
+        "santiago",
+        status,
+    );
+}
+
+fn t_lunar(status: &mut i32) {
+    // 2022–2025, with greatest eclipse (TD) and umbral magnitude from the
+    // NASA canon.
+    let (t1, t2) = jd(2022, 1, 1, 0.0, 0.0, 0.0);
+    let found = lunar_eclipses(t1, t2, 4.0 * 365.25).unwrap();
+    let want = [
+        (EclipseKind::Total, jd(2022, 5, 16, 4.0, 12.0, 42.0), 1.4137),
+        (EclipseKind::Total, jd(2022, 11, 8, 11.0, 0.0, 22.0), 1.3589),
+        (
+            EclipseKind::Penumbral,
+            jd(2023, 5, 5, 17.0, 24.0, 5.0),
+            -0.0441,
+        ),
+        (
+            EclipseKind::Partial,
+            jd(2023, 10, 28, 20.0, 15.0, 18.0),
+            0.1224,
+        ),
+        (
+            EclipseKind::Penumbral,
+            jd(2024, 3, 25, 7.0, 14.0, 1.0),
+            -0.1304,
+        ),
+        (
+            EclipseKind::Partial,
+            jd(2024, 9, 18, 2.0, 45.0, 25.0),
+            0.0848,
+        ),
+        (EclipseKind::Total, jd(2025, 3, 14, 7.0, 0.0, 9.0), 1.1784),
+        (EclipseKind::Total, jd(2025, 9, 7, 18.0, 12.0, 58.0), 1.3619),
+    ];
+    viv(found.len() as i32, 8, "lunar_eclipses", "count", status);
+    for (e, (kind, t, umag)) in found.iter().zip(want) {
+        viv((e.kind == kind) as i32, 1, "lunar_eclipses", "kind", status);
+        vvd(
+            dt((e.tt1, e.tt2), t),
+            0.0,
+            1.0 * MINUTE,
+            "lunar_eclipses",
+            "greatest",
+            status,
+        );
+        vvd(
+            e.umbral_magnitude,
+            umag,
+            1e-2,
+            "lunar_eclipses",
+            "umbral",
+            status,
+        );
+    }
+
+    // 2022 November 8: partial phase 09:09–12:49 UTC, total 10:16–11:42.
+    let e = found[1];
+    let utc = |c: Option<(f64, f64)>| {
+        let c = c.unwrap();
+        (c.0, c.1 - TT_UTC)
+    };
+    let want = [
+        (e.u1, jd(2022, 11, 8, 9.0, 9.0, 12.0)),
+        (e.u2, jd(2022, 11, 8, 10.0, 16.0, 39.0)),
+        (e.u3, jd(2022, 11, 8, 11.0, 41.0, 35.0)),
+        (e.u4, jd(2022, 11, 8, 12.0, 49.0, 2.0)),
+    ];
+    for (c, t) in want {
+        vvd(
+            dt(utc(c), t),
+            0.0,
+            1.0 * MINUTE,
+            "lunar_eclipses",
+            "contact",
+            status,
+        );
+    }
+    viv(
+        (e.p1.unwrap().1 < e.u1.unwrap().1 && e.u4.unwrap().1 < e.p4.unwrap().1) as i32,
+        1,
+        "lunar_eclipses",
+        "penumbra",
+        status,
+    );
+
+    // A penumbral eclipse has no umbral contacts.
+    viv(
+        found[2].u1.is_none() as i32,
+        1,
+        "lunar_eclipses",
+        "no u1",
+        status,
+    );
+    viv(
+        found[2].p1.is_some() as i32,
+        1,
+        "lunar_eclipses",
+        "p1",
+        status,
+    );
+
+    // Totality was high in the sky in Hawaii and below the horizon in Europe.
+    let hawaii = Site {
+        elong: -155.5 * ERFA_DD2R,
+        phi: 19.8 * ERFA_DD2R,
+        ..Site::default()
+    };
+    let l = lunar_local(&e, &hawaii).unwrap();
+    viv(
+        (l.greatest.el > 45.0 * ERFA_DD2R) as i32,
+        1,
+        "lunar_local",
+        "hawaii",
+        status,
+    );
+    vvd(
+        l.u2.unwrap().tt2,
+        e.u2.unwrap().1,
+        0.0,
+        "lunar_local",
+        "u2",
+        status,
+    );
+    let paris = Site {
+        elong: 2.35 * ERFA_DD2R,
+        phi: 48.86 * ERFA_DD2R,
+        ..Site::default()
+    };
+    let l = lunar_local(&e, &paris).unwrap();
+    viv(
+        (l.greatest.el < 0.0) as i32,
+        1,
+        "lunar_local",
+        "paris",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_obscuration(&mut status);
+    t_solar(&mut status);
+    t_solar_local(&mut status);
+    t_lunar(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,311 @@
+#![allow(dead_code)]
+
+use erfa_rust::eop::*;
+use erfa_rust::G14_safe::LeapSecondTable;
+use erfa_rust::G2_safe::eraApco13_safe;
+use erfa_rust::G32_safe::eraTaitt_safe;
+use erfa_rust::G33_safe::{eraUtctai_safe, eraUtcut1_safe};
+use erfa_rust::G4_safe::eraAtco13_safe;
+use erfa_rust::G7_safe::eraC2t06a_safe;
+use erfa_rust::H1_safe::{eraASTROM, ErfaError, ERFA_DAS2R, ERFA_DMAS2R};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+// Synthetic finals2000A rows either side of the 2016-12-31 leap second:
+// UT1−TAI = −35.400 s − 1 ms/day, so UT1−UTC steps by +1 s on 2017-01-01.
+const FINALS: &str = "\
+161227 57749.00 I  0.020000 0.000091  0.280000 0.000071  I 0.6000000 0.0000155  1.2000 0.0105  I     0.100    0.128    -0.050    0.160
+161228 57750.00 I  0.021000 0.000091  0.279500 0.000071  I 0.5990000 0.0000155  1.2000 0.0105  I     0.110    0.128    -0.050    0.160
+161229 57751.00 I  0.022000 0.000091  0.279000 0.000071  I 0.5980000 0.0000155  1.2000 0.0105  I     0.120    0.128    -0.050    0.160
+161230 57752.00 I  0.023000 0.000091  0.278500 0.000071  I 0.5970000 0.0000155  1.2000 0.0105  I     0.130    0.128    -0.050    0.160
+161231 57753.00 I  0.024000 0.000091  0.278000 0.000071  I 0.5960000 0.0000155  1.2000 0.0105  I     0.140    0.128    -0.050    0.160
+17 1 1 57754.00 I  0.025000 0.000091  0.277500 0.000071  I 1.5950000 0.0000155  1.2000 0.0105  I     0.150    0.128    -0.050    0.160
+17 1 2 57755.00 I  0.026000 0.000091  0.277000 0.000071  I 1.5940000 0.0000155  1.2000 0.0105  I     0.160    0.128    -0.050    0.160
+17 1 3 57756.00 I  0.027000 0.000091  0.276500 0.000071  I 1.5930000 0.0000155  1.2000 0.0105  I     0.170    0.128    -0.050    0.160
+17 1 4 57757.00 P  0.028000 0.000091  0.276000 0.000071  P 1.5920000 0.0000155  1.2000 0.0105  P     0.180    0.128    -0.050    0.160
+17 1 5 57758.00 P  0.029000 0.000091  0.275500 0.000071  P 1.5910000 0.0000155  1.2000 0.0105  P 
+17 1 6 57759.00
+";
+
+// The same series in the EOP 20 C04 layout (dX/dY in arcsec).
+const C04_20: &str = "\
+# EOP 20 C04 (IAU2000A) series, synthetic extract
+# YR  MM  DD  HH       MJD        x(\")        y(\")  UT1-UTC(s)       dX(\")      dY(\")
+2016  12  30   0  57752.00    0.023000    0.278500   0.5970000    0.000130   -0.000050
+2016  12  31   0  57753.00    0.024000    0.278000   0.5960000    0.000140   -0.000050
+2017   1   1   0  57754.00    0.025000    0.277500   1.5950000    0.000150   -0.000050
+2017   1   2   0  57755.00    0.026000    0.277000   1.5940000    0.000160   -0.000050
+";
+
+// And in the older 14 C04 layout, which has a plain-text header and LOD
+// before dX/dY.
+const C04_14: &str = "\
+                          EARTH ORIENTATION PARAMETER (EOP) PRODUCT CENTER
+                         EOP (IERS) 14 C04 TIME SERIES  consistent with ITRF 2014
+      Date      MJD      x          y        UT1-UTC       LOD         dX        dY
+2016  12  30  57752   0.023000   0.278500   0.5970000   0.0012000   0.000130  -0.000050
+2016  12  31  57753   0.024000   0.278000   0.5960000   0.0012000   0.000140  -0.000050
+2017   1   1  57754   0.025000   0.277500   1.5950000   0.0012000   0.000150  -0.000050
+2017   1   2  57755   0.026000   0.277000   1.5940000   0.0012000   0.000160  -0.000050
+";
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+fn t_finals(status: &mut i32) {
+    let eop = EopTable::parse_finals2000a(FINALS).unwrap();
+    viv(eop.records.len() as i32, 10, "finals2000A", "n", status);
+    vvd(
+        eop.records[0].mjd,
+        57749.0,
+        0.0,
+        "finals2000A",
+        "mjd",
+        status,
+    );
+    vvd(
+        eop.records[0].xp,
+        0.02 * ERFA_DAS2R,
+        1e-20,
+        "finals2000A",
+        "xp",
+        status,
+    );
+    vvd(
+        eop.records[0].dx,
+        0.1 * ERFA_DMAS2R,
+        1e-20,
+        "finals2000A",
+        "dx",
+        status,
+    );
+    vvd(eop.records[9].dx, 0.0, 0.0, "finals2000A", "no dx", status);
+    viv(
+        eop.records[8].predicted as i32,
+        1,
+        "finals2000A",
+        "predicted",
+        status,
+    );
+
+    // Across the leap second UT1−UTC follows the step, not a ramp.
+    let e = eop.at(2400000.5, 57753.5).unwrap();
+    vvd(e.dut1, 0.5955, 1e-9, "EopTable::at", "dut1 before", status);
+    vvd(
+        e.xp,
+        0.0245 * ERFA_DAS2R,
+        1e-15,
+        "EopTable::at",
+        "xp",
+        status,
+    );
+    vvd(
+        e.yp,
+        0.27775 * ERFA_DAS2R,
+        1e-15,
+        "EopTable::at",
+        "yp",
+        status,
+    );
+    vvd(
+        e.dx,
+        0.145 * ERFA_DMAS2R,
+        1e-18,
+        "EopTable::at",
+        "dx",
+        status,
+    );
+    vvd(
+        e.dy,
+        -0.05 * ERFA_DMAS2R,
+        1e-18,
+        "EopTable::at",
+        "dy",
+        status,
+    );
+    viv(e.predicted as i32, 0, "EopTable::at", "observed", status);
+    let e = eop.at(2457754.5, 0.25).unwrap();
+    vvd(e.dut1, 1.59475, 1e-9, "EopTable::at", "dut1 after", status);
+    let e = eop.at(2400000.5, 57757.5).unwrap();
+    viv(e.predicted as i32, 1, "EopTable::at", "predicted", status);
+
+    // A table without the 2017 leap second sees it as a UT1 jump instead.
+    let builtin = LeapSecondTable::builtin();
+    let n = builtin.entries().len();
+    let old = LeapSecondTable::new(builtin.entries()[..n - 1].to_vec(), 62_137.0);
+    let e = eop.at_in(&old, 2400000.5, 57753.5).unwrap();
+    viv(
+        ((e.dut1 - 0.5955).abs() > 0.1) as i32,
+        1,
+        "EopTable::at_in",
+        "old table",
+        status,
+    );
+
+    let r = eop.at(2400000.5, 57758.5);
+    viv(
+        (r.err() == Some(ErfaError::EopOutOfRange)) as i32,
+        1,
+        "EopTable::at",
+        "out of range",
+        status,
+    );
+    let r = EopTable::parse_finals2000a("");
+    viv(
+        (r.err() == Some(ErfaError::EopFileSyntax)) as i32,
+        1,
+        "finals2000A",
+        "empty",
+        status,
+    );
+}
+
+fn t_c04(status: &mut i32) {
+    let finals = EopTable::parse_finals2000a(FINALS).unwrap();
+    let f = finals.at(2400000.5, 57753.75).unwrap();
+
+    for (text, label) in [(C04_20, "20 C04"), (C04_14, "14 C04")] {
+        let eop = EopTable::parse_c04(text).unwrap();
+        viv(eop.records.len() as i32, 4, label, "n", status);
+        vvd(eop.records[1].mjd, 57753.0, 0.0, label, "mjd", status);
+        let e = eop.at(2400000.5, 57753.75).unwrap();
+        vvd(e.dut1, f.dut1, 1e-12, label, "dut1", status);
+        vvd(e.xp, f.xp, 1e-17, label, "xp", status);
+        vvd(e.yp, f.yp, 1e-17, label, "yp", status);
+        vvd(e.dx, f.dx, 1e-17, label, "dx", status);
+        vvd(e.dy, f.dy, 1e-17, label, "dy", status);
+    }
+}
+
+fn t_wrappers(status: &mut i32) {
+    let eop = EopTable::parse_finals2000a(FINALS).unwrap();
+    let (utc1, utc2) = (2457753.5, 0.3);
+    let e = eop.at(utc1, utc2).unwrap();
+    let (elong, phi, hm) = (-0.527800806, -1.2345856, 2738.0);
+    let (phpa, tc, rh, wl) = (731.0, 12.8, 0.59, 0.55);
+
+    let mut a = eraASTROM::default();
+    let mut b = eraASTROM::default();
+    let (eo, _) = eop
+        .apco13(utc1, utc2, elong, phi, hm, phpa, tc, rh, wl, &mut a)
+        .unwrap();
+    let (eo0, _) = eraApco13_safe(
+        utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh, wl, &mut b,
+    )
+    .unwrap();
+    vvd(eo, eo0, 0.0, "EopTable::apco13", "eo", status);
+    vvd(a.eral, b.eral, 0.0, "EopTable::apco13", "eral", status);
+
+    let r = eop
+        .atco13(
+            2.71, 0.174, 1e-5, 5e-6, 0.1, 55.0, utc1, utc2, elong, phi, hm, phpa, tc, rh, wl,
+        )
+        .unwrap();
+    let r0 = eraAtco13_safe(
+        2.71, 0.174, 1e-5, 5e-6, 0.1, 55.0, utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa,
+        tc, rh, wl,
+    )
+    .unwrap();
+    vvd(r.0, r0.0, 0.0, "EopTable::atco13", "aob", status);
+    vvd(r.1, r0.1, 0.0, "EopTable::atco13", "zob", status);
+
+    // With dX/dY zeroed, c2t matches the plain IAU 2006/2000A matrix.
+    let mut records = eop.records.clone();
+    for r in records.iter_mut() {
+        r.dx = 0.0;
+        r.dy = 0.0;
+    }
+    let eop0 = EopTable::new(records).unwrap();
+    let rc2t = eop0.c2t(utc1, utc2).unwrap();
+    let ((tai1, tai2), _) = eraUtctai_safe(utc1, utc2).unwrap();
+    let (tt1, tt2) = eraTaitt_safe(tai1, tai2).unwrap();
+    let ((ut11, ut12), _) = eraUtcut1_safe(utc1, utc2, e.dut1).unwrap();
+    let rc2t0 = eraC2t06a_safe(tt1, tt2, ut11, ut12, e.xp, e.yp).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            vvd(rc2t[i][j], rc2t0[i][j], 1e-11, "EopTable::c2t", "r", status);
+        }
+    }
+
+    // The offsets themselves tilt the pole by about dX.
+    let rc2t = eop.c2t(utc1, utc2).unwrap();
+    vvd(
+        rc2t[2][0] - rc2t0[2][0],
+        e.dx,
+        1e-11,
+        "EopTable::c2t",
+        "dx",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_finals(&mut status);
+    t_c04(&mut status);
+    t_wrappers(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,340 @@
+#![allow(dead_code)]
+
+use erfa_rust::bodies::Body;
+use erfa_rust::events::*;
+use erfa_rust::H1_safe::{ERFA_DAS2R, ERFA_DD2R, ERFA_DPI};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+const MINUTE: f64 = 1.0 / 1440.0;
+
+fn star(rc: f64, dc: f64) -> Target {
+    Target::Star {
+        rc,
+        dc,
+        pr: 0.0,
+        pd: 0.0,
+        px: 0.0,
+        rv: 0.0,
+    }
+}
+
+fn kinds(s: &EventSearch, kind: EventKind) -> Vec<Event> {
+    s.events
+        .iter()
+        .copied()
+        .filter(|e| e.kind == kind)
+        .collect()
+}
+
+fn t_star(status: &mut i32) {
+    // Equatorial star from the equator: up for half a sidereal day, with
+    // the transit midway.
+    let site = Site::default();
+    let s = search(
+        star(1.0, 0.0),
+        &site,
+        Horizon::Altitude(0.0),
+        2460000.5,
+        0.0,
+        1.0,
+    )
+    .unwrap();
+    let (r, st, tr) = (
+        kinds(&s, EventKind::Rise),
+        kinds(&s, EventKind::Set),
+        kinds(&s, EventKind::Transit),
+    );
+    viv(r.len() as i32, 1, "search", "rises", status);
+    viv(st.len() as i32, 1, "search", "sets", status);
+    viv(tr.len() as i32, 1, "search", "transits", status);
+    viv(
+        kinds(&s, EventKind::LowerTransit).len() as i32,
+        1,
+        "search",
+        "lower transits",
+        status,
+    );
+    viv(s.always_up as i32, 0, "search", "always up", status);
+    viv(s.always_down as i32, 0, "search", "always down", status);
+    vvd(r[0].el, 0.0, 1e-6, "search", "rise el", status);
+    vvd(st[0].el, 0.0, 1e-6, "search", "set el", status);
+    // The star has precessed ~8′ since J2000, so only roughly east/zenith.
+    vvd(r[0].az, ERFA_DPI / 2.0, 5e-3, "search", "rise az", status);
+    vvd(
+        tr[0].el,
+        ERFA_DPI / 2.0,
+        5e-3,
+        "search",
+        "transit el",
+        status,
+    );
+    let (rise, set) = (r[0].utc2, st[0].utc2);
+    let up = if set > rise {
+        set - rise
+    } else {
+        set + 1.0 - rise
+    };
+    vvd(
+        up,
+        0.5 / 1.002_737_909,
+        1.0 * MINUTE,
+        "search",
+        "up",
+        status,
+    );
+    let mid = rise + 0.5 * up;
+    let mid = if mid > 1.0 { mid - 1.0 } else { mid };
+    vvd(tr[0].utc2, mid, 0.5 * MINUTE, "search", "transit", status);
+    vvd(r[0].utc1, 2460000.5, 0.0, "search", "utc1", status);
+
+    // Events come in time order.
+    let ordered = s.events.windows(2).all(|w| w[0].utc2 <= w[1].utc2);
+    viv(ordered as i32, 1, "search", "order", status);
+}
+
+fn t_polar(status: &mut i32) {
+    let site = Site {
+        phi: 50.0 * ERFA_DD2R,
+        ..Site::default()
+    };
+    let s = search(
+        star(1.0, 80.0 * ERFA_DD2R),
+        &site,
+        Horizon::Standard,
+        2460000.5,
+        0.0,
+        2.0,
+    )
+    .unwrap();
+    viv(s.always_up as i32, 1, "search", "circumpolar", status);
+    viv(
+        s.always_down as i32,
+        0,
+        "search",
+        "circumpolar down",
+        status,
+    );
+    viv(
+        kinds(&s, EventKind::Rise).len() as i32,
+        0,
+        "search",
+        "circumpolar rises",
+        status,
+    );
+    viv(
+        kinds(&s, EventKind::Transit).len() as i32,
+        2,
+        "search",
+        "circumpolar transits",
+        status,
+    );
+
+    let s = search(
+        star(1.0, -80.0 * ERFA_DD2R),
+        &site,
+        Horizon::Standard,
+        2460000.5,
+        0.0,
+        2.0,
+    )
+    .unwrap();
+    viv(s.always_down as i32, 1, "search", "never rises", status);
+    viv(s.always_up as i32, 0, "search", "never rises up", status);
+    viv(
+        kinds(&s, EventKind::Set).len() as i32,
+        0,
+        "search",
+        "never rises sets",
+        status,
+    );
+}
+
+fn t_sun(status: &mut i32) {
+    // Greenwich, 2024-06-21: sunrise 03:43, noon 12:02, sunset 20:21 UTC.
+    let site = Site {
+        elong: -0.0015 * ERFA_DD2R,
+        phi: 51.4769 * ERFA_DD2R,
+        hm: 46.0,
+        ..Site::default()
+    };
+    let s = search(
+        Target::Body(Body::Sun),
+        &site,
+        Horizon::Standard,
+        2460482.5,
+        0.0,
+        1.0,
+    )
+    .unwrap();
+    let (r, st, tr) = (
+        kinds(&s, EventKind::Rise),
+        kinds(&s, EventKind::Set),
+        kinds(&s, EventKind::Transit),
+    );
+    vvd(
+        r[0].utc2,
+        (3.0 * 60.0 + 43.0) * MINUTE,
+        1.0 * MINUTE,
+        "search",
+        "sunrise",
+        status,
+    );
+    vvd(
+        tr[0].utc2,
+        (12.0 * 60.0 + 2.0) * MINUTE,
+        1.0 * MINUTE,
+        "search",
+        "noon",
+        status,
+    );
+    vvd(
+        st[0].utc2,
+        (20.0 * 60.0 + 21.0) * MINUTE,
+        1.0 * MINUTE,
+        "search",
+        "sunset",
+        status,
+    );
+    // Centre at −50′ (34′ + 15.8′ semi-diameter at aphelion season).
+    vvd(
+        r[0].el,
+        -(34.0 + 15.75) * 60.0 * ERFA_DAS2R,
+        0.2 * 60.0 * ERFA_DAS2R,
+        "search",
+        "sunrise el",
+        status,
+    );
+    let a = search(
+        Target::Body(Body::Sun),
+        &site,
+        Horizon::Altitude(0.0),
+        2460482.5,
+        0.0,
+        1.0,
+    )
+    .unwrap();
+    let ar = kinds(&a, EventKind::Rise);
+    viv(
+        (ar[0].utc2 > r[0].utc2 + 5.0 * MINUTE) as i32,
+        1,
+        "search",
+        "geometric sunrise later",
+        status,
+    );
+}
+
+fn t_moon(status: &mut i32) {
+    // Successive upper transits of the Moon are 24h 50m ± 40m apart, and
+    // at rise the centre sits 34′ + semi-diameter below the horizon.
+    let site = Site {
+        elong: -70.0 * ERFA_DD2R,
+        phi: -30.0 * ERFA_DD2R,
+        hm: 2400.0,
+        ..Site::default()
+    };
+    let s = search(
+        Target::Body(Body::Moon),
+        &site,
+        Horizon::Standard,
+        2460400.5,
+        0.0,
+        3.0,
+    )
+    .unwrap();
+    let tr = kinds(&s, EventKind::Transit);
+    viv((tr.len() >= 2) as i32, 1, "search", "Moon transits", status);
+    vvd(
+        tr[1].utc2 - tr[0].utc2,
+        1.0 + 50.0 * MINUTE,
+        40.0 * MINUTE,
+        "search",
+        "Moon transit interval",
+        status,
+    );
+    let r = kinds(&s, EventKind::Rise);
+    viv((r.len() >= 2) as i32, 1, "search", "Moon rises", status);
+    vvd(
+        r[0].el,
+        -(34.0 + 15.5) * 60.0 * ERFA_DAS2R,
+        1.5 * 60.0 * ERFA_DAS2R,
+        "search",
+        "Moon rise el",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_star(&mut status);
+    t_polar(&mut status);
+    t_sun(&mut status);
+    t_moon(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
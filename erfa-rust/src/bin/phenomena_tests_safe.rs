@@ -0,0 +1,164 @@
+#![allow(dead_code)]
+
+use erfa_rust::bodies::Body;
+use erfa_rust::phenomena::*;
+use erfa_rust::G13_safe::Epv00;
+use erfa_rust::G8_safe::eraCal2jd_safe;
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+const MINUTE: f64 = 1.0 / 1440.0;
+
+// TT−UTC in 2024 (37 s + 32.184 s).
+const TT_UTC: f64 = 69.184 / 86400.0;
+
+// TT (two-part JD) of a UTC date and time in 2024.
+fn tt(im: i32, id: i32, h: f64, m: f64) -> (f64, f64) {
+    let (d1, d2) = eraCal2jd_safe(2024, im, id).unwrap();
+    (d1 + d2, (60.0 * h + m) * MINUTE + TT_UTC)
+}
+
+fn t_seasons(status: &mut i32) {
+    let (t1, t2) = tt(1, 1, 0.0, 0.0);
+    let found = seasons(t1, t2, 366.0).unwrap();
+    viv(found.len() as i32, 4, "seasons", "count", status);
+
+    // Published instants, to the minute (UTC).
+    let want = [
+        (Season::MarchEquinox, tt(3, 20, 3.0, 6.0)),
+        (Season::JuneSolstice, tt(6, 20, 20.0, 51.0)),
+        (Season::SeptemberEquinox, tt(9, 22, 12.0, 44.0)),
+        (Season::DecemberSolstice, tt(12, 21, 9.0, 21.0)),
+    ];
+    for (e, (season, (w1, w2))) in found.iter().zip(want) {
+        viv((e.season == season) as i32, 1, "seasons", "kind", status);
+        vvd(e.tt1, t1, 0.0, "seasons", "tt1", status);
+        vvd(
+            (e.tt1 - w1) + e.tt2 - w2,
+            0.0,
+            1.0 * MINUTE,
+            "seasons",
+            "instant",
+            status,
+        );
+        let (l, b) = apparent_ecliptic(&Epv00, Body::Sun, e.tt1, e.tt2).unwrap();
+        let dl = (l - season.longitude() + 1.0).rem_euclid(std::f64::consts::TAU) - 1.0;
+        vvd(dl, 0.0, 1e-9, "seasons", "longitude", status);
+        vvd(b, 0.0, 1e-5, "seasons", "latitude", status);
+    }
+}
+
+fn t_phases(status: &mut i32) {
+    // January 2024 lunation, to the minute (UTC).
+    let (t1, t2) = tt(1, 5, 0.0, 0.0);
+    let found = moon_phases(t1, t2, 30.0).unwrap();
+    viv(found.len() as i32, 4, "moon_phases", "count", status);
+    let want = [
+        (MoonPhase::New, tt(1, 11, 11.0, 57.0)),
+        (MoonPhase::FirstQuarter, tt(1, 18, 3.0, 53.0)),
+        (MoonPhase::Full, tt(1, 25, 17.0, 54.0)),
+        (MoonPhase::LastQuarter, tt(2, 2, 23.0, 18.0)),
+    ];
+    for (e, (phase, (w1, w2))) in found.iter().zip(want) {
+        viv((e.phase == phase) as i32, 1, "moon_phases", "kind", status);
+        vvd(
+            (e.tt1 - w1) + e.tt2 - w2,
+            0.0,
+            2.0 * MINUTE,
+            "moon_phases",
+            "instant",
+            status,
+        );
+    }
+
+    // A year holds 12 or 13 of each, in cycle order.
+    let (t1, t2) = tt(1, 1, 0.0, 0.0);
+    let found = moon_phases(t1, t2, 366.0).unwrap();
+    viv(
+        (found.len() >= 49 && found.len() <= 51) as i32,
+        1,
+        "moon_phases",
+        "year",
+        status,
+    );
+    let cycle = found.windows(2).all(|w| {
+        let k = |p: MoonPhase| MoonPhase::ALL.iter().position(|&q| q == p).unwrap();
+        k(w[1].phase) == (k(w[0].phase) + 1) % 4
+            && w[1].tt2 - w[0].tt2 > 5.5
+            && w[1].tt2 - w[0].tt2 < 8.5
+    });
+    viv(cycle as i32, 1, "moon_phases", "cycle", status);
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_seasons(&mut status);
+    t_phases(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,275 @@
+#![allow(dead_code)]
+
+use erfa_rust::refraction::*;
+use erfa_rust::G28_safe::{eraRefco_safe, Refraction, TwoTerm};
+use erfa_rust::H1_safe::{ERFA_DAS2R, ERFA_DD2R};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+// Zenith distance (radians) of an elevation in degrees.
+fn zd(el: f64) -> f64 {
+    (90.0 - el) * ERFA_DD2R
+}
+
+fn t_ray_trace(status: &mut i32) {
+    // SLALIB's sla_REFRO test case, optical and radio.
+    let m = RayTrace {
+        phpa: 678.9,
+        tc: 280.0 - 273.15,
+        rh: 0.9,
+        wl: 0.55,
+        hm: 3456.7,
+        phi: -0.3,
+        tlr: 0.006,
+        eps: 1e-9,
+    };
+    vvd(
+        m.for_observed(1.4),
+        0.00106715763,
+        1e-12,
+        "RayTrace",
+        "optical",
+        status,
+    );
+    let radio = RayTrace { wl: 1000.0, ..m };
+    vvd(
+        radio.for_observed(1.4),
+        0.001296416185295523,
+        1e-12,
+        "RayTrace",
+        "radio",
+        status,
+    );
+    vvd(
+        m.for_observed(-1.4),
+        -0.00106715763,
+        1e-12,
+        "RayTrace",
+        "sign",
+        status,
+    );
+
+    // Inverse, down to below the horizon.
+    for el in [60.0, 10.0, 1.0, -0.5] {
+        let zo = zd(el);
+        let r = m.for_observed(zo);
+        vvd(
+            m.for_topocentric(zo + r),
+            r,
+            1e-12,
+            "RayTrace",
+            "inverse",
+            status,
+        );
+    }
+
+    // Against the two-term model well above the horizon, and its failure
+    // near it.
+    let m = RayTrace::new(1010.0, 10.0, 0.5, 0.55, 0.0, 0.7);
+    let (refa, refb) = eraRefco_safe(1010.0, 10.0, 0.5, 0.55).unwrap();
+    let green = TwoTerm { refa, refb };
+    for el in [80.0, 45.0, 20.0] {
+        let z = zd(el);
+        vvd(
+            m.for_observed(z),
+            green.for_observed(z),
+            0.05 * ERFA_DAS2R,
+            "RayTrace",
+            "refco",
+            status,
+        );
+    }
+    let z = zd(0.0);
+    viv(
+        (m.for_observed(z) - green.for_observed(z) > 1000.0 * ERFA_DAS2R) as i32,
+        1,
+        "RayTrace",
+        "horizon",
+        status,
+    );
+    vvd(
+        m.for_observed(z) / ERFA_DAS2R,
+        2030.0,
+        60.0,
+        "RayTrace",
+        "horizon",
+        status,
+    );
+}
+
+fn t_bennett(status: &mut i32) {
+    let m = Bennett::default();
+    vvd(
+        m.for_observed(zd(0.0)),
+        0.5746255623877095 * ERFA_DD2R,
+        1e-15,
+        "Bennett",
+        "horizon",
+        status,
+    );
+    vvd(
+        m.for_observed(zd(90.0)),
+        0.0,
+        0.0,
+        "Bennett",
+        "zenith",
+        status,
+    );
+    let thin = Bennett {
+        phpa: 731.0,
+        tc: 12.8,
+    };
+    vvd(
+        thin.for_observed(zd(0.0)),
+        0.4118178396851382 * ERFA_DD2R,
+        1e-15,
+        "Bennett",
+        "scaled",
+        status,
+    );
+
+    // The inverse starts from Saemundsson's formula, which is within 0.1′
+    // of it in the standard atmosphere above the horizon.
+    for m in [m, thin] {
+        for el in [45.0, 10.0, 2.0, 0.0, -0.5] {
+            let zo = zd(el);
+            let r = m.for_observed(zo);
+            vvd(
+                m.for_topocentric(zo + r),
+                r,
+                1e-12,
+                "Bennett",
+                "inverse",
+                status,
+            );
+        }
+    }
+    let zt = zd(5.0);
+    let saemundsson = 1.02 / ((5.0 + 10.3 / 10.11) * ERFA_DD2R).tan() / 60.0 * ERFA_DD2R;
+    vvd(
+        m.for_topocentric(zt),
+        saemundsson,
+        0.1 / 60.0 * ERFA_DD2R,
+        "Bennett",
+        "Saemundsson",
+        status,
+    );
+}
+
+fn t_itur(status: &mut i32) {
+    let m = ItuR::default();
+    vvd(
+        m.for_topocentric(zd(0.0)),
+        0.76103500761035 * ERFA_DD2R,
+        1e-15,
+        "ItuR",
+        "horizon",
+        status,
+    );
+    let high = ItuR { hm: 2000.0 };
+    vvd(
+        high.for_topocentric(zd(0.0)),
+        0.5526901641047635 * ERFA_DD2R,
+        1e-15,
+        "ItuR",
+        "height",
+        status,
+    );
+    vvd(
+        m.for_topocentric(zd(90.0)),
+        0.0,
+        1e-15,
+        "ItuR",
+        "zenith",
+        status,
+    );
+
+    // Continuous at 10°, and inverted exactly.
+    vvd(
+        m.for_topocentric(zd(10.0 + 1e-9)),
+        m.for_topocentric(zd(10.0)),
+        1e-12,
+        "ItuR",
+        "10°",
+        status,
+    );
+    for el in [30.0, 10.0, 3.0, 0.0] {
+        let zt = zd(el);
+        let r = m.for_topocentric(zt);
+        vvd(m.for_observed(zt - r), r, 1e-12, "ItuR", "inverse", status);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_ray_trace(&mut status);
+    t_bennett(&mut status);
+    t_itur(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
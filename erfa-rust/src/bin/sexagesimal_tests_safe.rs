@@ -0,0 +1,247 @@
+#![allow(dead_code)]
+
+use erfa_rust::sexagesimal::*;
+use erfa_rust::G1_safe::eraAf2a_safe;
+use erfa_rust::G32_safe::eraTf2a_safe;
+use erfa_rust::H1_safe::{ErfaError, ERFA_DAS2R};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+fn t_parse(status: &mut i32) {
+    let (ra0, _) = eraTf2a_safe('+', 12, 34, 56.7).unwrap();
+    for s in [
+        "12h34m56.7s",
+        "12:34:56.7",
+        " 12 34 56.7 ",
+        "12ʰ34ᵐ56.7ˢ",
+        "12h 34m 56.7s",
+    ] {
+        let (ra, j) = parse_hms(s).unwrap();
+        vvd(ra, ra0, 1e-15, "parse_hms", s, status);
+        viv(j.code(), 0, "parse_hms", s, status);
+    }
+
+    let (dec0, _) = eraAf2a_safe('-', 5, 23, 28.4).unwrap();
+    for s in [
+        "-05:23:28.4",
+        "-5d23m28.4s",
+        "−05°23′28.4″",
+        "-05 23 28.4",
+        "-5°23'28.4\"",
+    ] {
+        let (dec, j) = parse_dms(s).unwrap();
+        vvd(dec, dec0, 1e-15, "parse_dms", s, status);
+        viv(j.code(), 0, "parse_dms", s, status);
+    }
+    let (dec, _) = parse_dms("+12 34 56").unwrap();
+    vvd(
+        dec,
+        (12.0 * 3600.0 + 34.0 * 60.0 + 56.0) * ERFA_DAS2R,
+        1e-15,
+        "parse_dms",
+        "+12 34 56",
+        status,
+    );
+    let (dec, _) = parse_dms("12°34′56″").unwrap();
+    vvd(
+        dec,
+        (12.0 * 3600.0 + 34.0 * 60.0 + 56.0) * ERFA_DAS2R,
+        1e-15,
+        "parse_dms",
+        "symbols",
+        status,
+    );
+
+    // Fewer fields: the last one may be fractional.
+    let (a, _) = parse_dms("12.5").unwrap();
+    vvd(
+        a,
+        12.5 * 3600.0 * ERFA_DAS2R,
+        1e-15,
+        "parse_dms",
+        "decimal",
+        status,
+    );
+    let (a, _) = parse_dms("12:30.5").unwrap();
+    vvd(
+        a,
+        (12.0 * 3600.0 + 30.5 * 60.0) * ERFA_DAS2R,
+        1e-15,
+        "parse_dms",
+        "2 fields",
+        status,
+    );
+
+    // Range problems come back as eraTf2a/eraAf2a report them.
+    let (_, j) = parse_hms("24:00:00").unwrap();
+    viv(j.code(), 1, "parse_hms", "hour 24", status);
+    let (_, j) = parse_dms("10:60:00").unwrap();
+    viv(j.code(), 2, "parse_dms", "minute 60", status);
+    let (_, j) = parse_dms("10:00:60.0").unwrap();
+    viv(j.code(), 3, "parse_dms", "second 60", status);
+
+    // Units from markers, and conflicts.
+    let (a, _) = parse_angle("1h", SexagesimalUnit::Degrees).unwrap();
+    vvd(
+        a,
+        15.0 * 3600.0 * ERFA_DAS2R,
+        1e-15,
+        "parse_angle",
+        "h",
+        status,
+    );
+    let (a, _) = parse_angle("1:00:00", SexagesimalUnit::Degrees).unwrap();
+    vvd(
+        a,
+        3600.0 * ERFA_DAS2R,
+        1e-15,
+        "parse_angle",
+        "default",
+        status,
+    );
+    for s in [
+        "",
+        "12:34:56:7",
+        "12.5:30",
+        "12m34h",
+        "1:2x",
+        "12::34",
+        "abc",
+        "1h2d",
+        "--1",
+    ] {
+        viv(
+            (parse_dms(s) == Err(ErfaError::BadAngleString)) as i32,
+            1,
+            "parse_dms",
+            s,
+            status,
+        );
+    }
+    viv(
+        (parse_hms("12°34′56″") == Err(ErfaError::BadAngleString)) as i32,
+        1,
+        "parse_hms",
+        "degrees",
+        status,
+    );
+    viv(
+        (parse_dms("12h34m56s") == Err(ErfaError::BadAngleString)) as i32,
+        1,
+        "parse_dms",
+        "hours",
+        status,
+    );
+}
+
+fn t_format(status: &mut i32) {
+    let (ra, _) = eraTf2a_safe('+', 12, 34, 56.7).unwrap();
+    let want = [
+        (SexagesimalStyle::Colon, "12:34:56.70"),
+        (SexagesimalStyle::Space, "12 34 56.70"),
+        (SexagesimalStyle::Letters, "12h34m56.70s"),
+        (SexagesimalStyle::Symbols, "12ʰ34ᵐ56.70ˢ"),
+    ];
+    for (style, s) in want {
+        let f = format_hms(ra, 2, style).unwrap();
+        viv((f == s) as i32, 1, "format_hms", s, status);
+        let (back, _) = parse_hms(&f).unwrap();
+        vvd(back, ra, 1e-15, "format_hms", "round trip", status);
+    }
+
+    let (dec, _) = eraAf2a_safe('-', 5, 23, 28.4).unwrap();
+    let want = [
+        (SexagesimalStyle::Colon, "-05:23:28.4"),
+        (SexagesimalStyle::Space, "-05 23 28.4"),
+        (SexagesimalStyle::Letters, "-05d23m28.4s"),
+        (SexagesimalStyle::Symbols, "-05°23′28.4″"),
+    ];
+    for (style, s) in want {
+        let f = format_dms(dec, 1, style).unwrap();
+        viv((f == s) as i32, 1, "format_dms", s, status);
+        let (back, _) = parse_dms(&f).unwrap();
+        vvd(back, dec, 1e-15, "format_dms", "round trip", status);
+    }
+    let f = format_dms(-dec, 0, SexagesimalStyle::Colon).unwrap();
+    viv((f == "+05:23:28") as i32, 1, "format_dms", "sign", status);
+    viv(
+        (format_dms(dec, 10, SexagesimalStyle::Colon) == Err(ErfaError::BadPrecision)) as i32,
+        1,
+        "format_dms",
+        "ndp",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_parse(&mut status);
+    t_format(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,533 @@
+#![allow(dead_code)]
+
+use erfa_rust::events::Site;
+use erfa_rust::sgp4::*;
+use erfa_rust::G17_safe::eraGd2gc_safe;
+use erfa_rust::G32_safe::eraTaitt_safe;
+use erfa_rust::G33_safe::{eraUtctai_safe, eraUtcut1_safe};
+use erfa_rust::G8_safe::eraCal2jd_safe;
+use erfa_rust::H1_safe::{ErfaError, ERFA_DAS2R, ERFA_DD2R, ERFA_DPI};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+// Verification element sets from Vallado et al. (2006).
+const VANGUARD: [&str; 2] = [
+    "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+    "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+];
+const LOW_PERIGEE: [&str; 2] = [
+    "1 06251U 62025E   06176.82412014  .00008885  00000-0  12808-3 0  3985",
+    "2 06251  58.0579  54.0425 0030035 139.1568 221.1854 15.56387291  6774",
+];
+const MOLNIYA: [&str; 2] = [
+    "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813",
+    "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656",
+];
+const GEOSYNC: [&str; 2] = [
+    "1 14128U 83058A   06176.02844893 -.00000158  00000-0  10000-3 0  9627",
+    "2 14128  11.4384  35.2134 0011562  26.4582 333.5652  0.98870114 46093",
+];
+
+fn sgp4(lines: [&str; 2]) -> Sgp4 {
+    Sgp4::new(&Tle::parse(lines[0], lines[1]).unwrap()).unwrap()
+}
+
+// Compare a pv (m, m/s) with one in km and km/s.
+fn vpv(
+    pv: &[[f64; 3]; 2],
+    want: [f64; 6],
+    dp: f64,
+    dv: f64,
+    func: &str,
+    test: &str,
+    status: &mut i32,
+) {
+    for i in 0..3 {
+        vvd(pv[0][i], want[i] * 1e3, dp, func, test, status);
+        vvd(pv[1][i], want[3 + i] * 1e3, dv, func, test, status);
+    }
+}
+
+fn t_tle(status: &mut i32) {
+    let tle = Tle::parse(VANGUARD[0], VANGUARD[1]).unwrap();
+    viv(
+        (tle.catalog == "00005") as i32,
+        1,
+        "Tle::parse",
+        "catalog",
+        status,
+    );
+    viv(
+        (tle.designator == "58002B") as i32,
+        1,
+        "Tle::parse",
+        "designator",
+        status,
+    );
+    viv(
+        (tle.classification == 'U') as i32,
+        1,
+        "Tle::parse",
+        "class",
+        status,
+    );
+    vvd(
+        tle.epoch1 + tle.epoch2,
+        2451723.28495062,
+        1e-8,
+        "Tle::parse",
+        "epoch",
+        status,
+    );
+    vvd(tle.ndot, 2.3e-7, 1e-20, "Tle::parse", "ndot", status);
+    vvd(tle.nddot, 0.0, 0.0, "Tle::parse", "nddot", status);
+    vvd(tle.bstar, 2.8098e-5, 1e-18, "Tle::parse", "bstar", status);
+    viv(tle.element_number, 475, "Tle::parse", "element", status);
+    vvd(
+        tle.inclination,
+        34.2682 * ERFA_DD2R,
+        1e-15,
+        "Tle::parse",
+        "incl",
+        status,
+    );
+    vvd(
+        tle.eccentricity,
+        0.1859667,
+        1e-15,
+        "Tle::parse",
+        "ecc",
+        status,
+    );
+    vvd(
+        tle.mean_motion,
+        10.82419157,
+        1e-12,
+        "Tle::parse",
+        "n",
+        status,
+    );
+    viv(tle.revolution, 41366, "Tle::parse", "rev", status);
+
+    let tle = Tle::parse(GEOSYNC[0], GEOSYNC[1]).unwrap();
+    vvd(
+        tle.ndot,
+        -1.58e-6,
+        1e-20,
+        "Tle::parse",
+        "negative ndot",
+        status,
+    );
+    vvd(tle.bstar, 1e-4, 1e-18, "Tle::parse", "bstar", status);
+
+    // A changed digit fails the checksum; a short or mismatched line is
+    // malformed.
+    let bad = VANGUARD[1].replace("34.2682", "34.2683");
+    viv(
+        (Tle::parse(VANGUARD[0], &bad) == Err(ErfaError::TleChecksum)) as i32,
+        1,
+        "Tle::parse",
+        "checksum",
+        status,
+    );
+    viv(
+        (Tle::parse(VANGUARD[0], &VANGUARD[1][..60]) == Err(ErfaError::TleSyntax)) as i32,
+        1,
+        "Tle::parse",
+        "short",
+        status,
+    );
+    viv(
+        (Tle::parse(VANGUARD[0], GEOSYNC[1]) == Err(ErfaError::TleSyntax)) as i32,
+        1,
+        "Tle::parse",
+        "catalog mismatch",
+        status,
+    );
+
+    let text = format!(
+        "VANGUARD 1\n{}\n{}\n\n{}\n{}\n",
+        VANGUARD[0], VANGUARD[1], MOLNIYA[0], MOLNIYA[1]
+    );
+    let all = Tle::parse_all(&text).unwrap();
+    viv(all.len() as i32, 2, "Tle::parse_all", "count", status);
+    viv(
+        (all[0].name == "VANGUARD 1") as i32,
+        1,
+        "Tle::parse_all",
+        "name",
+        status,
+    );
+    viv(
+        all[1].name.is_empty() as i32,
+        1,
+        "Tle::parse_all",
+        "no name",
+        status,
+    );
+    viv(
+        (all[1].catalog == "08195") as i32,
+        1,
+        "Tle::parse_all",
+        "second",
+        status,
+    );
+}
+
+fn t_sgp4(status: &mut i32) {
+    // Near-Earth, against Vallado's verification output.
+    let s = sgp4(VANGUARD);
+    viv(s.is_deep_space() as i32, 0, "Sgp4", "near", status);
+    let cases = [
+        (
+            0.0,
+            [
+                7022.46529266,
+                -1400.08296755,
+                0.03995155,
+                1.893841015,
+                6.405893759,
+                4.534807250,
+            ],
+        ),
+        (
+            360.0,
+            [
+                -7154.03120202,
+                -3783.17682504,
+                -3536.19412294,
+                4.741887409,
+                -4.151817765,
+                -2.093935425,
+            ],
+        ),
+        (
+            4320.0,
+            [
+                -9060.47373569,
+                4658.70952502,
+                813.68673153,
+                -2.232832783,
+                -4.110453490,
+                -3.157345433,
+            ],
+        ),
+    ];
+    for (t, want) in cases {
+        vpv(
+            &s.propagate(t).unwrap(),
+            want,
+            1e-4,
+            1e-6,
+            "Sgp4",
+            "00005",
+            status,
+        );
+    }
+    let s = sgp4(LOW_PERIGEE);
+    vpv(
+        &s.propagate(0.0).unwrap(),
+        [
+            3988.31022699,
+            5498.96657235,
+            0.90055879,
+            -3.290032738,
+            2.357652820,
+            6.496623475,
+        ],
+        1e-4,
+        1e-6,
+        "Sgp4",
+        "06251",
+        status,
+    );
+
+    // The same at the epoch given as a UTC date.
+    let pv = s.pv(s.epoch1, s.epoch2).unwrap();
+    vvd(pv[0][0], 3988310.22699, 1e-4, "Sgp4::pv", "epoch", status);
+
+    // Deep space: 12-hour resonant and geosynchronous, at the epoch.
+    let s = sgp4(MOLNIYA);
+    viv(s.is_deep_space() as i32, 1, "Sgp4", "deep", status);
+    vpv(
+        &s.propagate(0.0).unwrap(),
+        [
+            2349.89483350,
+            -14785.93811562,
+            0.02119378,
+            2.721488096,
+            -3.256811655,
+            4.498416672,
+        ],
+        1e-4,
+        1e-6,
+        "Sgp4",
+        "08195",
+        status,
+    );
+    let g = sgp4(GEOSYNC);
+    vpv(
+        &g.propagate(0.0).unwrap(),
+        [
+            34747.57932696,
+            24502.37114079,
+            -1.32832986,
+            -1.731642662,
+            2.452772615,
+            0.608510081,
+        ],
+        1e-4,
+        1e-6,
+        "Sgp4",
+        "14128",
+        status,
+    );
+
+    // The resonance integration steps every 720 minutes; the orbit must
+    // run smoothly across a step.
+    for (s, name) in [(&s, "08195 step"), (&g, "14128 step")] {
+        let dt = 0.001;
+        let a = s.propagate(1440.0 - dt).unwrap();
+        let b = s.propagate(1440.0 + dt).unwrap();
+        for i in 0..3 {
+            let v = 0.5 * (a[1][i] + b[1][i]);
+            vvd(
+                b[0][i] - a[0][i],
+                v * 120.0 * dt,
+                0.05,
+                "Sgp4",
+                name,
+                status,
+            );
+        }
+    }
+
+    // Enough drag brings the satellite down.
+    let mut tle = Tle::parse(LOW_PERIGEE[0], LOW_PERIGEE[1]).unwrap();
+    tle.bstar = 0.1;
+    let s = Sgp4::new(&tle).unwrap();
+    viv(s.propagate(1e5).is_err() as i32, 1, "Sgp4", "decay", status);
+    tle.eccentricity = 1.2;
+    viv(
+        (Sgp4::new(&tle).err() == Some(ErfaError::Sgp4Elements)) as i32,
+        1,
+        "Sgp4",
+        "hyperbolic",
+        status,
+    );
+}
+
+fn t_frames(status: &mut i32) {
+    // Vallado's reduction example: 2004 April 6, 07:51:28.386009 UTC.
+    let (d1, d2) = eraCal2jd_safe(2004, 4, 6).unwrap();
+    let (utc1, utc2) = (d1 + d2, (7.0 * 3600.0 + 51.0 * 60.0 + 28.386009) / 86400.0);
+    let ((ut11, ut12), _) = eraUtcut1_safe(utc1, utc2, -0.4399619).unwrap();
+    let ((tai1, tai2), _) = eraUtctai_safe(utc1, utc2).unwrap();
+    let (tt1, tt2) = eraTaitt_safe(tai1, tai2).unwrap();
+    let (xp, yp) = (-0.140682 * ERFA_DAS2R, 0.333309 * ERFA_DAS2R);
+    let teme = [
+        [5094180.16210, 6127644.65950, 6380344.53270],
+        [-4746.131487, 785.818041, 5531.931288],
+    ];
+
+    let itrs = teme_to_itrs(&teme, ut11, ut12, xp, yp).unwrap();
+    vpv(
+        &itrs,
+        [
+            -1033.4793830,
+            7901.2952754,
+            6380.3565958,
+            -3.225636520,
+            -2.872451450,
+            5.531924446,
+        ],
+        0.02,
+        2e-5,
+        "teme_to_itrs",
+        "Vallado",
+        status,
+    );
+    let back = itrs_to_teme(&itrs, ut11, ut12, xp, yp).unwrap();
+    vpv(
+        &back,
+        [
+            5094.18016210,
+            6127.64465950,
+            6380.34453270,
+            -4.746131487,
+            0.785818041,
+            5.531931288,
+        ],
+        1e-6,
+        1e-9,
+        "itrs_to_teme",
+        "round trip",
+        status,
+    );
+
+    // IAU 2006/2000A against Vallado's IAU 1976/1980 with EOP nutation
+    // corrections.
+    let gcrs = teme_to_gcrs(&teme, tt1, tt2).unwrap();
+    for (i, want) in [5102508.958, 6123011.401, 6378136.928].iter().enumerate() {
+        vvd(gcrs[0][i], *want, 0.5, "teme_to_gcrs", "Vallado", status);
+    }
+    let back = gcrs_to_teme(&gcrs, tt1, tt2).unwrap();
+    for i in 0..3 {
+        vvd(
+            back[0][i],
+            teme[0][i],
+            1e-6,
+            "gcrs_to_teme",
+            "round trip p",
+            status,
+        );
+        vvd(
+            back[1][i],
+            teme[1][i],
+            1e-9,
+            "gcrs_to_teme",
+            "round trip v",
+            status,
+        );
+    }
+}
+
+fn t_look_angles(status: &mut i32) {
+    let site = Site {
+        elong: -104.883 * ERFA_DD2R,
+        phi: 39.007 * ERFA_DD2R,
+        hm: 2194.0,
+        dut1: 0.1,
+        xp: 0.2 * ERFA_DAS2R,
+        yp: 0.3 * ERFA_DAS2R,
+    };
+    let (utc1, utc2) = (2460000.5, 0.3);
+    let ((ut11, ut12), _) = eraUtcut1_safe(utc1, utc2, site.dut1).unwrap();
+
+    // Points fixed in ITRS above, north and east of the site.
+    let base = eraGd2gc_safe(1, site.elong, site.phi, site.hm).unwrap();
+    let (sl, cl) = site.elong.sin_cos();
+    let (sp, cp) = site.phi.sin_cos();
+    let up = [cp * cl, cp * sl, sp];
+    let north = [-sp * cl, -sp * sl, cp];
+    let east = [-sl, cl, 0.0];
+    let look = |dir: [f64; 3], d: f64, vel: f64| {
+        let mut itrs = [[0.0; 3]; 2];
+        for i in 0..3 {
+            itrs[0][i] = base[i] + d * dir[i];
+            itrs[1][i] = vel * dir[i];
+        }
+        let teme = itrs_to_teme(&itrs, ut11, ut12, site.xp, site.yp).unwrap();
+        look_angles(&teme, &site, utc1, utc2).unwrap()
+    };
+
+    let a = look(up, 500e3, 0.0);
+    vvd(
+        a.el,
+        ERFA_DPI / 2.0,
+        1e-9,
+        "look_angles",
+        "zenith el",
+        status,
+    );
+    vvd(a.range, 500e3, 1e-6, "look_angles", "zenith range", status);
+    vvd(a.range_rate, 0.0, 1e-6, "look_angles", "fixed", status);
+    let a = look(up, 500e3, 1000.0);
+    vvd(
+        a.range_rate,
+        1000.0,
+        1e-6,
+        "look_angles",
+        "receding",
+        status,
+    );
+    let a = look(north, 100e3, 0.0);
+    vvd(a.az.sin(), 0.0, 1e-9, "look_angles", "north az", status);
+    vvd(a.az.cos(), 1.0, 1e-9, "look_angles", "north az", status);
+    vvd(a.el, 0.0, 1e-9, "look_angles", "north el", status);
+    let a = look(east, 100e3, -50.0);
+    vvd(a.az, ERFA_DPI / 2.0, 1e-9, "look_angles", "east az", status);
+    vvd(
+        a.range_rate,
+        -50.0,
+        1e-6,
+        "look_angles",
+        "approaching",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_tle(&mut status);
+    t_sgp4(&mut status);
+    t_frames(&mut status);
+    t_look_angles(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,648 @@
+#![allow(dead_code)]
+
+use erfa_rust::bodies::{place_with, Body, BodyEphemeris};
+use erfa_rust::spk::*;
+use erfa_rust::G13_safe::{eraEpv00_safe, Ephemeris, Epv00};
+use erfa_rust::G1_safe::{eraApcg13Eph_safe, eraApcg13_safe, eraApci13Eph_safe, eraApci13_safe};
+use erfa_rust::G2_safe::{eraApco13Eph_safe, eraApco13_safe, eraApcs13Eph_safe, eraApcs13_safe};
+use erfa_rust::H1_safe::{eraASTROM, ErfaError, ErfaResult, ERFA_DAU, ERFA_DAYSEC, ERFA_DJ00};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+// A synthetic segment: summary plus data words.
+struct Seg {
+    target: i32,
+    center: i32,
+    data_type: i32,
+    start_et: f64,
+    end_et: f64,
+    words: Vec<f64>,
+}
+
+// Chebyshev record data for type 2/3 with N records of RSIZE words over
+// [init, init + n·intlen], followed by the INIT/INTLEN/RSIZE/N directory.
+fn cheb_segment(
+    target: i32,
+    center: i32,
+    data_type: i32,
+    init: f64,
+    intlen: f64,
+    recs: &[Vec<f64>],
+) -> Seg {
+    let mut words = Vec::new();
+    for (i, c) in recs.iter().enumerate() {
+        words.push(init + (i as f64 + 0.5) * intlen);
+        words.push(intlen / 2.0);
+        words.extend_from_slice(c);
+    }
+    let rsize = 2 + recs[0].len();
+    words.extend_from_slice(&[init, intlen, rsize as f64, recs.len() as f64]);
+    Seg {
+        target,
+        center,
+        data_type,
+        start_et: init,
+        end_et: init + intlen * recs.len() as f64,
+        words,
+    }
+}
+
+// File record, one summary record, one name record, then the data.
+fn build_daf(segs: &[Seg], big: bool) -> Vec<u8> {
+    let f = |x: f64| {
+        if big {
+            x.to_be_bytes()
+        } else {
+            x.to_le_bytes()
+        }
+    };
+    let i = |x: i32| {
+        if big {
+            x.to_be_bytes()
+        } else {
+            x.to_le_bytes()
+        }
+    };
+
+    let mut out = vec![0u8; 3 * 1024];
+    out[0..8].copy_from_slice(b"DAF/SPK ");
+    out[8..12].copy_from_slice(&i(2));
+    out[12..16].copy_from_slice(&i(6));
+    out[16..76].fill(b' ');
+    out[76..80].copy_from_slice(&i(2));
+    out[80..84].copy_from_slice(&i(2));
+    out[88..96].copy_from_slice(if big { b"BIG-IEEE" } else { b"LTL-IEEE" });
+    out[2048..3072].fill(b' ');
+
+    let mut summary = vec![0.0, 0.0, segs.len() as f64];
+    let mut addr = 3 * 128 + 1;
+    let mut ints = Vec::new();
+    for s in segs {
+        let end = addr + s.words.len() - 1;
+        ints.push([s.target, s.center, 1, s.data_type, addr as i32, end as i32]);
+        summary.extend_from_slice(&[s.start_et, s.end_et, 0.0, 0.0, 0.0]);
+        addr = end + 1;
+        for w in &s.words {
+            out.extend_from_slice(&f(*w));
+        }
+    }
+    out[84..88].copy_from_slice(&i(addr as i32));
+    for (k, w) in summary.iter().enumerate() {
+        out[1024 + 8 * k..1024 + 8 * k + 8].copy_from_slice(&f(*w));
+    }
+    for (k, n) in ints.iter().enumerate() {
+        for (j, v) in n.iter().enumerate() {
+            let o = 1024 + 8 * (3 + 5 * k + 2) + 4 * j;
+            out[o..o + 4].copy_from_slice(&i(*v));
+        }
+    }
+    out
+}
+
+// Chebyshev sum via T_n(t) = cos(n acos t).
+fn cheb(c: &[f64], t: f64) -> f64 {
+    c.iter()
+        .enumerate()
+        .map(|(n, k)| k * (n as f64 * t.acos()).cos())
+        .sum()
+}
+
+const DAY: f64 = 86_400.0;
+
+// EMB about the SSB and Sun about the SSB as type 2, two 10-day records
+// each; Earth about the EMB as type 3, one 20-day record.
+fn kernel_segments() -> Vec<Seg> {
+    let emb = vec![
+        vec![
+            1.0e8, 2.0e6, -3.0e4, 4.0e2, -5.0e7, 1.0e6, 2.0e4, -1.0e2, 2.0e7, -4.0e5, 1.0e3, 5.0,
+        ],
+        vec![
+            1.1e8, 1.5e6, 3.0e4, -4.0e2, -4.0e7, 2.0e6, -2.0e4, 1.0e2, 2.5e7, 4.0e5, -1.0e3, -5.0,
+        ],
+    ];
+    let sun = vec![
+        vec![
+            5.0e5, 1.0e3, 10.0, -1.0, 3.0e5, -2.0e3, 20.0, 2.0, -1.0e5, 5.0e2, -5.0, 0.5,
+        ],
+        vec![
+            5.1e5, 1.1e3, -10.0, 1.0, 2.9e5, -1.9e3, -20.0, -2.0, -0.9e5, 4.0e2, 5.0, -0.5,
+        ],
+    ];
+    let earth = vec![vec![
+        4.0e3, 1.0e3, -50.0, 3.0e3, -2.0e2, 10.0, 1.0e3, 50.0, -5.0, // x, y, z
+        1.0e-3, -2.0e-4, 3.0e-5, 4.0e-4, 5.0e-5, -6.0e-6, -7.0e-5, 8.0e-6,
+        9.0e-7, // vx, vy, vz
+    ]];
+    vec![
+        cheb_segment(NAIF_EMB, NAIF_SSB, 2, -10.0 * DAY, 10.0 * DAY, &emb),
+        cheb_segment(NAIF_SUN, NAIF_SSB, 2, -10.0 * DAY, 10.0 * DAY, &sun),
+        cheb_segment(NAIF_EARTH, NAIF_EMB, 3, -10.0 * DAY, 20.0 * DAY, &earth),
+    ]
+}
+
+fn t_segments(status: &mut i32) {
+    let k = SpkKernel::from_bytes(build_daf(&kernel_segments(), false)).unwrap();
+    let s = k.segments();
+    viv(s.len() as i32, 3, "SpkKernel", "n", status);
+    viv(s[0].target, NAIF_EMB, "SpkKernel", "target", status);
+    viv(s[2].center, NAIF_EMB, "SpkKernel", "center", status);
+    viv(s[2].data_type, 3, "SpkKernel", "type", status);
+    viv(s[1].frame, 1, "SpkKernel", "frame", status);
+    vvd(
+        s[0].start_et,
+        -10.0 * DAY,
+        0.0,
+        "SpkKernel",
+        "start",
+        status,
+    );
+    vvd(s[0].end_et, 10.0 * DAY, 0.0, "SpkKernel", "end", status);
+
+    let mut bad = build_daf(&kernel_segments(), false);
+    bad[0..8].copy_from_slice(b"DAF/XYZ ");
+    viv(
+        (SpkKernel::from_bytes(bad).err() == Some(ErfaError::SpkFileSyntax)) as i32,
+        1,
+        "SpkKernel",
+        "idword",
+        status,
+    );
+    let mut short = build_daf(&kernel_segments(), false);
+    short.truncate(3500);
+    viv(
+        (SpkKernel::from_bytes(short).err() == Some(ErfaError::SpkFileSyntax)) as i32,
+        1,
+        "SpkKernel",
+        "truncated",
+        status,
+    );
+
+    // Directory claiming RSIZE = 1e300 (word 3 of the last 4 of segment 0).
+    let mut huge = build_daf(&kernel_segments(), false);
+    let o = 3072 + 8 * (kernel_segments()[0].words.len() - 2);
+    huge[o..o + 8].copy_from_slice(&1e300_f64.to_le_bytes());
+    viv(
+        (SpkKernel::from_bytes(huge).err() == Some(ErfaError::SpkFileSyntax)) as i32,
+        1,
+        "SpkKernel",
+        "rsize",
+        status,
+    );
+
+    // Segment 0 with start = 1, end = 2: too short for a directory.
+    let mut tiny = build_daf(&kernel_segments(), false);
+    let o = 1024 + 8 * (3 + 2);
+    tiny[o + 16..o + 20].copy_from_slice(&1_i32.to_le_bytes());
+    tiny[o + 20..o + 24].copy_from_slice(&2_i32.to_le_bytes());
+    viv(
+        (SpkKernel::from_bytes(tiny).err() == Some(ErfaError::SpkFileSyntax)) as i32,
+        1,
+        "SpkKernel",
+        "end",
+        status,
+    );
+    viv(
+        (SpkKernel::open("/nonexistent/de440.bsp").err() == Some(ErfaError::FileRead)) as i32,
+        1,
+        "SpkKernel",
+        "open",
+        status,
+    );
+}
+
+fn t_pv(status: &mut i32) {
+    let segs = kernel_segments();
+    let k = SpkKernel::from_bytes(build_daf(&segs, false)).unwrap();
+
+    // 3.25 days after J2000: second half of the first EMB record.
+    let et = 3.25 * DAY;
+    let (tdb1, tdb2) = (ERFA_DJ00, 3.25);
+    let t = (et - (-5.0 * DAY)) / (5.0 * DAY);
+    let c = &segs[0].words[2..14];
+    let pv = k.pv(NAIF_EMB, NAIF_SSB, tdb1, tdb2).unwrap();
+    for i in 0..3 {
+        let p = cheb(&c[4 * i..4 * i + 4], t) * 1e3 / ERFA_DAU;
+        vvd(pv[0][i], p, 1e-15, "SpkKernel::pv", "type 2 p", status);
+    }
+
+    // Type 2 velocity is the derivative of the position series.
+    let h = 1e-4;
+    let pm = k.pv(NAIF_EMB, NAIF_SSB, tdb1, tdb2 - h).unwrap();
+    let pp = k.pv(NAIF_EMB, NAIF_SSB, tdb1, tdb2 + h).unwrap();
+    for i in 0..3 {
+        vvd(
+            pv[1][i],
+            (pp[0][i] - pm[0][i]) / (2.0 * h),
+            1e-9,
+            "SpkKernel::pv",
+            "type 2 v",
+            status,
+        );
+    }
+
+    // Type 3 velocity comes from its own series, in km/s.
+    let te = (et - 0.0) / (10.0 * DAY);
+    let c = &segs[2].words[2..20];
+    let pe = k.pv(NAIF_EARTH, NAIF_EMB, tdb1, tdb2).unwrap();
+    for i in 0..3 {
+        let p = cheb(&c[3 * i..3 * i + 3], te) * 1e3 / ERFA_DAU;
+        let v = cheb(&c[9 + 3 * i..9 + 3 * i + 3], te) * 1e3 * ERFA_DAYSEC / ERFA_DAU;
+        vvd(pe[0][i], p, 1e-15, "SpkKernel::pv", "type 3 p", status);
+        vvd(pe[1][i], v, 1e-15, "SpkKernel::pv", "type 3 v", status);
+    }
+
+    // Chained through the barycentre, and reversed.
+    let es = k.pv(NAIF_EARTH, NAIF_SUN, tdb1, tdb2).unwrap();
+    let eb = k.pv(NAIF_EARTH, NAIF_SSB, tdb1, tdb2).unwrap();
+    let sb = k.pv(NAIF_SUN, NAIF_SSB, tdb1, tdb2).unwrap();
+    let se = k.pv(NAIF_SUN, NAIF_EARTH, tdb1, tdb2).unwrap();
+    for j in 0..2 {
+        for i in 0..3 {
+            vvd(
+                es[j][i],
+                eb[j][i] - sb[j][i],
+                1e-15,
+                "SpkKernel::pv",
+                "chain",
+                status,
+            );
+            vvd(se[j][i], -es[j][i], 0.0, "SpkKernel::pv", "reverse", status);
+        }
+    }
+    let (pvh, pvb) = k.epv(tdb1, tdb2).unwrap();
+    vvd(pvh[0][0], es[0][0], 0.0, "SpkKernel::epv", "pvh", status);
+    vvd(pvb[1][2], eb[1][2], 0.0, "SpkKernel::epv", "pvb", status);
+
+    // Big-endian file, read from disk, agrees exactly.
+    let path = std::env::temp_dir().join("erfa_spk_tests_safe.bsp");
+    std::fs::write(&path, build_daf(&segs, true)).unwrap();
+    let kb = SpkKernel::open(&path).unwrap();
+    let es2 = kb.pv(NAIF_EARTH, NAIF_SUN, tdb1, tdb2).unwrap();
+    let _ = std::fs::remove_file(&path);
+    for j in 0..2 {
+        for i in 0..3 {
+            vvd(
+                es2[j][i],
+                es[j][i],
+                0.0,
+                "SpkKernel::open",
+                "big-endian",
+                status,
+            );
+        }
+    }
+
+    // Out of coverage, and bodies with no segment.
+    viv(
+        (k.pv(NAIF_EARTH, NAIF_SUN, ERFA_DJ00, 10.5).err() == Some(ErfaError::SpkOutOfRange))
+            as i32,
+        1,
+        "SpkKernel::pv",
+        "date",
+        status,
+    );
+    viv(
+        (k.pv(NAIF_MOON, NAIF_EARTH, tdb1, tdb2).err() == Some(ErfaError::SpkOutOfRange)) as i32,
+        1,
+        "SpkKernel::pv",
+        "body",
+        status,
+    );
+
+    // A later segment overrides an earlier one where both cover the date.
+    let mut over = kernel_segments();
+    over.push(cheb_segment(
+        NAIF_SUN,
+        NAIF_SSB,
+        2,
+        0.0,
+        5.0 * DAY,
+        &[vec![7.0e5, 0.0, 0.0, 0.0, 0.0, 0.0]],
+    ));
+    let ko = SpkKernel::from_bytes(build_daf(&over, false)).unwrap();
+    let s = ko.pv(NAIF_SUN, NAIF_SSB, tdb1, tdb2).unwrap();
+    vvd(
+        s[0][0],
+        7.0e8 / ERFA_DAU,
+        1e-15,
+        "SpkKernel::pv",
+        "override",
+        status,
+    );
+    vvd(s[1][0], 0.0, 0.0, "SpkKernel::pv", "override v", status);
+}
+
+fn t_units(status: &mut i32) {
+    // A circular orbit at 1 au in the ecliptic-ish plane, as a type 3
+    // segment built from Epv00's own Earth, must reproduce it.
+    let (tdb1, tdb2) = (ERFA_DJ00, 100.0);
+    let (_, pvb, _) = eraEpv00_safe(tdb1, tdb2).unwrap();
+    let mut c = Vec::new();
+    for i in 0..3 {
+        c.push(pvb[0][i] * ERFA_DAU / 1e3);
+    }
+    for i in 0..3 {
+        c.push(pvb[1][i] * ERFA_DAU / 1e3 / ERFA_DAYSEC);
+    }
+    let seg = cheb_segment(NAIF_EARTH, NAIF_SSB, 3, 99.0 * DAY, 2.0 * DAY, &[c]);
+    let k = SpkKernel::from_bytes(build_daf(&[seg], false)).unwrap();
+    let pv = k.pv(NAIF_EARTH, NAIF_SSB, tdb1, tdb2).unwrap();
+    for j in 0..2 {
+        for i in 0..3 {
+            vvd(
+                pv[j][i],
+                pvb[j][i],
+                1e-14,
+                "SpkKernel::pv",
+                "Epv00 units",
+                status,
+            );
+        }
+    }
+}
+
+// Epv00 with the barycentre moved by a fixed offset (au).
+struct Shifted(f64);
+
+impl Ephemeris for Shifted {
+    fn earth_pv(&self, date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2])> {
+        let (pvh, mut pvb) = Epv00.earth_pv(date1, date2)?;
+        pvb[0][0] += self.0;
+        Ok((pvh, pvb))
+    }
+}
+
+// eraASTROM has no PartialEq; Debug shows every field exactly.
+fn same(a: &eraASTROM, b: &eraASTROM) -> bool {
+    format!("{:?}", a) == format!("{:?}", b)
+}
+
+fn t_ephemeris(status: &mut i32) {
+    let (date1, date2) = (2456165.5, 0.401182685);
+    let pv = [
+        [-6241497.16, 401346.896, -1251136.04],
+        [-29.264597, -455.021831, 0.0266151194],
+    ];
+
+    // Epv00 through the trait matches the plain routines exactly.
+    let mut a = eraASTROM::default();
+    let mut b = eraASTROM::default();
+    eraApcg13_safe(date1, date2, &mut a).unwrap();
+    eraApcg13Eph_safe(&Epv00, date1, date2, &mut b).unwrap();
+    viv(same(&a, &b) as i32, 1, "eraApcg13Eph_safe", "Epv00", status);
+    let eo = eraApci13_safe(date1, date2, &mut a).unwrap();
+    let eo2 = eraApci13Eph_safe(&Epv00, date1, date2, &mut b).unwrap();
+    viv(same(&a, &b) as i32, 1, "eraApci13Eph_safe", "Epv00", status);
+    vvd(eo2, eo, 0.0, "eraApci13Eph_safe", "eo", status);
+    eraApcs13_safe(date1, date2, &pv, &mut a).unwrap();
+    eraApcs13Eph_safe(&Epv00, date1, date2, &pv, &mut b).unwrap();
+    viv(same(&a, &b) as i32, 1, "eraApcs13Eph_safe", "Epv00", status);
+    let args = (
+        2456384.5,
+        0.969254051,
+        0.1550675,
+        -0.527800806,
+        -1.2345856,
+        2738.0,
+        2.47230737e-7,
+        1.82640464e-6,
+        731.0,
+        12.8,
+        0.59,
+        0.55,
+    );
+    let (eo, _) = eraApco13_safe(
+        args.0, args.1, args.2, args.3, args.4, args.5, args.6, args.7, args.8, args.9, args.10,
+        args.11, &mut a,
+    )
+    .unwrap();
+    let (eo2, _) = eraApco13Eph_safe(
+        &Epv00, args.0, args.1, args.2, args.3, args.4, args.5, args.6, args.7, args.8, args.9,
+        args.10, args.11, &mut b,
+    )
+    .unwrap();
+    viv(same(&a, &b) as i32, 1, "eraApco13Eph_safe", "Epv00", status);
+    vvd(eo2, eo, 0.0, "eraApco13Eph_safe", "eo", status);
+
+    // A user ephemeris is what ends up in astrom.
+    eraApcg13_safe(date1, date2, &mut a).unwrap();
+    eraApcg13Eph_safe(&Shifted(1e-6), date1, date2, &mut b).unwrap();
+    vvd(
+        b.eb[0] - a.eb[0],
+        1e-6,
+        1e-15,
+        "eraApcg13Eph_safe",
+        "custom",
+        status,
+    );
+    vvd(
+        b.eb[1],
+        a.eb[1],
+        0.0,
+        "eraApcg13Eph_safe",
+        "custom y",
+        status,
+    );
+
+    // An SPK kernel holding Epv00's Earth and Sun reproduces eraApcs13,
+    // also behind a trait object.
+    let (pvh, pvb, _) = eraEpv00_safe(date1, date2).unwrap();
+    let km = |pv: [[f64; 3]; 2]| {
+        let mut c = Vec::new();
+        for i in 0..3 {
+            c.push(pv[0][i] * ERFA_DAU / 1e3);
+        }
+        for i in 0..3 {
+            c.push(pv[1][i] * ERFA_DAU / 1e3 / ERFA_DAYSEC);
+        }
+        c
+    };
+    let mut sun = [[0.0; 3]; 2];
+    for j in 0..2 {
+        for i in 0..3 {
+            sun[j][i] = pvb[j][i] - pvh[j][i];
+        }
+    }
+    let et = ((date1 - ERFA_DJ00) + date2) * DAY;
+    let k = SpkKernel::from_bytes(build_daf(
+        &[
+            cheb_segment(NAIF_EARTH, NAIF_SSB, 3, et - DAY, 2.0 * DAY, &[km(pvb)]),
+            cheb_segment(NAIF_SUN, NAIF_SSB, 3, et - DAY, 2.0 * DAY, &[km(sun)]),
+        ],
+        false,
+    ))
+    .unwrap();
+    let eph: &dyn Ephemeris = &k;
+    eraApcs13_safe(date1, date2, &pv, &mut a).unwrap();
+    eraApcs13Eph_safe(eph, date1, date2, &pv, &mut b).unwrap();
+    for i in 0..3 {
+        vvd(
+            b.eb[i],
+            a.eb[i],
+            1e-14,
+            "eraApcs13Eph_safe",
+            "SPK eb",
+            status,
+        );
+        vvd(
+            b.eh[i],
+            a.eh[i],
+            1e-14,
+            "eraApcs13Eph_safe",
+            "SPK eh",
+            status,
+        );
+        vvd(b.v[i], a.v[i], 1e-16, "eraApcs13Eph_safe", "SPK v", status);
+    }
+    vvd(b.em, a.em, 1e-14, "eraApcs13Eph_safe", "SPK em", status);
+}
+
+fn t_bodies(status: &mut i32) {
+    // Earth, Sun and Mars from Epv00/eraPlan94 as straight-line type 2
+    // segments about the date; places agree with the built-in series.
+    let (utc1, utc2) = (2456384.5, 0.969254051);
+    let site = (
+        0.1550675,
+        -0.527800806,
+        -1.2345856,
+        2738.0,
+        2.47230737e-7,
+        1.82640464e-6,
+        731.0,
+        12.8,
+        0.59,
+        0.55,
+    );
+    let (tdb1, tdb2) = (utc1, utc2 + 67.184 / ERFA_DAYSEC);
+    let et = ((tdb1 - ERFA_DJ00) + tdb2) * DAY;
+    let line = |pv: [[f64; 3]; 2]| {
+        let mut c = Vec::new();
+        for i in 0..3 {
+            c.push(pv[0][i] * ERFA_DAU / 1e3);
+            c.push(pv[1][i] * ERFA_DAU / 1e3 / ERFA_DAYSEC * DAY);
+        }
+        c
+    };
+    let mut segs = Vec::new();
+    for body in [Body::Sun, Body::Mars] {
+        let pv = Epv00.body_pv(body, tdb1, tdb2).unwrap();
+        segs.push(cheb_segment(
+            body.naif_id(),
+            NAIF_SSB,
+            2,
+            et - DAY,
+            2.0 * DAY,
+            &[line(pv)],
+        ));
+    }
+    let (_, pvb) = Epv00.earth_pv(tdb1, tdb2).unwrap();
+    segs.push(cheb_segment(
+        NAIF_EARTH,
+        NAIF_SSB,
+        2,
+        et - DAY,
+        2.0 * DAY,
+        &[line(pvb)],
+    ));
+    let k = SpkKernel::from_bytes(build_daf(&segs, false)).unwrap();
+
+    for body in [Body::Sun, Body::Mars] {
+        let args = |eph: &dyn BodyEphemeris| {
+            place_with(
+                eph, body, utc1, utc2, site.0, site.1, site.2, site.3, site.4, site.5, site.6,
+                site.7, site.8, site.9,
+            )
+            .unwrap()
+            .0
+        };
+        let a = args(&Epv00);
+        let b = args(&k);
+        vvd(b.az, a.az, 1e-8, "place_with", "SPK az", status);
+        vvd(b.el, a.el, 1e-8, "place_with", "SPK el", status);
+        // Straight lines miss ~1e-8 au of Mars's curvature over the light time.
+        vvd(
+            b.distance,
+            a.distance,
+            5e-8,
+            "place_with",
+            "SPK distance",
+            status,
+        );
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_segments(&mut status);
+    t_pv(&mut status);
+    t_units(&mut status);
+    t_ephemeris(&mut status);
+    t_bodies(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
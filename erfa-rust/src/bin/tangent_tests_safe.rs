@@ -0,0 +1,254 @@
+#![allow(dead_code)]
+
+use erfa_rust::tangent::*;
+use erfa_rust::G11_safe::Ellipsoid;
+use erfa_rust::G18_safe::eraHd2ae_safe;
+use erfa_rust::H1_safe::{ErfaError, ERFA_DD2R, ERFA_DPI};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+fn vpv(
+    pv: &[[f64; 3]; 2],
+    want: &[[f64; 3]; 2],
+    dp: f64,
+    dv: f64,
+    func: &str,
+    test: &str,
+    status: &mut i32,
+) {
+    for i in 0..3 {
+        vvd(pv[0][i], want[0][i], dp, func, test, status);
+        vvd(pv[1][i], want[1][i], dv, func, test, status);
+    }
+}
+
+fn t_enu(status: &mut i32) {
+    // On the equator at the prime meridian, east is +Y and up is +X.
+    let s = Station::new(0.0, 0.0, 0.0);
+    let a = Ellipsoid::WGS84.a;
+    let pv = [[a + 1000.0, 200.0, -300.0], [1.0, 2.0, 3.0]];
+    let enu = itrs_to_enu(&s, &pv).unwrap();
+    vpv(
+        &enu,
+        &[[200.0, -300.0, 1000.0], [2.0, 3.0, 1.0]],
+        1e-9,
+        1e-15,
+        "itrs_to_enu",
+        "equator",
+        status,
+    );
+    let ned = itrs_to_ned(&s, &pv).unwrap();
+    vpv(
+        &ned,
+        &[[-300.0, 200.0, -1000.0], [3.0, 2.0, -1.0]],
+        1e-9,
+        1e-15,
+        "itrs_to_ned",
+        "equator",
+        status,
+    );
+
+    // At the north pole, north is −X and up is +Z.
+    let s = Station::new(0.0, ERFA_DPI / 2.0, 10.0);
+    let b = Ellipsoid::WGS84.b();
+    let enu = itrs_to_enu(&s, &[[-50.0, 0.0, b + 110.0], [0.0; 3]]).unwrap();
+    vpv(
+        &enu,
+        &[[0.0, 50.0, 100.0], [0.0; 3]],
+        1e-9,
+        0.0,
+        "itrs_to_enu",
+        "pole",
+        status,
+    );
+
+    // Round trips on another ellipsoid.
+    let s = Station::on(Ellipsoid::AIRY1830, -0.02, 0.9, 150.0);
+    let pv = [[4.0e6, -1.0e5, 4.9e6], [120.0, -35.0, 7.0]];
+    let back = enu_to_itrs(&s, &itrs_to_enu(&s, &pv).unwrap()).unwrap();
+    vpv(&back, &pv, 1e-8, 1e-12, "enu_to_itrs", "round trip", status);
+    let back = ned_to_itrs(&s, &itrs_to_ned(&s, &pv).unwrap()).unwrap();
+    vpv(&back, &pv, 1e-8, 1e-12, "ned_to_itrs", "round trip", status);
+}
+
+fn t_aer(status: &mut i32) {
+    let s = Station::new(-1.2, 0.6, 2000.0);
+
+    // A distant target in the direction of an hour angle and declination
+    // has eraHd2ae's azimuth and elevation.
+    let (ha, dec): (f64, f64) = (0.7, 0.2);
+    let o = s.position().unwrap();
+    let d = 1e15;
+    let l = s.elong - ha;
+    let p = [
+        o[0] + d * dec.cos() * l.cos(),
+        o[1] + d * dec.cos() * l.sin(),
+        o[2] + d * dec.sin(),
+    ];
+    let aer = itrs_to_aer(&s, &[p, [0.0; 3]]).unwrap();
+    let (az, el) = eraHd2ae_safe(ha, dec, s.phi).unwrap();
+    vvd(aer.az, az, 1e-12, "itrs_to_aer", "az", status);
+    vvd(aer.el, el, 1e-12, "itrs_to_aer", "el", status);
+    vvd(aer.range, d, 1.0, "itrs_to_aer", "range", status);
+
+    // Rates against differences along a straight track.
+    let pv = [
+        [o[0] + 3e4, o[1] - 2e4, o[2] + 1.5e4],
+        [-150.0, 220.0, 40.0],
+    ];
+    let at = |t: f64| {
+        let p = [
+            pv[0][0] + pv[1][0] * t,
+            pv[0][1] + pv[1][1] * t,
+            pv[0][2] + pv[1][2] * t,
+        ];
+        itrs_to_aer(&s, &[p, pv[1]]).unwrap()
+    };
+    let (a0, am, ap) = (at(0.0), at(-0.01), at(0.01));
+    vvd(
+        a0.az_rate,
+        (ap.az - am.az) / 0.02,
+        1e-10,
+        "itrs_to_aer",
+        "az rate",
+        status,
+    );
+    vvd(
+        a0.el_rate,
+        (ap.el - am.el) / 0.02,
+        1e-10,
+        "itrs_to_aer",
+        "el rate",
+        status,
+    );
+    vvd(
+        a0.range_rate,
+        (ap.range - am.range) / 0.02,
+        1e-6,
+        "itrs_to_aer",
+        "range rate",
+        status,
+    );
+
+    // And back.
+    let back = aer_to_itrs(&s, &a0).unwrap();
+    vpv(&back, &pv, 1e-8, 1e-10, "aer_to_itrs", "round trip", status);
+
+    // Straight up and receding.
+    let up = [[0.0, 0.0, 500.0], [3.0, 0.0, 10.0]];
+    let aer = enu_to_aer(&up).unwrap();
+    vvd(
+        aer.el,
+        ERFA_DPI / 2.0,
+        1e-15,
+        "enu_to_aer",
+        "zenith el",
+        status,
+    );
+    vvd(aer.az, 0.0, 0.0, "enu_to_aer", "zenith az", status);
+    vvd(
+        aer.az_rate,
+        0.0,
+        0.0,
+        "enu_to_aer",
+        "zenith az rate",
+        status,
+    );
+    vvd(
+        aer.range_rate,
+        10.0,
+        1e-12,
+        "enu_to_aer",
+        "zenith rate",
+        status,
+    );
+    let aer = enu_to_aer(&[[0.0, -1.0, 0.0], [0.0; 3]]).unwrap();
+    vvd(
+        aer.az,
+        180.0 * ERFA_DD2R,
+        1e-15,
+        "enu_to_aer",
+        "south",
+        status,
+    );
+    viv(
+        (enu_to_aer(&[[0.0; 3], [1.0; 3]]) == Err(ErfaError::NullPosition)) as i32,
+        1,
+        "enu_to_aer",
+        "null",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_enu(&mut status);
+    t_aer(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
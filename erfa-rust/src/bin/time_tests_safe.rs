@@ -0,0 +1,1186 @@
+#![allow(dead_code)]
+
+use erfa_rust::time::convert::*;
+use erfa_rust::time::delta_t::*;
+use erfa_rust::time::gnss::*;
+use erfa_rust::time::iso::*;
+use erfa_rust::time::leap::LeapSecondFile;
+use erfa_rust::time::unix::*;
+use erfa_rust::time::*;
+use erfa_rust::G10_safe::{eraDtdb_safe, eraDtf2dTable_safe};
+use erfa_rust::G14_safe::{eraSetLeapSeconds_safe, LeapSecondTable};
+use erfa_rust::G32_safe::{eraTaitt_safe, eraTaiutcTable_safe};
+use erfa_rust::G33_safe::{eraTttdb_safe, eraTtut1_safe, eraUtctaiTable_safe, eraUtctai_safe};
+use erfa_rust::G9_safe::{eraD2dtfTable_safe, eraDatTable_safe, eraDat_safe};
+use erfa_rust::H1_safe::{DatStatus, ErfaError};
+use libc::{c_char, snprintf};
+use std::ffi::CString;
+
+static mut VERBOSE: bool = false;
+
+fn format_g(val: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; 512];
+    let format_str = format!("%.{}g", precision);
+    let c_format_str = CString::new(format_str).unwrap();
+    unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            c_format_str.as_ptr(),
+            val,
+        );
+    }
+    String::from_utf8_lossy(&buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn viv(ival: i32, ivalok: i32, func: &str, test: &str, status: &mut i32) {
+    if ival != ivalok {
+        *status = 1;
+        println!("{} failed: {} want {} got {}", func, test, ivalok, ival);
+    } else if unsafe { VERBOSE } {
+        println!("{} passed: {} want {} got {}", func, test, ivalok, ival);
+    }
+}
+
+fn vvd(val: f64, valok: f64, dval: f64, func: &str, test: &str, status: &mut i32) {
+    let a = val - valok;
+    if a != 0.0 && a.abs() > dval.abs() {
+        let f = (valok / a).abs();
+        *status = 1;
+        println!(
+            "{} failed: {} want {} got {} (1/{})",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20),
+            format_g(f, 3)
+        );
+    } else if unsafe { VERBOSE } {
+        println!(
+            "{} passed: {} want {} got {}",
+            func,
+            test,
+            format_g(valok, 20),
+            format_g(val, 20)
+        );
+    }
+}
+
+fn t_julian_date(status: &mut i32) {
+    let jd = JulianDate::from_calendar(2003, 6, 1).unwrap();
+    vvd(jd.jd1, 2400000.5, 0.0, "JulianDate", "cal jd1", status);
+    vvd(jd.jd2, 52791.0, 0.0, "JulianDate", "cal jd2", status);
+    vvd(jd.mjd(), 52791.0, 0.0, "JulianDate", "mjd", status);
+
+    let ((iy, im, id), fd) = JulianDate::new(2400000.5, 50123.9999)
+        .to_calendar()
+        .unwrap();
+    viv(iy, 1996, "JulianDate", "y", status);
+    viv(im, 2, "JulianDate", "m", status);
+    viv(id, 10, "JulianDate", "d", status);
+    vvd(fd, 0.9999, 1e-7, "JulianDate", "fd", status);
+
+    let a = JulianDate::new(2453750.5, 0.892482639).add_days(1e-9);
+    vvd(a.jd1, 2453750.5, 0.0, "JulianDate", "add jd1", status);
+    vvd(a.jd2, 0.892482640, 1e-15, "JulianDate", "add jd2", status);
+    vvd(
+        a.days_since(JulianDate::new(2453750.5, 0.892482639)),
+        1e-9,
+        1e-15,
+        "JulianDate",
+        "days_since",
+        status,
+    );
+}
+
+fn t_tai(status: &mut i32) {
+    let tai = Tai::new(2453750.5, 0.892482639);
+
+    let tt = tai.to_tt().unwrap();
+    vvd(tt.jd1(), 2453750.5, 1e-6, "Tai::to_tt", "t1", status);
+    vvd(tt.jd2(), 0.892855139, 1e-12, "Tai::to_tt", "t2", status);
+
+    let ut1 = tai.to_ut1(-32.6659).unwrap();
+    vvd(ut1.jd1(), 2453750.5, 1e-6, "Tai::to_ut1", "u1", status);
+    vvd(
+        ut1.jd2(),
+        0.8921045614537037037,
+        1e-12,
+        "Tai::to_ut1",
+        "u2",
+        status,
+    );
+
+    let (utc, js) = tai.to_utc().unwrap();
+    vvd(utc.jd1(), 2453750.5, 1e-6, "Tai::to_utc", "u1", status);
+    vvd(
+        utc.jd2(),
+        0.8921006945555555556,
+        1e-12,
+        "Tai::to_utc",
+        "u2",
+        status,
+    );
+    viv(js.code(), 0, "Tai::to_utc", "j", status);
+}
+
+fn t_tt(status: &mut i32) {
+    let tt = Tt::new(2453750.5, 0.892482639);
+
+    let tai = tt.to_tai().unwrap();
+    vvd(tai.jd2(), 0.892110139, 1e-12, "Tt::to_tai", "a2", status);
+
+    let tcg = tt.to_tcg().unwrap();
+    vvd(
+        tcg.jd2(),
+        0.8924900312508587113,
+        1e-12,
+        "Tt::to_tcg",
+        "g2",
+        status,
+    );
+
+    let tt = Tt::new(2453750.5, 0.892855139);
+    let tdb = tt.to_tdb(-0.000201).unwrap();
+    vvd(
+        tdb.jd2(),
+        0.8928551366736111111,
+        1e-12,
+        "Tt::to_tdb",
+        "b2",
+        status,
+    );
+
+    let ut1 = tt.to_ut1(64.8499).unwrap();
+    vvd(
+        ut1.jd2(),
+        0.8921045614537037037,
+        1e-12,
+        "Tt::to_ut1",
+        "u2",
+        status,
+    );
+}
+
+fn t_utc(status: &mut i32) {
+    let utc = Utc::new(2453750.5, 0.892100694);
+
+    let (tai, js) = utc.to_tai().unwrap();
+    vvd(
+        tai.jd2(),
+        0.8924826384444444444,
+        1e-12,
+        "Utc::to_tai",
+        "u2",
+        status,
+    );
+    viv(js.code(), 0, "Utc::to_tai", "j", status);
+
+    let (ut1, js) = utc.to_ut1(0.3341).unwrap();
+    vvd(
+        ut1.jd2(),
+        0.8921045608981481481,
+        1e-12,
+        "Utc::to_ut1",
+        "u2",
+        status,
+    );
+    viv(js.code(), 0, "Utc::to_ut1", "j", status);
+
+    let (utc, js) = Utc::from_calendar(1994, 6, 30, 23, 59, 60.13599).unwrap();
+    vvd(
+        utc.jd().jd(),
+        2449534.49999,
+        1e-6,
+        "Utc::from_calendar",
+        "u",
+        status,
+    );
+    viv(js.code(), 0, "Utc::from_calendar", "j", status);
+
+    let (((iy, im, id), ihmsf), js) = Utc::new(2400000.5, 49533.99999).to_calendar(5).unwrap();
+    viv(iy, 1994, "Utc::to_calendar", "y", status);
+    viv(im, 6, "Utc::to_calendar", "mo", status);
+    viv(id, 30, "Utc::to_calendar", "d", status);
+    viv(ihmsf[0], 23, "Utc::to_calendar", "h", status);
+    viv(ihmsf[1], 59, "Utc::to_calendar", "m", status);
+    viv(ihmsf[2], 60, "Utc::to_calendar", "s", status);
+    viv(ihmsf[3], 13599, "Utc::to_calendar", "f", status);
+    viv(js.code(), 0, "Utc::to_calendar", "j", status);
+}
+
+fn t_ut1(status: &mut i32) {
+    let ut1 = Ut1::new(2453750.5, 0.892104561);
+
+    let tai = ut1.to_tai(-32.6659).unwrap();
+    vvd(
+        tai.jd2(),
+        0.8924826385462962963,
+        1e-12,
+        "Ut1::to_tai",
+        "a2",
+        status,
+    );
+
+    let tt = ut1.to_tt(64.8499).unwrap();
+    vvd(
+        tt.jd2(),
+        0.8928551385462962963,
+        1e-12,
+        "Ut1::to_tt",
+        "t2",
+        status,
+    );
+
+    let (utc, js) = ut1.to_utc(0.3341).unwrap();
+    vvd(
+        utc.jd2(),
+        0.8921006941018518519,
+        1e-12,
+        "Ut1::to_utc",
+        "u2",
+        status,
+    );
+    viv(js.code(), 0, "Ut1::to_utc", "j", status);
+}
+
+fn t_relativistic(status: &mut i32) {
+    let tcb = Tcb::new(2453750.5, 0.893019599).to_tdb().unwrap();
+    vvd(
+        tcb.jd2(),
+        0.8928551362746343397,
+        1e-12,
+        "Tcb::to_tdb",
+        "b2",
+        status,
+    );
+
+    let tcb = Tdb::new(2453750.5, 0.892855137).to_tcb().unwrap();
+    vvd(
+        tcb.jd2(),
+        0.8930195997253656716,
+        1e-12,
+        "Tdb::to_tcb",
+        "b2",
+        status,
+    );
+
+    let tt = Tdb::new(2453750.5, 0.892855137).to_tt(-0.000201).unwrap();
+    vvd(
+        tt.jd2(),
+        0.8928551393263888889,
+        1e-12,
+        "Tdb::to_tt",
+        "t2",
+        status,
+    );
+
+    let tt = Tcg::new(2453750.5, 0.892862531).to_tt().unwrap();
+    vvd(
+        tt.jd2(),
+        0.8928551387488816828,
+        1e-12,
+        "Tcg::to_tt",
+        "t2",
+        status,
+    );
+}
+
+fn t_round_trip(status: &mut i32) {
+    // Large jd1 with a tiny jd2 offset: the split must survive the round trip.
+    let tai = Tai::new(2453750.5, 1e-10);
+    let back = tai.to_tt().unwrap().to_tai().unwrap();
+    vvd(back.jd1(), 2453750.5, 0.0, "round trip", "jd1", status);
+    vvd(back.jd2(), 1e-10, 1e-18, "round trip", "jd2", status);
+    vvd(back - tai, 0.0, 1e-18, "round trip", "sub", status);
+}
+
+fn t_route(status: &mut i32) {
+    let path = route(TimeScale::Utc, TimeScale::Tcb);
+    viv(path.len() as i32, 5, "route", "UTC->TCB len", status);
+    viv(
+        (path
+            == [
+                TimeScale::Utc,
+                TimeScale::Tai,
+                TimeScale::Tt,
+                TimeScale::Tdb,
+                TimeScale::Tcb,
+            ]) as i32,
+        1,
+        "route",
+        "UTC->TCB",
+        status,
+    );
+    viv(
+        route(TimeScale::Tt, TimeScale::Tt).len() as i32,
+        1,
+        "route",
+        "TT->TT",
+        status,
+    );
+
+    // Every pair is reachable.
+    for &a in TimeScale::ALL.iter() {
+        for &b in TimeScale::ALL.iter() {
+            let path = route(a, b);
+            let ends = path.first() == Some(&a) && path.last() == Some(&b);
+            viv(ends as i32, 1, "route", a.label(), status);
+        }
+    }
+}
+
+fn t_convert(status: &mut i32) {
+    // UTC -> TDB by hand.
+    let ((a1, a2), _) = eraUtctai_safe(2453750.5, 0.892100694).unwrap();
+    let (t1, t2) = eraTaitt_safe(a1, a2).unwrap();
+    let dtr = eraDtdb_safe(t1, t2, 0.892482639, 0.0, 0.0, 0.0).unwrap();
+    let (b1, b2) = eraTttdb_safe(t1, t2, dtr).unwrap();
+
+    let ctx = TimeContext::default();
+    let (tdb, js) = convert(
+        JulianDate::new(2453750.5, 0.892100694),
+        TimeScale::Utc,
+        TimeScale::Tdb,
+        &ctx,
+    )
+    .unwrap();
+    vvd(tdb.jd1, b1, 0.0, "convert", "UTC->TDB jd1", status);
+    vvd(tdb.jd2, b2, 1e-15, "convert", "UTC->TDB jd2", status);
+    viv(js.code(), 0, "convert", "UTC->TDB j", status);
+
+    // Explicit TDB-TT overrides eraDtdb.
+    let ctx = TimeContext::default().with_dtr(-0.000201);
+    let (tdb, _) = Tt::new(2453750.5, 0.892855139).to::<Tdb>(&ctx).unwrap();
+    vvd(
+        tdb.jd2(),
+        0.8928551366736111111,
+        1e-12,
+        "to",
+        "TT->TDB",
+        status,
+    );
+
+    // UT1 needs DUT1.
+    let ctx = TimeContext::default();
+    let r = Tai::new(2453750.5, 0.892482639).to::<Ut1>(&ctx);
+    viv(
+        (r.err() == Some(ErfaError::MissingDut1)) as i32,
+        1,
+        "to",
+        "TAI->UT1 no dut1",
+        status,
+    );
+
+    let ctx = TimeContext::default().with_dut1(0.3341);
+    let (ut1, _) = Utc::new(2453750.5, 0.892100694).to::<Ut1>(&ctx).unwrap();
+    vvd(
+        ut1.jd2(),
+        0.8921045608981481481,
+        1e-12,
+        "to",
+        "UTC->UT1",
+        status,
+    );
+
+    // Round trip TCG -> UT1 -> TCG through six steps.
+    let site = ObserverLocation::from_geodetic(-0.5, 0.6, 2000.0).unwrap();
+    let ctx = TimeContext::default().with_dut1(0.3341).with_location(site);
+    let tcb = Tcb::new(2453750.5, 0.893019599);
+    let (ut1, _) = tcb.to::<Ut1>(&ctx).unwrap();
+    let (back, _) = ut1.to::<Tcb>(&ctx).unwrap();
+    vvd(back - tcb, 0.0, 1e-14, "to", "TCB round trip", status);
+}
+
+const LEAP_SECONDS_LIST: &str = "\
+#	Updated through IERS Bulletin C
+#$	3960835200
+#	File expires on 28 June 2026
+#@	3991593600
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+3692217600	37	# 1 Jan 2017
+#h	df2d95f2 65ffd943 fb1f34a8 d38fa006 16446bf9
+";
+
+const LEAP_SECOND_DAT: &str = "\
+#  File expires on 28 December 2025
+#
+#    MJD        Date        TAI-UTC (s)
+#           day month year
+#    ---    --------------   ------
+#
+    41317.0    1  1 1972       10
+    41499.0    1  7 1972       11
+    57204.0    1  7 2015       36
+";
+
+fn t_leap_files(status: &mut i32) {
+    let f = LeapSecondFile::parse_leap_seconds_list(LEAP_SECONDS_LIST).unwrap();
+    viv(f.table.len() as i32, 17, "leap-seconds.list", "n", status);
+    viv(f.table[14].iyear, 1972, "leap-seconds.list", "y1", status);
+    viv(f.table[14].month, 1, "leap-seconds.list", "m1", status);
+    vvd(
+        f.table[14].delat,
+        10.0,
+        0.0,
+        "leap-seconds.list",
+        "d1",
+        status,
+    );
+    viv(f.table[16].iyear, 2017, "leap-seconds.list", "y3", status);
+    vvd(
+        f.table[16].delat,
+        37.0,
+        0.0,
+        "leap-seconds.list",
+        "d3",
+        status,
+    );
+    viv(
+        f.checksum_verified as i32,
+        1,
+        "leap-seconds.list",
+        "sha1",
+        status,
+    );
+    let ((iy, im, id), fd) = f.expires.jd().to_calendar().unwrap();
+    viv(iy, 2026, "leap-seconds.list", "expiry y", status);
+    viv(im, 6, "leap-seconds.list", "expiry m", status);
+    viv(id, 28, "leap-seconds.list", "expiry d", status);
+    vvd(fd, 0.0, 0.0, "leap-seconds.list", "expiry fd", status);
+    viv(
+        f.is_expired(Utc::new(2400000.5, 61219.0)) as i32,
+        1,
+        "leap-seconds.list",
+        "expired",
+        status,
+    );
+    viv(
+        f.is_expired(Utc::new(2400000.5, 61218.5)) as i32,
+        0,
+        "leap-seconds.list",
+        "not expired",
+        status,
+    );
+
+    let tampered = LEAP_SECONDS_LIST.replace("3692217600\t37", "3692217600\t38");
+    let r = LeapSecondFile::parse_leap_seconds_list(&tampered);
+    viv(
+        (r.err() == Some(ErfaError::LeapFileChecksum)) as i32,
+        1,
+        "leap-seconds.list",
+        "tampered",
+        status,
+    );
+
+    let f = LeapSecondFile::parse_leap_second_dat(LEAP_SECOND_DAT).unwrap();
+    viv(f.table.len() as i32, 17, "Leap_Second.dat", "n", status);
+    viv(f.table[16].iyear, 2015, "Leap_Second.dat", "y3", status);
+    viv(f.table[16].month, 7, "Leap_Second.dat", "m3", status);
+    vvd(
+        f.table[16].delat,
+        36.0,
+        0.0,
+        "Leap_Second.dat",
+        "d3",
+        status,
+    );
+    vvd(
+        f.expires.jd().mjd(),
+        61037.0,
+        0.0,
+        "Leap_Second.dat",
+        "expiry",
+        status,
+    );
+
+    // Installed table drives eraDat; the pre-1972 drift eras still apply.
+    f.install().unwrap();
+    let (dat, _) = eraDat_safe(2017, 9, 1, 0.0).unwrap();
+    vvd(dat, 36.0, 0.0, "install", "2017", status);
+    let (dat, _) = eraDat_safe(1965, 2, 1, 0.9).unwrap();
+    vvd(dat, 3.5814724, 1e-12, "install", "1965", status);
+    let (_, js) = eraDat_safe(2026, 1, 1, 0.0).unwrap();
+    viv(
+        (js == DatStatus::PastExpiry) as i32,
+        1,
+        "install",
+        "expiry",
+        status,
+    );
+    eraSetLeapSeconds_safe(&[]).unwrap();
+    let (dat, _) = eraDat_safe(2017, 9, 1, 0.0).unwrap();
+    vvd(dat, 37.0, 0.0, "install", "reset", status);
+    let (_, js) = eraDat_safe(2026, 1, 1, 0.0).unwrap();
+    viv(js.code(), 0, "install", "reset expiry", status);
+
+    // The system tzdata copy, when present, must pass its own checksum.
+    let path = "/usr/share/zoneinfo/leap-seconds.list";
+    if std::path::Path::new(path).exists() {
+        let f = LeapSecondFile::load_leap_seconds_list(path).unwrap();
+        viv(f.checksum_verified as i32, 1, "tzdata", "sha1", status);
+        vvd(
+            f.table.last().unwrap().delat,
+            37.0,
+            0.0,
+            "tzdata",
+            "last",
+            status,
+        );
+    }
+}
+
+fn t_leap_table(status: &mut i32) {
+    // A table that stops before the 2016-12-31 leap second, expiring 2017-01-01.
+    let builtin = LeapSecondTable::builtin();
+    let n = builtin.entries().len();
+    let old = LeapSecondTable::new(builtin.entries()[..n - 1].to_vec(), 57_754.0);
+
+    let (dat, _) = eraDatTable_safe(&old, 2017, 9, 1, 0.0).unwrap();
+    vvd(dat, 36.0, 0.0, "eraDatTable", "old", status);
+    let (dat, _) = eraDatTable_safe(&builtin, 2017, 9, 1, 0.0).unwrap();
+    vvd(dat, 37.0, 0.0, "eraDatTable", "builtin", status);
+    let (dat, _) = eraDat_safe(2017, 9, 1, 0.0).unwrap();
+    vvd(dat, 37.0, 0.0, "eraDatTable", "global", status);
+
+    let ((tai1, tai2), _) = eraUtctaiTable_safe(&old, 2457754.5, 0.75).unwrap();
+    vvd(tai1, 2457754.5, 0.0, "eraUtctaiTable", "tai1", status);
+    vvd(
+        tai2,
+        0.75 + 36.0 / 86400.0,
+        1e-12,
+        "eraUtctaiTable",
+        "tai2",
+        status,
+    );
+    let ((utc1, utc2), _) = eraTaiutcTable_safe(&old, tai1, tai2).unwrap();
+    vvd(utc1, 2457754.5, 0.0, "eraTaiutcTable", "utc1", status);
+    vvd(utc2, 0.75, 1e-12, "eraTaiutcTable", "utc2", status);
+
+    // 23:59:60 is inside the day only when the table has the leap second.
+    let ((d1, d2), js) = eraDtf2dTable_safe(&builtin, "UTC", 2016, 12, 31, 23, 59, 60.5).unwrap();
+    viv(js.code(), 0, "eraDtf2dTable", "builtin j", status);
+    let (ymd, ihmsf, _) = eraD2dtfTable_safe(&builtin, "UTC", 1, d1, d2).unwrap();
+    viv(ymd.2, 31, "eraD2dtfTable", "builtin d", status);
+    viv(ihmsf[2], 60, "eraD2dtfTable", "builtin s", status);
+    let (_, js) = eraDtf2dTable_safe(&old, "UTC", 2016, 12, 31, 23, 59, 60.5).unwrap();
+    // Beyond the end of the day, and the next day is past the old expiry.
+    viv(js.code(), 3, "eraDtf2dTable", "old j", status);
+    let (ymd, ihmsf, _) = eraD2dtfTable_safe(&old, "UTC", 1, d1, d2).unwrap();
+    viv(ymd.2, 31, "eraD2dtfTable", "old d", status);
+    viv(ihmsf[2], 59, "eraD2dtfTable", "old s", status);
+
+    let (tai, _) = Utc::new(2457754.5, 0.75).to_tai_in(&old).unwrap();
+    let (utc, _) = tai.to_utc_in(&old).unwrap();
+    vvd(utc.jd2(), 0.75, 1e-12, "Utc::to_tai_in", "", status);
+
+    // Past the expiry ΔAT is still the last value, flagged as unknown.
+    let (dat, js) = eraDatTable_safe(&old, 2017, 9, 1, 0.0).unwrap();
+    vvd(dat, 36.0, 0.0, "eraDatTable", "expired dat", status);
+    viv(
+        (js == DatStatus::PastExpiry) as i32,
+        1,
+        "eraDatTable",
+        "expired",
+        status,
+    );
+    let (_, js) = eraDatTable_safe(&old, 2016, 12, 31, 0.999).unwrap();
+    viv(js.code(), 0, "eraDatTable", "before expiry", status);
+    let (_, js) = eraDatTable_safe(&old, 2017, 1, 1, 0.0).unwrap();
+    viv(js.code(), 1, "eraDatTable", "at expiry", status);
+    let (_, js) = eraDatTable_safe(&builtin, 2028, 12, 31, 0.0).unwrap();
+    viv(js.code(), 0, "eraDatTable", "builtin 2028", status);
+    let (_, js) = eraDatTable_safe(&builtin, 2029, 1, 1, 0.0).unwrap();
+    viv(
+        (js == DatStatus::PastExpiry) as i32,
+        1,
+        "eraDatTable",
+        "builtin 2029",
+        status,
+    );
+    let ((_, _), js) = eraUtctaiTable_safe(&old, 2457754.5, 0.75).unwrap();
+    viv(
+        (js == DatStatus::PastExpiry) as i32,
+        1,
+        "eraUtctaiTable",
+        "expired",
+        status,
+    );
+
+    let f = LeapSecondFile::parse_leap_seconds_list(LEAP_SECONDS_LIST).unwrap();
+    let t = f.to_table();
+    vvd(t.expires(), 61219.0, 0.0, "to_table", "expires", status);
+    let (_, js) = eraDatTable_safe(&t, 2026, 6, 27, 0.0).unwrap();
+    viv(js.code(), 0, "to_table", "before", status);
+    let (_, js) = eraDatTable_safe(&t, 2026, 6, 28, 0.0).unwrap();
+    viv(js.code(), 1, "to_table", "after", status);
+
+    let r = eraDatTable_safe(&LeapSecondTable::default(), 2017, 9, 1, 0.0);
+    viv(
+        (r.err() == Some(ErfaError::EmptyLeapTable)) as i32,
+        1,
+        "eraDatTable",
+        "empty",
+        status,
+    );
+
+    // Concurrent installs: every snapshot pairs a table with its own expiry.
+    let older = LeapSecondTable::new(builtin.entries()[..n - 2].to_vec(), 70_000.0);
+    let torn = std::thread::scope(|s| {
+        let writers: Vec<_> = [&old, &older]
+            .into_iter()
+            .map(|t| {
+                s.spawn(move || {
+                    for _ in 0..2000 {
+                        t.install().unwrap();
+                    }
+                })
+            })
+            .collect();
+        let mut torn = 0;
+        while writers.iter().any(|w| !w.is_finished()) {
+            let c = LeapSecondTable::current().unwrap();
+            let paired = match c.entries().len() {
+                k if k == n - 1 => c.expires() == 57_754.0,
+                k if k == n - 2 => c.expires() == 70_000.0,
+                _ => c.expires() == 62_137.0,
+            };
+            torn += (!paired) as i32;
+        }
+        torn
+    });
+    viv(torn, 0, "LeapSecondTable", "install/current", status);
+    eraSetLeapSeconds_safe(&[]).unwrap();
+    let c = LeapSecondTable::current().unwrap();
+    vvd(
+        c.expires(),
+        62_137.0,
+        0.0,
+        "LeapSecondTable",
+        "reset",
+        status,
+    );
+    viv(
+        c.entries().len() as i32,
+        n as i32,
+        "LeapSecondTable",
+        "reset n",
+        status,
+    );
+}
+
+fn t_delta_t(status: &mut i32) {
+    use DeltaTModel::*;
+
+    vvd(
+        EspenakMeeus.at_year(2000.0),
+        63.86,
+        1e-9,
+        "ΔT",
+        "EM 2000",
+        status,
+    );
+    vvd(
+        EspenakMeeus.at_year(1900.0),
+        -2.79,
+        1e-9,
+        "ΔT",
+        "EM 1900",
+        status,
+    );
+    vvd(
+        EspenakMeeus.at_year(0.0),
+        10583.6,
+        1e-9,
+        "ΔT",
+        "EM 0",
+        status,
+    );
+    vvd(
+        EspenakMeeus.at_year(1000.0),
+        1574.2,
+        1e-9,
+        "ΔT",
+        "EM 1000",
+        status,
+    );
+    vvd(
+        EspenakMeeus.at_year(-1000.0),
+        25427.68,
+        1e-9,
+        "ΔT",
+        "EM -1000",
+        status,
+    );
+    vvd(
+        Parabola.at_year(1820.0),
+        -20.0,
+        0.0,
+        "ΔT",
+        "parabola",
+        status,
+    );
+
+    // The MS2004 spline passes through the tabulated centuries.
+    vvd(
+        MorrisonStephenson2004.at_year(1000.0),
+        1570.0,
+        1e-9,
+        "ΔT",
+        "MS2004 1000",
+        status,
+    );
+    vvd(
+        MorrisonStephenson2004.at_year(-500.0),
+        17190.0,
+        1e-9,
+        "ΔT",
+        "MS2004 -500",
+        status,
+    );
+    vvd(
+        MorrisonStephenson2004.at_year(1600.0),
+        EspenakMeeus.at_year(1600.0),
+        1e-9,
+        "ΔT",
+        "MS2004 1600",
+        status,
+    );
+    let mid = MorrisonStephenson2004.at_year(1050.0);
+    viv(
+        (mid < 1570.0 && mid > 1090.0) as i32,
+        1,
+        "ΔT",
+        "MS2004 1050",
+        status,
+    );
+
+    vvd(
+        Stephenson2016.at_year(-720.0),
+        20371.848,
+        1e-9,
+        "ΔT",
+        "SMH2016 -720",
+        status,
+    );
+    vvd(
+        Stephenson2016.at_year(1000.0),
+        1650.393,
+        1e-9,
+        "ΔT",
+        "SMH2016 1000",
+        status,
+    );
+    vvd(
+        Stephenson2016.at_year(1810.0),
+        15.678,
+        1e-9,
+        "ΔT",
+        "SMH2016 1810",
+        status,
+    );
+    for k in [
+        -100.0, 400.0, 1000.0, 1150.0, 1300.0, 1500.0, 1600.0, 1650.0, 1720.0, 1800.0,
+    ] {
+        vvd(
+            Stephenson2016.at_year(k - 1e-6),
+            Stephenson2016.at_year(k + 1e-6),
+            2e-3,
+            "ΔT",
+            "SMH2016 knot",
+            status,
+        );
+    }
+    // Continuous onto the parabola at −720 and onto Espenak–Meeus at 1810,
+    // and at 1830 where the latter's offset has tapered out.
+    for k in [-720.0, 1810.0, 1830.0] {
+        vvd(
+            Stephenson2016.at_year(k - 1e-6),
+            Stephenson2016.at_year(k + 1e-6),
+            2e-3,
+            "ΔT",
+            "SMH2016 ends",
+            status,
+        );
+    }
+    vvd(
+        Stephenson2016.at_year(1820.0),
+        EspenakMeeus.at_year(1820.0) + 0.5 * (15.678 - EspenakMeeus.at_year(1810.0)),
+        1e-9,
+        "ΔT",
+        "SMH2016 taper",
+        status,
+    );
+    vvd(
+        Stephenson2016.at_year(1900.0),
+        EspenakMeeus.at_year(1900.0),
+        0.0,
+        "ΔT",
+        "SMH2016 1900",
+        status,
+    );
+    vvd(
+        Stephenson2016.at_year(-1000.0),
+        20371.848 + 32.5 * (28.25 * 28.25 - 25.45 * 25.45),
+        1e-9,
+        "ΔT",
+        "SMH2016 parabola",
+        status,
+    );
+
+    // J2000 as a JD gives the same as the decimal year.
+    vvd(
+        EspenakMeeus.at(JulianDate::j2000()),
+        63.86,
+        1e-9,
+        "ΔT",
+        "at",
+        status,
+    );
+
+    // Modelled TT↔UT1 matches eraTtut1 with the same ΔT, and round-trips
+    // even where ΔT is hours.
+    let (ut11, ut12) = ttut1(EspenakMeeus, 2451545.0, 0.0).unwrap();
+    let dt = EspenakMeeus.at(JulianDate::new(ut11, ut12));
+    let (u1, u2) = eraTtut1_safe(2451545.0, 0.0, dt).unwrap();
+    vvd(ut11 + ut12, u1 + u2, 1e-12, "ttut1", "2000", status);
+
+    let ut1 = Ut1::new(990557.5, 0.25); // −2001 Jun
+    let tt = ut1.to_tt_modelled(Stephenson2016).unwrap();
+    vvd(
+        (tt - Tt::new(990557.5, 0.25)) * 86400.0,
+        Stephenson2016.at(ut1.jd()),
+        1e-6,
+        "ut1tt",
+        "ancient",
+        status,
+    );
+    let back = tt.to_ut1_modelled(Stephenson2016).unwrap();
+    vvd(
+        (back - ut1) * 86400.0,
+        0.0,
+        1e-3,
+        "ttut1",
+        "round trip",
+        status,
+    );
+}
+
+fn t_gnss(status: &mut i32) {
+    // Second GPS week rollover, 2019-04-07 00:00:00 UTC (TAI−UTC = 37 s).
+    let utc = Utc::new(2458580.5, 0.0);
+    let (gps, _) = GnssTime::from_utc(GnssSystem::Gps, utc).unwrap();
+    viv(gps.week, 2048, "GnssTime", "GPS week", status);
+    vvd(gps.tow, 18.0, 1e-9, "GnssTime", "GPS tow", status);
+    viv(gps.broadcast_week(), 0, "GnssTime", "GPS broadcast", status);
+
+    let gst = gps.to_system(GnssSystem::Galileo);
+    viv(gst.week, 1024, "GnssTime", "GST week", status);
+    vvd(gst.tow, 18.0, 0.0, "GnssTime", "GST tow", status);
+    let bdt = gps.to_system(GnssSystem::BeiDou);
+    viv(bdt.week, 692, "GnssTime", "BDT week", status);
+    vvd(bdt.tow, 4.0, 0.0, "GnssTime", "BDT tow", status);
+    let (bdt2, _) = GnssTime::from_utc(GnssSystem::BeiDou, utc).unwrap();
+    viv(bdt2.week, 692, "GnssTime", "BDT week via TAI", status);
+    vvd(bdt2.tow, 4.0, 1e-9, "GnssTime", "BDT tow via TAI", status);
+
+    let (back, _) = bdt.to_utc().unwrap();
+    vvd(
+        back.jd1() + back.jd2(),
+        2458580.5,
+        1e-9,
+        "GnssTime",
+        "to_utc",
+        status,
+    );
+
+    // Epochs: GPS 0/0 is 1980-01-06 00:00:19 TAI.
+    let tai = GnssTime::new(GnssSystem::Gps, 0, 0.0).to_tai();
+    vvd(tai.jd1(), 2444244.5, 0.0, "GnssTime", "epoch jd1", status);
+    vvd(
+        tai.jd2(),
+        19.0 / 86400.0,
+        1e-15,
+        "GnssTime",
+        "epoch jd2",
+        status,
+    );
+
+    // tow overflow carries into the week.
+    let t = GnssTime::new(GnssSystem::Gps, 100, -1.0);
+    viv(t.week, 99, "GnssTime", "carry week", status);
+    vvd(t.tow, 604799.0, 0.0, "GnssTime", "carry tow", status);
+
+    // A 10-bit week resolved against a nearby date.
+    let near = Tai::new(2460000.5, 0.0);
+    let t = GnssTime::from_broadcast(GnssSystem::Gps, 1024 + 200, 3600.0, near);
+    let (full, _) = GnssTime::from_utc(GnssSystem::Gps, Utc::new(2460000.5, 0.0)).unwrap();
+    viv(t.week % 1024, 200, "GnssTime", "broadcast mod", status);
+    viv(
+        ((t.week - full.week).abs() < 512) as i32,
+        1,
+        "GnssTime",
+        "broadcast near",
+        status,
+    );
+    viv(t.week, 2248, "GnssTime", "broadcast week", status);
+}
+
+fn t_unix(status: &mut i32) {
+    let table = LeapSecondTable::builtin();
+
+    let (utc, _) = utc_from_unix(&table, 0.0).unwrap();
+    vvd(
+        utc.jd1() + utc.jd2(),
+        2440587.5,
+        0.0,
+        "unix",
+        "epoch",
+        status,
+    );
+
+    // 2016-12-31 had a leap second: 23:59:59.5 is 86399.5/86401 of the day.
+    let (utc, _) = utc_from_unix(&table, 1483228799.5).unwrap();
+    vvd(utc.jd1(), 2457753.5, 0.0, "unix", "leap day jd1", status);
+    vvd(
+        utc.jd2(),
+        86399.5 / 86401.0,
+        1e-15,
+        "unix",
+        "leap day jd2",
+        status,
+    );
+    let (unix, _) = unix_from_utc(&table, utc).unwrap();
+    vvd(unix, 1483228799.5, 1e-6, "unix", "leap day back", status);
+
+    // 23:59:60.5 counts as 00:00:00.5.
+    let (utc, _) = Utc::from_calendar(2016, 12, 31, 23, 59, 60.5).unwrap();
+    let (unix, _) = unix_from_utc(&table, utc).unwrap();
+    vvd(unix, 1483228800.5, 1e-6, "unix", "leap second", status);
+    let (utc, _) = utc_from_unix(&table, 1483228800.0).unwrap();
+    vvd(
+        utc.jd1() + utc.jd2(),
+        2457754.5,
+        0.0,
+        "unix",
+        "2017",
+        status,
+    );
+
+    // Through TAI the repeated count is visible as a 1 s step.
+    let (a, _) = utc_from_unix(&table, 1483228799.0).unwrap();
+    let (b, _) = utc_from_unix(&table, 1483228800.0).unwrap();
+    let (ta, _) = a.to_tai().unwrap();
+    let (tb, _) = b.to_tai().unwrap();
+    vvd((tb - ta) * 86400.0, 2.0, 1e-6, "unix", "TAI step", status);
+
+    // NTP, as in leap-seconds.list: 3692217600 is 2017-01-01.
+    let (utc, _) = Utc::from_ntp(3692217600.0).unwrap();
+    vvd(utc.jd1() + utc.jd2(), 2457754.5, 0.0, "ntp", "2017", status);
+    let (ntp, _) = utc.to_ntp().unwrap();
+    vvd(ntp, 3692217600.0, 1e-6, "ntp", "back", status);
+    vvd(NTP_UNIX_OFFSET, 2208988800.0, 0.0, "ntp", "offset", status);
+
+    let (era, ts) = ntp_to_timestamp(3692217600.25);
+    viv(era, 0, "ntp", "era", status);
+    viv(
+        (ts >> 32) as i32,
+        3692217600_u32 as i32,
+        "ntp",
+        "seconds",
+        status,
+    );
+    viv(
+        (ts & 0xFFFF_FFFF) as i32,
+        0x4000_0000,
+        "ntp",
+        "fraction",
+        status,
+    );
+    vvd(
+        ntp_from_timestamp(era, ts),
+        3692217600.25,
+        0.0,
+        "ntp",
+        "timestamp",
+        status,
+    );
+
+    // Era 1 starts 2036-02-07 06:28:16 UTC.
+    let (era, ts) = ntp_to_timestamp(4294967296.0 + 10.0);
+    viv(era, 1, "ntp", "era 1", status);
+    viv((ts >> 32) as i32, 10, "ntp", "era 1 seconds", status);
+}
+
+fn t_iso(status: &mut i32) {
+    let (tt, _) = Tt::from_iso("2024-03-01T12:34:56.789").unwrap();
+    let (tt0, _) = Tt::from_calendar(2024, 3, 1, 12, 34, 56.789).unwrap();
+    vvd(tt.jd1(), tt0.jd1(), 0.0, "Tt::from_iso", "jd1", status);
+    vvd(tt.jd2(), tt0.jd2(), 0.0, "Tt::from_iso", "jd2", status);
+    let (s, _) = tt.to_iso(3).unwrap();
+    viv(
+        (s == "2024-03-01T12:34:56.789") as i32,
+        1,
+        "Tt::to_iso",
+        "ndp 3",
+        status,
+    );
+    let (s, _) = tt.to_iso(0).unwrap();
+    viv(
+        (s == "2024-03-01T12:34:57") as i32,
+        1,
+        "Tt::to_iso",
+        "ndp 0",
+        status,
+    );
+    let (s, _) = tt.to_iso(6).unwrap();
+    viv(
+        (s == "2024-03-01T12:34:56.789000") as i32,
+        1,
+        "Tt::to_iso",
+        "ndp 6",
+        status,
+    );
+
+    // The leap second parses without warning and formats back as :60.
+    let (utc, js) = Utc::from_iso("2016-12-31T23:59:60.5Z").unwrap();
+    viv(js.code(), 0, "Utc::from_iso", "leap j", status);
+    let (s, _) = utc.to_iso(1).unwrap();
+    viv(
+        (s == "2016-12-31T23:59:60.5") as i32,
+        1,
+        "Utc::to_iso",
+        "leap",
+        status,
+    );
+    let (_, js) = Utc::from_iso("2016-12-30T23:59:60.5").unwrap();
+    viv(
+        js.time_after_end_of_day as i32,
+        1,
+        "Utc::from_iso",
+        "no leap",
+        status,
+    );
+    let (_, js) = Tai::from_iso("2016-12-31T23:59:60.5").unwrap();
+    viv(
+        js.time_after_end_of_day as i32,
+        1,
+        "Tai::from_iso",
+        "60 s",
+        status,
+    );
+
+    // Other accepted forms.
+    let (a, _) = Utc::from_iso("2017-01-01").unwrap();
+    vvd(
+        a.jd1() + a.jd2(),
+        2457754.5,
+        0.0,
+        "Utc::from_iso",
+        "date",
+        status,
+    );
+    let (b, _) = Utc::from_iso("2017-01-01 06:00").unwrap();
+    vvd(b.jd2(), 0.25, 0.0, "Utc::from_iso", "hh:mm", status);
+    let (c, _) = Utc::from_iso("31/12/98").unwrap();
+    vvd(
+        c.jd1() + c.jd2(),
+        2451178.5,
+        0.0,
+        "Utc::from_iso",
+        "DD/MM/YY",
+        status,
+    );
+    let f = parse_fields("-04712-01-01T12:00:00").unwrap();
+    viv(f.iy, -4712, "parse_fields", "signed year", status);
+    let (d, _) = Tt::from_iso("-04712-01-01T12:00:00").unwrap();
+    // Proleptic Gregorian, so 38 days after JD 0 (Julian −4712 Jan 1).
+    vvd(
+        d.jd1() + d.jd2(),
+        38.0,
+        0.0,
+        "Tt::from_iso",
+        "signed year",
+        status,
+    );
+    let (s, _) = d.to_iso(0).unwrap();
+    viv(
+        (s == "-04712-01-01T12:00:00") as i32,
+        1,
+        "Tt::to_iso",
+        "signed year",
+        status,
+    );
+
+    for bad in [
+        "2024-3-01",
+        "24-03-01",
+        "2024-03-01T12",
+        "2024-03-01T12:34:5",
+        "2024-03-01T12:34:56.7x",
+        "2024-03-01T12:34:56:01",
+        "12024-03-01",
+    ] {
+        let r = Utc::from_iso(bad);
+        viv(
+            (r.err() == Some(ErfaError::BadTimeString)) as i32,
+            1,
+            "Utc::from_iso",
+            bad,
+            status,
+        );
+    }
+    let r = Tt::from_iso("2024-03-01T00:00:00Z");
+    viv(
+        (r.err() == Some(ErfaError::BadTimeString)) as i32,
+        1,
+        "Tt::from_iso",
+        "Z",
+        status,
+    );
+    let r = tt.to_iso(10);
+    viv(
+        (r.err() == Some(ErfaError::BadPrecision)) as i32,
+        1,
+        "Tt::to_iso",
+        "ndp 10",
+        status,
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() > 1 {
+        unsafe {
+            VERBOSE = true;
+        }
+    }
+
+    let mut status = 0;
+
+    t_julian_date(&mut status);
+    t_tai(&mut status);
+    t_tt(&mut status);
+    t_utc(&mut status);
+    t_ut1(&mut status);
+    t_relativistic(&mut status);
+    t_round_trip(&mut status);
+    t_route(&mut status);
+    t_convert(&mut status);
+    t_leap_files(&mut status);
+    t_leap_table(&mut status);
+    t_delta_t(&mut status);
+    t_gnss(&mut status);
+    t_unix(&mut status);
+    t_iso(&mut status);
+
+    if status != 0 {
+        println!("t_erfa_c validation failed!");
+    } else {
+        println!("t_erfa_c validation successful");
+    }
+    std::process::exit(status);
+}
@@ -0,0 +1,281 @@
+// bodies
+//   Apparent and observed places of the Sun, Moon and planets for a site.
+//
+//   Body               → Sun, Moon, Mercury … Neptune
+//   BodyEphemeris      → barycentric pv of a body at a TDB date; Epv00
+//                        (eraEpv00 + eraPlan94 + eraMoon98) and SpkKernel
+//   place / place_with → from UTC and site, via eraApco13Eph
+//   place_astrom       → the same from an existing eraASTROM context
+//
+//   The observer's barycentric position comes from astrom.eb, so the
+//   result is topocentric: parallax needs no separate step.  Light time is
+//   iterated against the body's barycentric position; the direction is then
+//   deflected by the Sun (eraLdn, the Sun itself excepted), aberrated with
+//   the observer's velocity (eraAb) and rotated to CIRS by astrom.bpn.
+//   eraAtioq gives the observed place, refracted if phpa > 0.  eraLdn treats
+//   the body as infinitely distant, which is adequate except very near the
+//   Sun.  TT is used for TDB.
+
+use crate::spk::{
+    SpkKernel, NAIF_JUPITER_BARYCENTER, NAIF_MARS_BARYCENTER, NAIF_MERCURY, NAIF_MOON,
+    NAIF_NEPTUNE_BARYCENTER, NAIF_SATURN_BARYCENTER, NAIF_SSB, NAIF_SUN, NAIF_URANUS_BARYCENTER,
+    NAIF_VENUS,
+};
+use crate::G13_safe::{eraEpv00_safe, Ephemeris, Epv00};
+use crate::G1_safe::{eraAb_safe, eraAnp_safe};
+use crate::G20_safe::eraLdn_safe;
+use crate::G21_safe::eraMoon98_safe;
+use crate::G24_safe::eraPlan94_safe;
+use crate::G25_safe::eraPn_safe;
+use crate::G28_safe::eraRxp_safe;
+use crate::G2_safe::eraApco13Eph_safe;
+use crate::G32_safe::eraTaitt_safe;
+use crate::G33_safe::eraUtctai_safe;
+use crate::G4_safe::eraAtioq_safe;
+use crate::G7_safe::eraC2s_safe;
+use crate::H1_safe::{eraASTROM, eraLDBODY, DatStatus, ERFA_AULT, ERFA_DAYSEC, ERFA_DPI};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Light-time iterations; three reach 1e-12 d for the Moon and planets.
+const LIGHT_TIME_ITERATIONS: usize = 4;
+
+// Deflection limiter for the Sun, as recommended for eraLdn.
+const SUN_DL: f64 = 6e-6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Body {
+    Sun,
+    Moon,
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+}
+
+impl Body {
+    pub const ALL: [Body; 9] = [
+        Body::Sun,
+        Body::Moon,
+        Body::Mercury,
+        Body::Venus,
+        Body::Mars,
+        Body::Jupiter,
+        Body::Saturn,
+        Body::Uranus,
+        Body::Neptune,
+    ];
+
+    // eraPlan94 planet number, for the planets.
+    pub fn plan94(self) -> Option<i32> {
+        match self {
+            Body::Sun | Body::Moon => None,
+            Body::Mercury => Some(1),
+            Body::Venus => Some(2),
+            Body::Mars => Some(4),
+            Body::Jupiter => Some(5),
+            Body::Saturn => Some(6),
+            Body::Uranus => Some(7),
+            Body::Neptune => Some(8),
+        }
+    }
+
+    // NAIF code in the DE kernels; Mars and beyond are system barycentres.
+    pub fn naif_id(self) -> i32 {
+        match self {
+            Body::Sun => NAIF_SUN,
+            Body::Moon => NAIF_MOON,
+            Body::Mercury => NAIF_MERCURY,
+            Body::Venus => NAIF_VENUS,
+            Body::Mars => NAIF_MARS_BARYCENTER,
+            Body::Jupiter => NAIF_JUPITER_BARYCENTER,
+            Body::Saturn => NAIF_SATURN_BARYCENTER,
+            Body::Uranus => NAIF_URANUS_BARYCENTER,
+            Body::Neptune => NAIF_NEPTUNE_BARYCENTER,
+        }
+    }
+}
+
+// Barycentric pv of a body (au, au/day) at a TDB date, on ICRS axes.
+pub trait BodyEphemeris: Ephemeris {
+    fn body_pv(&self, body: Body, date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 2]>;
+}
+
+impl BodyEphemeris for Epv00 {
+    fn body_pv(&self, body: Body, date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        let (pvh, pvb, _) = eraEpv00_safe(date1, date2)?;
+        let offset = match body {
+            Body::Sun => [[0.0; 3]; 2],
+            Body::Moon => {
+                // Geocentric Moon on top of the barycentric Earth.
+                let pvm = eraMoon98_safe(date1, date2)?;
+                let mut pv = [[0.0; 3]; 2];
+                for j in 0..2 {
+                    for i in 0..3 {
+                        pv[j][i] = pvh[j][i] + pvm[j][i];
+                    }
+                }
+                pv
+            }
+            _ => {
+                let np = body.plan94().unwrap_or_default();
+                eraPlan94_safe(date1, date2, np)?.0
+            }
+        };
+        // Heliocentric offset on top of the barycentric Sun.
+        let mut pv = [[0.0; 3]; 2];
+        for j in 0..2 {
+            for i in 0..3 {
+                pv[j][i] = pvb[j][i] - pvh[j][i] + offset[j][i];
+            }
+        }
+        Ok(pv)
+    }
+}
+
+impl BodyEphemeris for SpkKernel {
+    fn body_pv(&self, body: Body, date1: f64, date2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        self.pv(body.naif_id(), NAIF_SSB, date1, date2)
+    }
+}
+
+// Place of a body as seen from the site (angles in radians).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BodyPlace {
+    pub rc: f64,         // astrometric RA (ICRS, light-time corrected)
+    pub dc: f64,         // astrometric Dec
+    pub ri: f64,         // apparent RA (CIRS, topocentric)
+    pub di: f64,         // apparent Dec (CIRS, topocentric)
+    pub ra: f64,         // apparent RA from the equinox (ri − eo)
+    pub az: f64,         // observed azimuth (N = 0, E = 90°)
+    pub el: f64,         // observed elevation
+    pub ha: f64,         // observed hour angle
+    pub dob: f64,        // observed Dec
+    pub rob: f64,        // observed RA (CIO-based)
+    pub distance: f64,   // observer to body at emission (au)
+    pub light_time: f64, // (days)
+}
+
+// Place of a body for a site at a UTC, with the eraApco13 arguments and
+// the built-in ephemerides.
+#[allow(clippy::too_many_arguments)]
+pub fn place(
+    body: Body,
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+) -> ErfaResult<(BodyPlace, DatStatus)> {
+    place_with(
+        &Epv00, body, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl,
+    )
+}
+
+// As place, with Earth and body positions from the given ephemeris.
+#[allow(clippy::too_many_arguments)]
+pub fn place_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    body: Body,
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+) -> ErfaResult<(BodyPlace, DatStatus)> {
+    let mut astrom = eraASTROM::default();
+    let (eo, j) = eraApco13Eph_safe(
+        eph,
+        utc1,
+        utc2,
+        dut1,
+        elong,
+        phi,
+        hm,
+        xp,
+        yp,
+        phpa,
+        tc,
+        rh,
+        wl,
+        &mut astrom,
+    )?;
+    let ((tai1, tai2), _) = eraUtctai_safe(utc1, utc2)?;
+    let (tt1, tt2) = eraTaitt_safe(tai1, tai2)?;
+    Ok((place_astrom(eph, body, tt1, tt2, &astrom, eo)?, j))
+}
+
+// Place of a body at a TT date from an eraApco/eraApco13 context made for
+// that date; eo is the equation of the origins it returned.
+pub fn place_astrom<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    body: Body,
+    tt1: f64,
+    tt2: f64,
+    astrom: &eraASTROM,
+    eo: f64,
+) -> ErfaResult<BodyPlace> {
+    // Observer → body at emission, iterating the light time.
+    let mut p = [0.0; 3];
+    let mut tau = 0.0;
+    for _ in 0..LIGHT_TIME_ITERATIONS {
+        let pv = eph.body_pv(body, tt1, tt2 - tau)?;
+        for i in 0..3 {
+            p[i] = pv[0][i] - astrom.eb[i];
+        }
+        tau = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt() * ERFA_AULT / ERFA_DAYSEC;
+    }
+    let (distance, u) = eraPn_safe(&p)?;
+    let (rc, dc) = eraC2s_safe(&u)?;
+
+    // Deflection by the Sun, then aberration.
+    let pnat = if body == Body::Sun {
+        u
+    } else {
+        let sun = eraLDBODY {
+            bm: 1.0,
+            dl: SUN_DL,
+            pv: eph.body_pv(Body::Sun, tt1, tt2)?,
+        };
+        eraLdn_safe(&[sun], &astrom.eb, &u)?
+    };
+    let ppr = eraAb_safe(&pnat, &astrom.v, astrom.em, astrom.bm1)?;
+
+    // CIRS, then observed.
+    let pi = eraRxp_safe(&astrom.bpn, &ppr)?;
+    let (w, di) = eraC2s_safe(&pi)?;
+    let ri = eraAnp_safe(w)?;
+    let (aob, zob, hob, dob, rob) = eraAtioq_safe(ri, di, astrom)?;
+
+    Ok(BodyPlace {
+        rc: eraAnp_safe(rc)?,
+        dc,
+        ri,
+        di,
+        ra: eraAnp_safe(ri - eo)?,
+        az: aob,
+        el: ERFA_DPI / 2.0 - zob,
+        ha: hob,
+        dob,
+        rob,
+        distance,
+        light_time: tau,
+    })
+}
@@ -0,0 +1,259 @@
+// dual
+//   Forward-mode automatic differentiation for the Jacobian variants
+//   (eraApioJac_safe, eraAtciqJac_safe, eraAtioqJac_safe, eraAtoiqJac_safe).
+//
+//   Dual<N>          → a value and its partials with respect to N inputs
+//   Dual::variables  → the inputs themselves, one unit partial each
+//   s2c / c2s        → as eraS2c_safe and eraC2s_safe
+//   pdp / pxp / rxp  → as eraPdp_safe, eraPxp_safe and eraRxp_safe
+//   pn               → unit vector, as the second result of eraPn_safe
+//   jacobian         → partials of several results, one row each
+//
+//   Carrying partials through the arithmetic gives derivatives exact to
+//   rounding, free of the step-size trade-off of finite differences.  At
+//   points where a function is not differentiable (atan2 at the origin,
+//   sqrt at zero) the partials are returned as zero, matching the values
+//   ERFA substitutes there.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// A value with its partial derivatives.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dual<const N: usize> {
+    pub v: f64,
+    pub d: [f64; N],
+}
+
+impl<const N: usize> Dual<N> {
+    // A value that does not depend on the inputs.
+    pub fn constant(v: f64) -> Dual<N> {
+        Dual { v, d: [0.0; N] }
+    }
+
+    // Input i with value v.
+    pub fn variable(v: f64, i: usize) -> Dual<N> {
+        let mut d = [0.0; N];
+        d[i] = 1.0;
+        Dual { v, d }
+    }
+
+    // All N inputs, in order.
+    pub fn variables(v: [f64; N]) -> [Dual<N>; N] {
+        std::array::from_fn(|i| Dual::variable(v[i], i))
+    }
+
+    // Result of a function with value v and derivative dv at self.
+    fn chain(self, v: f64, dv: f64) -> Dual<N> {
+        Dual {
+            v,
+            d: self.d.map(|x| dv * x),
+        }
+    }
+
+    fn product(self, b: Dual<N>) -> Dual<N> {
+        Dual {
+            v: self.v * b.v,
+            d: std::array::from_fn(|i| self.d[i] * b.v + self.v * b.d[i]),
+        }
+    }
+
+    fn quotient(self, b: Dual<N>) -> Dual<N> {
+        let q = self.v / b.v;
+        Dual {
+            v: q,
+            d: std::array::from_fn(|i| (self.d[i] - q * b.d[i]) / b.v),
+        }
+    }
+
+    pub fn sin(self) -> Dual<N> {
+        self.chain(self.v.sin(), self.v.cos())
+    }
+
+    pub fn cos(self) -> Dual<N> {
+        self.chain(self.v.cos(), -self.v.sin())
+    }
+
+    pub fn sin_cos(self) -> (Dual<N>, Dual<N>) {
+        (self.sin(), self.cos())
+    }
+
+    pub fn sqrt(self) -> Dual<N> {
+        let s = self.v.sqrt();
+        self.chain(s, if s > 0.0 { 0.5 / s } else { 0.0 })
+    }
+
+    // Four-quadrant arctangent of self/x.
+    pub fn atan2(self, x: Dual<N>) -> Dual<N> {
+        let r2 = x.v * x.v + self.v * self.v;
+        let d = if r2 > 0.0 {
+            std::array::from_fn(|i| (x.v * self.d[i] - self.v * x.d[i]) / r2)
+        } else {
+            [0.0; N]
+        };
+        Dual {
+            v: self.v.atan2(x.v),
+            d,
+        }
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Dual<N>;
+    fn add(self, b: Dual<N>) -> Dual<N> {
+        Dual {
+            v: self.v + b.v,
+            d: std::array::from_fn(|i| self.d[i] + b.d[i]),
+        }
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Dual<N>;
+    fn sub(self, b: Dual<N>) -> Dual<N> {
+        Dual {
+            v: self.v - b.v,
+            d: std::array::from_fn(|i| self.d[i] - b.d[i]),
+        }
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Dual<N>;
+    fn mul(self, b: Dual<N>) -> Dual<N> {
+        self.product(b)
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Dual<N>;
+    fn div(self, b: Dual<N>) -> Dual<N> {
+        self.quotient(b)
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Dual<N>;
+    fn neg(self) -> Dual<N> {
+        self.chain(-self.v, -1.0)
+    }
+}
+
+impl<const N: usize> Add<f64> for Dual<N> {
+    type Output = Dual<N>;
+    fn add(self, b: f64) -> Dual<N> {
+        Dual {
+            v: self.v + b,
+            d: self.d,
+        }
+    }
+}
+
+impl<const N: usize> Sub<f64> for Dual<N> {
+    type Output = Dual<N>;
+    fn sub(self, b: f64) -> Dual<N> {
+        Dual {
+            v: self.v - b,
+            d: self.d,
+        }
+    }
+}
+
+impl<const N: usize> Mul<f64> for Dual<N> {
+    type Output = Dual<N>;
+    fn mul(self, b: f64) -> Dual<N> {
+        self.chain(self.v * b, b)
+    }
+}
+
+impl<const N: usize> Div<f64> for Dual<N> {
+    type Output = Dual<N>;
+    fn div(self, b: f64) -> Dual<N> {
+        self.chain(self.v / b, 1.0 / b)
+    }
+}
+
+impl<const N: usize> Add<Dual<N>> for f64 {
+    type Output = Dual<N>;
+    fn add(self, b: Dual<N>) -> Dual<N> {
+        b + self
+    }
+}
+
+impl<const N: usize> Sub<Dual<N>> for f64 {
+    type Output = Dual<N>;
+    fn sub(self, b: Dual<N>) -> Dual<N> {
+        -b + self
+    }
+}
+
+impl<const N: usize> Mul<Dual<N>> for f64 {
+    type Output = Dual<N>;
+    fn mul(self, b: Dual<N>) -> Dual<N> {
+        b * self
+    }
+}
+
+impl<const N: usize> Div<Dual<N>> for f64 {
+    type Output = Dual<N>;
+    fn div(self, b: Dual<N>) -> Dual<N> {
+        Dual::constant(self) / b
+    }
+}
+
+// Unit vector from spherical coordinates.
+pub fn s2c<const N: usize>(theta: Dual<N>, phi: Dual<N>) -> [Dual<N>; 3] {
+    let (st, ct) = theta.sin_cos();
+    let (sp, cp) = phi.sin_cos();
+    [ct * cp, st * cp, sp]
+}
+
+// Spherical coordinates of a vector; zero where ERFA returns zero.
+pub fn c2s<const N: usize>(p: &[Dual<N>; 3]) -> (Dual<N>, Dual<N>) {
+    let [x, y, z] = *p;
+    let d2 = x * x + y * y;
+    let theta = if d2.v == 0.0 {
+        Dual::constant(0.0)
+    } else {
+        y.atan2(x)
+    };
+    let phi = if z.v == 0.0 {
+        Dual::constant(0.0)
+    } else {
+        z.atan2(d2.sqrt())
+    };
+    (theta, phi)
+}
+
+// Scalar product.
+pub fn pdp<const N: usize>(a: &[Dual<N>; 3], b: &[Dual<N>; 3]) -> Dual<N> {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// Vector product.
+pub fn pxp<const N: usize>(a: &[Dual<N>; 3], b: &[Dual<N>; 3]) -> [Dual<N>; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+// Constant matrix times vector.
+pub fn rxp<const N: usize>(r: &[[f64; 3]; 3], p: &[Dual<N>; 3]) -> [Dual<N>; 3] {
+    r.map(|row| row[0] * p[0] + row[1] * p[1] + row[2] * p[2])
+}
+
+// Unit vector in the direction of p (zero for a null vector).
+pub fn pn<const N: usize>(p: &[Dual<N>; 3]) -> [Dual<N>; 3] {
+    let w = pdp(p, p).sqrt();
+    if w.v == 0.0 {
+        [Dual::constant(0.0); 3]
+    } else {
+        p.map(|x| x / w)
+    }
+}
+
+// Partials of each result, one row per result.
+pub fn jacobian<const N: usize, const M: usize>(f: &[Dual<N>; M]) -> [[f64; N]; M] {
+    f.map(|x| x.d)
+}
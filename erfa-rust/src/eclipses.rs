@@ -0,0 +1,481 @@
+// eclipses
+//   Solar and lunar eclipses, and their local circumstances at a site.
+//
+//   EclipseKind                          → Total, Annular, Partial, Penumbral
+//   SolarEclipse                         → greatest eclipse and γ
+//   LunarEclipse                         → magnitudes and shadow contacts
+//   solar_eclipses / solar_eclipses_with → solar eclipses in a TT window
+//   lunar_eclipses / lunar_eclipses_with → lunar eclipses in a TT window
+//   solar_local / solar_local_with       → contacts, magnitude, obscuration
+//   lunar_local / lunar_local_with       → Moon's elevation at the contacts
+//   obscuration                          → fraction of a disc covered
+//
+//   Candidates are the new and full Moons of phenomena::moon_phases, and
+//   all geocentric geometry uses the apparent places found there.  For the
+//   Sun, greatest eclipse is when the shadow axis passes closest to the
+//   Earth's centre, γ equatorial radii away (positive to the north).  With
+//   |γ| < 1 the eclipse is central, and total or annular as the umbra or
+//   antumbra reaches the nearest point of a spherical Earth; hybrids are
+//   classed by that point.  Otherwise it is partial if the penumbra reaches
+//   the Earth.  For the Moon, the shadow radii follow Danjon (the Earth's
+//   parallax, reduced to latitude 45° and enlarged by 1%, plus the Sun's,
+//   ∓ its semi-diameter) and greatest eclipse is the Moon's closest
+//   approach to the shadow centre.  Local circumstances use the
+//   topocentric places of bodies::place, unrefracted; contacts are given
+//   whether or not the body is up, with its elevation.  With eraMoon98 the
+//   times are good to a minute or so and the magnitudes to about 0.01.
+//   Times are TT two-part JDs, with the first part of the window's start.
+
+use crate::bodies::{place_with, Body, BodyEphemeris, BodyPlace};
+use crate::events::{refine, Site};
+use crate::phenomena::{apparent_geocentric, moon_phases_with, MoonPhase};
+use crate::G13_safe::Epv00;
+use crate::G25_safe::eraPn_safe;
+use crate::G30_safe::{eraSepp_safe, eraSeps_safe};
+use crate::G32_safe::eraTaiutc_safe;
+use crate::G33_safe::eraTttai_safe;
+use crate::H1_safe::{ERFA_DAU, ERFA_DPI};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Equatorial radius of the Earth (m), the Moon's radius in those units and
+// the Sun's radius (m, 959.63″ at 1 au).  The last is the semi-diameter of
+// the eclipse canons and Besselian elements, kept so contacts agree with
+// them; it is larger than the nominal photospheric radius of events.rs.
+const EARTH_RADIUS: f64 = 6_378_137.0;
+const MOON_K: f64 = 0.272_507_6;
+const SUN_RADIUS_ECLIPSE: f64 = 695_990e3;
+
+// Danjon's enlargement of the shadow, and the ratio of the Earth's radius
+// at latitude 45° to the equatorial one.
+const DANJON: f64 = 1.01;
+const RADIUS_45: f64 = 0.998_340;
+
+// New and full Moons farther than this from the shadow centre, or from
+// the Sun, are passed over (radians; the eclipse limits are about 1.6°).
+const SCREEN: f64 = 0.05;
+
+// Half-width of the windows searched around a syzygy and around greatest
+// eclipse (days), sampling step, and the tolerance on greatest eclipse.
+const WINDOW: f64 = 0.25;
+const STEP: f64 = 1.0 / 48.0;
+const TOLERANCE: f64 = 1e-7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EclipseKind {
+    Total,
+    Annular,
+    Partial,
+    Penumbral,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarEclipse {
+    pub kind: EclipseKind,
+    pub tt1: f64,
+    pub tt2: f64,   // greatest eclipse
+    pub gamma: f64, // least distance of the shadow axis (Earth radii)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LunarEclipse {
+    pub kind: EclipseKind,
+    pub tt1: f64,
+    pub tt2: f64, // greatest eclipse
+    pub penumbral_magnitude: f64,
+    pub umbral_magnitude: f64,
+    // Penumbra and umbra contacts, where they occur, as TT two-part JDs.
+    pub p1: Option<(f64, f64)>,
+    pub u1: Option<(f64, f64)>,
+    pub u2: Option<(f64, f64)>,
+    pub u3: Option<(f64, f64)>,
+    pub u4: Option<(f64, f64)>,
+    pub p4: Option<(f64, f64)>,
+}
+
+// An instant at the site, with the eclipsed body's geometric elevation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+    pub tt1: f64,
+    pub tt2: f64,
+    pub el: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalSolarEclipse {
+    pub kind: EclipseKind,
+    pub greatest: Contact,
+    pub magnitude: f64,   // fraction of the Sun's diameter covered
+    pub obscuration: f64, // fraction of the Sun's disc covered
+    pub c1: Contact,
+    pub c2: Option<Contact>,
+    pub c3: Option<Contact>,
+    pub c4: Contact,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalLunarEclipse {
+    pub greatest: Contact,
+    pub p1: Option<Contact>,
+    pub u1: Option<Contact>,
+    pub u2: Option<Contact>,
+    pub u3: Option<Contact>,
+    pub u4: Option<Contact>,
+    pub p4: Option<Contact>,
+}
+
+// Solar eclipses over `days` from the TT (tt1, tt2), with the built-in
+// ephemerides.
+pub fn solar_eclipses(tt1: f64, tt2: f64, days: f64) -> ErfaResult<Vec<SolarEclipse>> {
+    solar_eclipses_with(&Epv00, tt1, tt2, days)
+}
+
+// As solar_eclipses, with Earth, Sun and Moon from the given ephemeris.
+pub fn solar_eclipses_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    tt1: f64,
+    tt2: f64,
+    days: f64,
+) -> ErfaResult<Vec<SolarEclipse>> {
+    let mut out = Vec::new();
+    for p in moon_phases_with(eph, tt1, tt2, days)? {
+        if p.phase != MoonPhase::New {
+            continue;
+        }
+        let (um, _) = apparent_geocentric(eph, Body::Moon, tt1, p.tt2)?;
+        let (us, _) = apparent_geocentric(eph, Body::Sun, tt1, p.tt2)?;
+        if eraSepp_safe(&um, &us)? > SCREEN {
+            continue;
+        }
+        let f = |t: f64| Ok(shadow_axis(eph, tt1, t)?.0.abs());
+        let (t, _) = minimize(&f, p.tt2 - WINDOW, p.tt2 + WINDOW)?;
+        let (gamma, l1, l2) = shadow_axis(eph, tt1, t)?;
+        let kind = if gamma.abs() < 1.0 {
+            if l2 > 0.0 {
+                EclipseKind::Total
+            } else {
+                EclipseKind::Annular
+            }
+        } else if gamma.abs() < 1.0 + l1 {
+            EclipseKind::Partial
+        } else {
+            continue;
+        };
+        out.push(SolarEclipse {
+            kind,
+            tt1,
+            tt2: t,
+            gamma,
+        });
+    }
+    Ok(out)
+}
+
+// Lunar eclipses over `days` from the TT (tt1, tt2), with the built-in
+// ephemerides.
+pub fn lunar_eclipses(tt1: f64, tt2: f64, days: f64) -> ErfaResult<Vec<LunarEclipse>> {
+    lunar_eclipses_with(&Epv00, tt1, tt2, days)
+}
+
+// As lunar_eclipses, with Earth, Sun and Moon from the given ephemeris.
+pub fn lunar_eclipses_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    tt1: f64,
+    tt2: f64,
+    days: f64,
+) -> ErfaResult<Vec<LunarEclipse>> {
+    let mut out = Vec::new();
+    for p in moon_phases_with(eph, tt1, tt2, days)? {
+        if p.phase != MoonPhase::Full || earth_shadow(eph, tt1, p.tt2)?.0 > SCREEN {
+            continue;
+        }
+        let f = |t: f64| Ok(earth_shadow(eph, tt1, t)?.0);
+        let (t, _) = minimize(&f, p.tt2 - WINDOW, p.tt2 + WINDOW)?;
+        let (d, rp, ru, sm) = earth_shadow(eph, tt1, t)?;
+        let penumbral_magnitude = (rp + sm - d) / (2.0 * sm);
+        let umbral_magnitude = (ru + sm - d) / (2.0 * sm);
+        let kind = if umbral_magnitude >= 1.0 {
+            EclipseKind::Total
+        } else if umbral_magnitude > 0.0 {
+            EclipseKind::Partial
+        } else if penumbral_magnitude > 0.0 {
+            EclipseKind::Penumbral
+        } else {
+            continue;
+        };
+
+        // Contacts: the Moon's limb on the edge of each shadow.
+        let contacts = |edge: fn(f64, f64, f64) -> f64| {
+            let g = |t: f64| {
+                let (d, rp, ru, sm) = earth_shadow(eph, tt1, t)?;
+                Ok(d - edge(rp, ru, sm))
+            };
+            contact_pair(&g, t)
+        };
+        let (p1, p4) = contacts(|rp, _, sm| rp + sm)?;
+        let (u1, u4) = contacts(|_, ru, sm| ru + sm)?;
+        let (u2, u3) = contacts(|_, ru, sm| ru - sm)?;
+        let pair = |c: Option<f64>| c.map(|c| (tt1, c));
+        out.push(LunarEclipse {
+            kind,
+            tt1,
+            tt2: t,
+            penumbral_magnitude,
+            umbral_magnitude,
+            p1: pair(p1),
+            u1: pair(u1),
+            u2: pair(u2),
+            u3: pair(u3),
+            u4: pair(u4),
+            p4: pair(p4),
+        });
+    }
+    Ok(out)
+}
+
+// The eclipse as seen from the site, or None if the Moon misses the Sun
+// there, with the built-in ephemerides.
+pub fn solar_local(eclipse: &SolarEclipse, site: &Site) -> ErfaResult<Option<LocalSolarEclipse>> {
+    solar_local_with(&Epv00, eclipse, site)
+}
+
+// As solar_local, with Earth, Sun and Moon from the given ephemeris.
+pub fn solar_local_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    eclipse: &SolarEclipse,
+    site: &Site,
+) -> ErfaResult<Option<LocalSolarEclipse>> {
+    let tt1 = eclipse.tt1;
+
+    // Topocentric separation and semi-diameters, and the Sun's elevation.
+    let f = |t: f64| -> ErfaResult<(f64, f64, f64, f64)> {
+        let s = topocentric(eph, Body::Sun, site, tt1, t)?;
+        let m = topocentric(eph, Body::Moon, site, tt1, t)?;
+        let d = eraSeps_safe(s.ri, s.di, m.ri, m.di)?;
+        let ss = (SUN_RADIUS_ECLIPSE / (s.distance * ERFA_DAU)).asin();
+        let sm = (MOON_K * EARTH_RADIUS / (m.distance * ERFA_DAU)).asin();
+        Ok((d, ss, sm, s.el))
+    };
+    let (t, _) = minimize(&|t| Ok(f(t)?.0), eclipse.tt2 - WINDOW, eclipse.tt2 + WINDOW)?;
+    let (d, ss, sm, el) = f(t)?;
+    if d >= ss + sm {
+        return Ok(None);
+    }
+    let kind = if d >= (sm - ss).abs() {
+        EclipseKind::Partial
+    } else if sm > ss {
+        EclipseKind::Total
+    } else {
+        EclipseKind::Annular
+    };
+
+    let contact = |c: f64| -> ErfaResult<Contact> {
+        Ok(Contact {
+            tt1,
+            tt2: c,
+            el: f(c)?.3,
+        })
+    };
+    let outer = |t: f64| {
+        let (d, ss, sm, _) = f(t)?;
+        Ok(d - (ss + sm))
+    };
+    let inner = |t: f64| {
+        let (d, ss, sm, _) = f(t)?;
+        Ok(d - (sm - ss).abs())
+    };
+    let (c1, c4) = contact_pair(&outer, t)?;
+    let (c2, c3) = contact_pair(&inner, t)?;
+    let (Some(c1), Some(c4)) = (c1, c4) else {
+        return Ok(None);
+    };
+    Ok(Some(LocalSolarEclipse {
+        kind,
+        greatest: Contact { tt1, tt2: t, el },
+        magnitude: (ss + sm - d) / (2.0 * ss),
+        obscuration: obscuration(ss, sm, d),
+        c1: contact(c1)?,
+        c2: c2.map(contact).transpose()?,
+        c3: c3.map(contact).transpose()?,
+        c4: contact(c4)?,
+    }))
+}
+
+// The Moon's elevation at greatest eclipse and at each contact, with the
+// built-in ephemerides.
+pub fn lunar_local(eclipse: &LunarEclipse, site: &Site) -> ErfaResult<LocalLunarEclipse> {
+    lunar_local_with(&Epv00, eclipse, site)
+}
+
+// As lunar_local, with Earth, Sun and Moon from the given ephemeris.
+pub fn lunar_local_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    eclipse: &LunarEclipse,
+    site: &Site,
+) -> ErfaResult<LocalLunarEclipse> {
+    let contact = |(tt1, tt2): (f64, f64)| -> ErfaResult<Contact> {
+        Ok(Contact {
+            tt1,
+            tt2,
+            el: topocentric(eph, Body::Moon, site, tt1, tt2)?.el,
+        })
+    };
+    Ok(LocalLunarEclipse {
+        greatest: contact((eclipse.tt1, eclipse.tt2))?,
+        p1: eclipse.p1.map(contact).transpose()?,
+        u1: eclipse.u1.map(contact).transpose()?,
+        u2: eclipse.u2.map(contact).transpose()?,
+        u3: eclipse.u3.map(contact).transpose()?,
+        u4: eclipse.u4.map(contact).transpose()?,
+        p4: eclipse.p4.map(contact).transpose()?,
+    })
+}
+
+// Fraction of a disc of radius r1 covered by one of radius r2 whose centre
+// is d away (all angles, or all lengths).
+pub fn obscuration(r1: f64, r2: f64, d: f64) -> f64 {
+    if d >= r1 + r2 {
+        return 0.0;
+    }
+    if d <= (r2 - r1).abs() {
+        return if r2 >= r1 { 1.0 } else { (r2 / r1).powi(2) };
+    }
+    let a1 = ((d * d + r1 * r1 - r2 * r2) / (2.0 * d * r1))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let a2 = ((d * d + r2 * r2 - r1 * r1) / (2.0 * d * r2))
+        .clamp(-1.0, 1.0)
+        .acos();
+    let k = (-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2);
+    (r1 * r1 * a1 + r2 * r2 * a2 - 0.5 * k.max(0.0).sqrt()) / (ERFA_DPI * r1 * r1)
+}
+
+// Signed distance of the Moon's shadow axis from the Earth's centre, the
+// penumbra's radius in the plane through the centre and the umbra's at
+// the surface point nearest the Moon (negative for the antumbra), all in
+// Earth radii.
+fn shadow_axis<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    tt1: f64,
+    tt2: f64,
+) -> ErfaResult<(f64, f64, f64)> {
+    let scale = ERFA_DAU / EARTH_RADIUS;
+    let (um, dm) = apparent_geocentric(eph, Body::Moon, tt1, tt2)?;
+    let (us, ds) = apparent_geocentric(eph, Body::Sun, tt1, tt2)?;
+    let mut m = [0.0; 3];
+    let mut sm = [0.0; 3];
+    for i in 0..3 {
+        m[i] = um[i] * dm * scale;
+        sm[i] = m[i] - us[i] * ds * scale;
+    }
+    let (d, k) = eraPn_safe(&sm)?;
+
+    // Geocentre relative to the axis, and its depth behind the Moon.
+    let z = -(m[0] * k[0] + m[1] * k[1] + m[2] * k[2]);
+    let mut g = [0.0; 3];
+    for i in 0..3 {
+        g[i] = -m[i] - z * k[i];
+    }
+    let (gamma, _) = eraPn_safe(&g)?;
+
+    let rs = SUN_RADIUS_ECLIPSE / EARTH_RADIUS;
+    let f1 = ((rs + MOON_K) / d).asin();
+    let f2 = ((rs - MOON_K) / d).asin();
+    let l1 = z * f1.tan() + MOON_K / f1.cos();
+    let zs = z - (1.0 - gamma * gamma).max(0.0).sqrt();
+    let l2 = MOON_K / f2.cos() - zs * f2.tan();
+    Ok((if g[2] > 0.0 { -gamma } else { gamma }, l1, l2))
+}
+
+// Distance of the Moon from the centre of the Earth's shadow, the penumbra
+// and umbra radii and the Moon's semi-diameter (radians).
+fn earth_shadow<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    tt1: f64,
+    tt2: f64,
+) -> ErfaResult<(f64, f64, f64, f64)> {
+    let (um, dm) = apparent_geocentric(eph, Body::Moon, tt1, tt2)?;
+    let (us, ds) = apparent_geocentric(eph, Body::Sun, tt1, tt2)?;
+    let d = eraSepp_safe(&um, &[-us[0], -us[1], -us[2]])?;
+    let (dm, ds) = (dm * ERFA_DAU, ds * ERFA_DAU);
+    let pm = (EARTH_RADIUS / dm).asin();
+    let ps = (EARTH_RADIUS / ds).asin();
+    let ss = (SUN_RADIUS_ECLIPSE / ds).asin();
+    let sm = (MOON_K * EARTH_RADIUS / dm).asin();
+    let r = DANJON * RADIUS_45 * pm + ps;
+    Ok((d, r + ss, r - ss, sm))
+}
+
+fn topocentric<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    body: Body,
+    site: &Site,
+    tt1: f64,
+    tt2: f64,
+) -> ErfaResult<BodyPlace> {
+    let (tai1, tai2) = eraTttai_safe(tt1, tt2)?;
+    let ((utc1, utc2), _) = eraTaiutc_safe(tai1, tai2)?;
+    let s = site;
+    let (p, _) = place_with(
+        eph, body, utc1, utc2, s.dut1, s.elong, s.phi, s.hm, s.xp, s.yp, 0.0, 0.0, 0.0, 0.0,
+    )?;
+    Ok(p)
+}
+
+// Least value of f in [a, b]: the smallest sample, then golden section.
+fn minimize<F: Fn(f64) -> ErfaResult<f64>>(f: &F, a: f64, b: f64) -> ErfaResult<(f64, f64)> {
+    let n = ((b - a) / STEP).ceil().max(2.0) as usize;
+    let step = (b - a) / n as f64;
+    let mut best = (a, f(a)?);
+    for k in 1..=n {
+        let t = a + k as f64 * step;
+        let v = f(t)?;
+        if v < best.1 {
+            best = (t, v);
+        }
+    }
+    let r = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut lo, mut hi) = ((best.0 - step).max(a), (best.0 + step).min(b));
+    let mut x1 = hi - r * (hi - lo);
+    let mut x2 = lo + r * (hi - lo);
+    let (mut f1, mut f2) = (f(x1)?, f(x2)?);
+    while hi - lo > TOLERANCE {
+        if f1 < f2 {
+            hi = x2;
+            (x2, f2) = (x1, f1);
+            x1 = hi - r * (hi - lo);
+            f1 = f(x1)?;
+        } else {
+            lo = x1;
+            (x1, f1) = (x2, f2);
+            x2 = lo + r * (hi - lo);
+            f2 = f(x2)?;
+        }
+    }
+    let t = 0.5 * (lo + hi);
+    Ok((t, f(t)?))
+}
+
+// Where g, negative at t, crosses zero before and after it within WINDOW.
+fn contact_pair<G: Fn(f64) -> ErfaResult<f64>>(
+    g: &G,
+    t: f64,
+) -> ErfaResult<(Option<f64>, Option<f64>)> {
+    let gt = g(t)?;
+    if gt >= 0.0 {
+        return Ok((None, None));
+    }
+    let (a, b) = (t - WINDOW, t + WINDOW);
+    let (ga, gb) = (g(a)?, g(b)?);
+    let before = if ga > 0.0 {
+        Some(refine(g, a, ga, t, gt)?)
+    } else {
+        None
+    };
+    let after = if gb > 0.0 {
+        Some(refine(g, t, gt, b, gb)?)
+    } else {
+        None
+    };
+    Ok((before, after))
+}
@@ -0,0 +1,368 @@
+// eop
+//   IERS Earth orientation parameters, read from local files.
+//
+//   EopTable::parse_finals2000a → finals2000A.all / finals2000A.daily
+//                                 (Bulletin A columns)
+//   EopTable::parse_c04         → EOP 14 C04 and EOP 20 C04 series
+//   EopTable::at                → UT1−UTC, polar motion and dX/dY at a UTC
+//   apco13 … atoi13, c2t        → the *13 routines with the EOP filled in
+//
+//   UT1−UTC is interpolated as UT1−TAI so leap-second steps do not disturb
+//   it; all values use 4-point Lagrange interpolation over the tabulation.
+
+use std::fs;
+use std::path::Path;
+
+use crate::G14_safe::LeapSecondTable;
+use crate::G19_safe::eraJd2cal_safe;
+use crate::G2_safe::eraApco13_safe;
+use crate::G32_safe::eraTaitt_safe;
+use crate::G33_safe::{eraUtctai_safe, eraUtcut1_safe};
+use crate::G34_safe::eraXy06_safe;
+use crate::G3_safe::eraApio13_safe;
+use crate::G4_safe::{eraAtco13_safe, eraAtio13_safe};
+use crate::G5_safe::{eraAtoc13_safe, eraAtoi13_safe};
+use crate::G8_safe::eraC2txy_safe;
+use crate::G9_safe::eraDatTable_safe;
+use crate::H1_safe::{eraASTROM, DatStatus, ErfaError, ERFA_DAS2R, ERFA_DJM0, ERFA_DMAS2R};
+
+pub use crate::H1_safe::ErfaResult;
+
+// One tabulated epoch.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EopRecord {
+    pub mjd: f64,        // UTC
+    pub dut1: f64,       // UT1−UTC (s)
+    pub xp: f64,         // polar motion x (radians)
+    pub yp: f64,         // polar motion y (radians)
+    pub dx: f64,         // CIP offset dX (radians), 0 where not given
+    pub dy: f64,         // CIP offset dY (radians), 0 where not given
+    pub predicted: bool, // Bulletin A prediction rather than observed
+}
+
+// EOP interpolated to one date; units as EopRecord.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EopValues {
+    pub dut1: f64,
+    pub xp: f64,
+    pub yp: f64,
+    pub dx: f64,
+    pub dy: f64,
+    pub predicted: bool, // any record used was a prediction
+}
+
+// Daily (or finer) EOP series in increasing MJD order.
+#[derive(Clone, Debug, Default)]
+pub struct EopTable {
+    pub records: Vec<EopRecord>,
+}
+
+impl EopTable {
+    // Records must be non-empty and strictly increasing in MJD.
+    pub fn new(records: Vec<EopRecord>) -> ErfaResult<Self> {
+        if records.is_empty() || records.windows(2).any(|p| p[1].mjd <= p[0].mjd) {
+            return Err(ErfaError::EopFileSyntax);
+        }
+        Ok(EopTable { records })
+    }
+
+    // Parse finals2000A (fixed columns).  Rows past the end of the UT1 and
+    // polar-motion predictions are skipped; missing dX/dY are taken as 0.
+    pub fn parse_finals2000a(text: &str) -> ErfaResult<Self> {
+        let mut records = Vec::new();
+        for line in text.lines() {
+            let col = |a: usize, b: usize| line.get(a..b).map(str::trim).unwrap_or("");
+            if col(18, 27).is_empty() || col(37, 46).is_empty() || col(58, 68).is_empty() {
+                continue;
+            }
+            let (dx, dy) = match (col(97, 106), col(116, 125)) {
+                ("", _) | (_, "") => (0.0, 0.0),
+                (dx, dy) => (number(dx)? * ERFA_DMAS2R, number(dy)? * ERFA_DMAS2R),
+            };
+            records.push(EopRecord {
+                mjd: number(col(7, 15))?,
+                dut1: number(col(58, 68))?,
+                xp: number(col(18, 27))? * ERFA_DAS2R,
+                yp: number(col(37, 46))? * ERFA_DAS2R,
+                dx,
+                dy,
+                predicted: col(16, 17) == "P" || col(57, 58) == "P",
+            });
+        }
+        EopTable::new(records)
+    }
+
+    // Parse an EOP C04 series.  Both the 14 C04 layout (y m d MJD x y
+    // UT1−UTC LOD dX dY …) and the 20 C04 layout (y m d h MJD x y UT1−UTC
+    // dX dY …) are accepted; text header lines are skipped.
+    pub fn parse_c04(text: &str) -> ErfaResult<Self> {
+        let mut records = Vec::new();
+        for line in text.lines() {
+            let data = line.trim();
+            let first = data.split_whitespace().next().unwrap_or("");
+            if data.starts_with('#') || first.parse::<i32>().is_err() {
+                continue;
+            }
+            let f = data
+                .split_whitespace()
+                .map(number)
+                .collect::<ErfaResult<Vec<f64>>>()?;
+            if f.len() < 10 {
+                return Err(ErfaError::EopFileSyntax);
+            }
+            // 20 C04 has an hour column where 14 C04 has the MJD.
+            let (mjd, x, y, dut1, dx, dy) = if f[3] < 24.0 {
+                (f[4], f[5], f[6], f[7], f[8], f[9])
+            } else {
+                (f[3], f[4], f[5], f[6], f[8], f[9])
+            };
+            records.push(EopRecord {
+                mjd,
+                dut1,
+                xp: x * ERFA_DAS2R,
+                yp: y * ERFA_DAS2R,
+                dx: dx * ERFA_DAS2R,
+                dy: dy * ERFA_DAS2R,
+                predicted: false,
+            });
+        }
+        EopTable::new(records)
+    }
+
+    pub fn load_finals2000a<P: AsRef<Path>>(path: P) -> ErfaResult<Self> {
+        let text = fs::read_to_string(path).map_err(|_| ErfaError::FileRead)?;
+        EopTable::parse_finals2000a(&text)
+    }
+
+    pub fn load_c04<P: AsRef<Path>>(path: P) -> ErfaResult<Self> {
+        let text = fs::read_to_string(path).map_err(|_| ErfaError::FileRead)?;
+        EopTable::parse_c04(&text)
+    }
+
+    // EOP at a UTC quasi-JD, using the process-wide leap-second table.
+    pub fn at(&self, utc1: f64, utc2: f64) -> ErfaResult<EopValues> {
+        self.at_in(&LeapSecondTable::current()?, utc1, utc2)
+    }
+
+    // As at, with ΔAT from the given leap-second table.
+    pub fn at_in(&self, leap: &LeapSecondTable, utc1: f64, utc2: f64) -> ErfaResult<EopValues> {
+        let recs = &self.records;
+        let mjd = (utc1 - ERFA_DJM0) + utc2;
+        match (recs.first(), recs.last()) {
+            (Some(a), Some(b)) if mjd >= a.mjd && mjd <= b.mjd => {}
+            _ => return Err(ErfaError::EopOutOfRange),
+        }
+
+        // Four records around the date, fewer if the table is shorter.
+        let i = recs.partition_point(|r| r.mjd <= mjd).saturating_sub(1);
+        let hi = (i.saturating_sub(1) + 4).min(recs.len());
+        let pts = &recs[hi.saturating_sub(4)..hi];
+
+        let t: Vec<f64> = pts.iter().map(|r| r.mjd).collect();
+        let ut1tai = pts
+            .iter()
+            .map(|r| Ok(r.dut1 - dat_at(leap, r.mjd)?))
+            .collect::<ErfaResult<Vec<f64>>>()?;
+        let interp = |f: fn(&EopRecord) -> f64| {
+            let y: Vec<f64> = pts.iter().map(f).collect();
+            lagrange(&t, &y, mjd)
+        };
+
+        Ok(EopValues {
+            dut1: lagrange(&t, &ut1tai, mjd) + dat_at(leap, mjd)?,
+            xp: interp(|r| r.xp),
+            yp: interp(|r| r.yp),
+            dx: interp(|r| r.dx),
+            dy: interp(|r| r.dy),
+            predicted: pts.iter().any(|r| r.predicted),
+        })
+    }
+
+    // eraApco13_safe with dut1, xp and yp from the table.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apco13(
+        &self,
+        utc1: f64,
+        utc2: f64,
+        elong: f64,
+        phi: f64,
+        hm: f64,
+        phpa: f64,
+        tc: f64,
+        rh: f64,
+        wl: f64,
+        astrom: &mut eraASTROM,
+    ) -> ErfaResult<(f64, DatStatus)> {
+        let e = self.at(utc1, utc2)?;
+        eraApco13_safe(
+            utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh, wl, astrom,
+        )
+    }
+
+    // eraApio13_safe with dut1, xp and yp from the table.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apio13(
+        &self,
+        utc1: f64,
+        utc2: f64,
+        elong: f64,
+        phi: f64,
+        hm: f64,
+        phpa: f64,
+        tc: f64,
+        rh: f64,
+        wl: f64,
+        astrom: &mut eraASTROM,
+    ) -> ErfaResult<DatStatus> {
+        let e = self.at(utc1, utc2)?;
+        eraApio13_safe(
+            utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh, wl, astrom,
+        )
+    }
+
+    // eraAtco13_safe with dut1, xp and yp from the table.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub fn atco13(
+        &self,
+        rc: f64,
+        dc: f64,
+        pr: f64,
+        pd: f64,
+        px: f64,
+        rv: f64,
+        utc1: f64,
+        utc2: f64,
+        elong: f64,
+        phi: f64,
+        hm: f64,
+        phpa: f64,
+        tc: f64,
+        rh: f64,
+        wl: f64,
+    ) -> ErfaResult<(f64, f64, f64, f64, f64, f64, DatStatus)> {
+        let e = self.at(utc1, utc2)?;
+        eraAtco13_safe(
+            rc, dc, pr, pd, px, rv, utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh,
+            wl,
+        )
+    }
+
+    // eraAtio13_safe with dut1, xp and yp from the table.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub fn atio13(
+        &self,
+        ri: f64,
+        di: f64,
+        utc1: f64,
+        utc2: f64,
+        elong: f64,
+        phi: f64,
+        hm: f64,
+        phpa: f64,
+        tc: f64,
+        rh: f64,
+        wl: f64,
+    ) -> ErfaResult<(f64, f64, f64, f64, f64, DatStatus)> {
+        let e = self.at(utc1, utc2)?;
+        eraAtio13_safe(
+            ri, di, utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh, wl,
+        )
+    }
+
+    // eraAtoc13_safe with dut1, xp and yp from the table.
+    #[allow(clippy::too_many_arguments)]
+    pub fn atoc13(
+        &self,
+        type_: &str,
+        ob1: f64,
+        ob2: f64,
+        utc1: f64,
+        utc2: f64,
+        elong: f64,
+        phi: f64,
+        hm: f64,
+        phpa: f64,
+        tc: f64,
+        rh: f64,
+        wl: f64,
+    ) -> ErfaResult<(f64, f64, f64, DatStatus)> {
+        let e = self.at(utc1, utc2)?;
+        eraAtoc13_safe(
+            type_, ob1, ob2, utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh, wl,
+        )
+    }
+
+    // eraAtoi13_safe with dut1, xp and yp from the table.
+    #[allow(clippy::too_many_arguments)]
+    pub fn atoi13(
+        &self,
+        type_: &str,
+        ob1: f64,
+        ob2: f64,
+        utc1: f64,
+        utc2: f64,
+        elong: f64,
+        phi: f64,
+        hm: f64,
+        phpa: f64,
+        tc: f64,
+        rh: f64,
+        wl: f64,
+    ) -> ErfaResult<(f64, f64, DatStatus)> {
+        let e = self.at(utc1, utc2)?;
+        eraAtoi13_safe(
+            type_, ob1, ob2, utc1, utc2, e.dut1, elong, phi, hm, e.xp, e.yp, phpa, tc, rh, wl,
+        )
+    }
+
+    // Celestial-to-terrestrial matrix at a UTC (IAU 2006/2000A, CIO based),
+    // with the observed dX/dY added to the model CIP.
+    pub fn c2t(&self, utc1: f64, utc2: f64) -> ErfaResult<[[f64; 3]; 3]> {
+        let e = self.at(utc1, utc2)?;
+        let ((tai1, tai2), _) = eraUtctai_safe(utc1, utc2)?;
+        let (tt1, tt2) = eraTaitt_safe(tai1, tai2)?;
+        let ((ut11, ut12), _) = eraUtcut1_safe(utc1, utc2, e.dut1)?;
+        let (x, y) = eraXy06_safe(tt1, tt2)?;
+        let mut rc2t = [[0.0; 3]; 3];
+        eraC2txy_safe(
+            tt1,
+            tt2,
+            ut11,
+            ut12,
+            x + e.dx,
+            y + e.dy,
+            e.xp,
+            e.yp,
+            &mut rc2t,
+        )?;
+        Ok(rc2t)
+    }
+}
+
+fn number(s: &str) -> ErfaResult<f64> {
+    s.trim()
+        .parse::<f64>()
+        .map_err(|_| ErfaError::EopFileSyntax)
+}
+
+// TAI−UTC (s) at a UTC MJD.
+fn dat_at(leap: &LeapSecondTable, mjd: f64) -> ErfaResult<f64> {
+    let ((iy, im, id), fd) = eraJd2cal_safe(ERFA_DJM0, mjd)?;
+    let (dat, _) = eraDatTable_safe(leap, iy, im, id, fd)?;
+    Ok(dat)
+}
+
+// Lagrange polynomial through (t[k], y[k]) evaluated at x.
+fn lagrange(t: &[f64], y: &[f64], x: f64) -> f64 {
+    let mut sum = 0.0;
+    for (k, (&tk, &yk)) in t.iter().zip(y).enumerate() {
+        let mut w = 1.0;
+        for (m, &tm) in t.iter().enumerate() {
+            if m != k {
+                w *= (x - tm) / (tk - tm);
+            }
+        }
+        sum += w * yk;
+    }
+    sum
+}
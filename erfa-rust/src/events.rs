@@ -0,0 +1,338 @@
+// events
+//   Rise, set and transit times of stars, the Sun, Moon and planets.
+//
+//   Site                 → longitude, latitude, height, ΔUT1, polar motion
+//   Target               → catalogue star (as eraAtco13) or solar-system body
+//   Horizon              → Standard (−34′ refraction, less the Sun's or
+//                          Moon's semi-diameter) or a fixed geometric altitude
+//   search / search_with → events in a UTC window, in time order
+//
+//   Elevations are geometric (unrefracted) and topocentric, from eraAtco13
+//   for stars and bodies::place for the rest, so the Moon's parallax is in
+//   the positions and only its semi-diameter is in the horizon.  The window
+//   is sampled every half hour and each sign change of elevation − horizon
+//   (rise, set) or of hour angle (transit, lower transit) is refined by
+//   regula falsi; grazing events shorter than a sample can be missed.
+//   Times are UTC two-part JDs, with the first part of the window's start.
+
+use crate::bodies::{place_with, Body, BodyEphemeris};
+use crate::G11_safe::Ellipsoid;
+use crate::G13_safe::Epv00;
+use crate::G17_safe::{eraGc2gdEll_safe, eraGd2gcEll_safe};
+use crate::G4_safe::eraAtco13_safe;
+use crate::H1_safe::{ERFA_D2PI, ERFA_DAS2R, ERFA_DAU, ERFA_DPI};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Sampling step and root tolerance (days).
+const STEP: f64 = 1.0 / 48.0;
+const TOLERANCE: f64 = 1e-8;
+
+// Standard refraction at the horizon, and mean radii (m).
+const HORIZON_REFRACTION: f64 = 34.0 * 60.0 * ERFA_DAS2R;
+const SUN_RADIUS: f64 = 695_700e3;
+const MOON_RADIUS: f64 = 1_737_400.0;
+
+// An observing site, with the Earth orientation eraAtco13 needs.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Site {
+    pub elong: f64, // east longitude (radians)
+    pub phi: f64,   // geodetic latitude (radians)
+    pub hm: f64,    // height above the ellipsoid (m)
+    pub dut1: f64,  // UT1−UTC (s)
+    pub xp: f64,    // polar motion (radians)
+    pub yp: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Target {
+    // ICRS RA/Dec, proper motion (RA rate is dRA/dt), parallax (arcsec)
+    // and radial velocity (km/s), as for eraAtco13.
+    Star {
+        rc: f64,
+        dc: f64,
+        pr: f64,
+        pd: f64,
+        px: f64,
+        rv: f64,
+    },
+    Body(Body),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Horizon {
+    // Upper limb on the refracted horizon: −34′, less the semi-diameter
+    // for the Sun and Moon (−0°50′ for the Sun at 1 au).
+    #[default]
+    Standard,
+    // Centre at this geometric elevation (radians).
+    Altitude(f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    Rise,
+    Set,
+    Transit,
+    LowerTransit,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Event {
+    pub kind: EventKind,
+    pub utc1: f64,
+    pub utc2: f64,
+    pub az: f64, // geometric azimuth at the event (radians)
+    pub el: f64, // geometric elevation at the event (radians)
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventSearch {
+    pub events: Vec<Event>,
+    // With no rise or set in the window: up, or down, throughout.
+    pub always_up: bool,
+    pub always_down: bool,
+}
+
+// Geometric topocentric place of a target, hour angle in (−π, π], and
+// its angular semi-diameter (0 for stars and planets).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Topocentric {
+    pub az: f64,
+    pub el: f64,
+    pub ha: f64,
+    pub sd: f64,
+}
+
+impl Topocentric {
+    // Height of the centre above the horizon.
+    pub fn above(&self, horizon: Horizon) -> f64 {
+        self.el
+            - match horizon {
+                Horizon::Standard => -HORIZON_REFRACTION - self.sd,
+                Horizon::Altitude(h) => h,
+            }
+    }
+
+    // Hour angle from the lower meridian, in (−π, π].
+    pub fn lower_ha(&self) -> f64 {
+        wrap(self.ha + ERFA_DPI)
+    }
+}
+
+impl Site {
+    // Site from geodetic coordinates on any ellipsoid, built-in, named or
+    // registered, re-expressed on WGS84 as eraPvtob expects.
+    pub fn on_ellipsoid(
+        ellipsoid: &Ellipsoid,
+        elong: f64,
+        phi: f64,
+        height: f64,
+    ) -> ErfaResult<Site> {
+        let xyz = eraGd2gcEll_safe(ellipsoid, elong, phi, height)?;
+        let (elong, phi, hm) = eraGc2gdEll_safe(&Ellipsoid::WGS84, &xyz)?;
+        Ok(Site {
+            elong,
+            phi,
+            hm,
+            ..Site::default()
+        })
+    }
+}
+
+// Events for a target over `days` from the UTC (utc1, utc2), with the
+// built-in ephemerides.
+pub fn search(
+    target: Target,
+    site: &Site,
+    horizon: Horizon,
+    utc1: f64,
+    utc2: f64,
+    days: f64,
+) -> ErfaResult<EventSearch> {
+    search_with(&Epv00, target, site, horizon, utc1, utc2, days)
+}
+
+// As search, with Earth and body positions from the given ephemeris.
+pub fn search_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    target: Target,
+    site: &Site,
+    horizon: Horizon,
+    utc1: f64,
+    utc2: f64,
+    days: f64,
+) -> ErfaResult<EventSearch> {
+    let f = |t: f64| topocentric(eph, target, site, utc1, t);
+    let samples = sample(&f, utc2, days)?;
+    let above = |p: &Topocentric| p.above(horizon);
+
+    // Hour angles only increase, so their crossings are all transits.
+    let mut found = Vec::new();
+    for (t, rising) in crossings(&f, &samples, &above)? {
+        let kind = if rising {
+            EventKind::Rise
+        } else {
+            EventKind::Set
+        };
+        found.push((kind, t));
+    }
+    for (t, _) in crossings(&f, &samples, &|p: &Topocentric| p.ha)? {
+        found.push((EventKind::Transit, t));
+    }
+    for (t, _) in crossings(&f, &samples, &|p: &Topocentric| p.lower_ha())? {
+        found.push((EventKind::LowerTransit, t));
+    }
+
+    let mut out = EventSearch::default();
+    for (kind, t) in found {
+        let p = f(t)?;
+        out.events.push(Event {
+            kind,
+            utc1,
+            utc2: t,
+            az: p.az,
+            el: p.el,
+        });
+    }
+    out.events.sort_by(|a, b| {
+        a.utc2
+            .partial_cmp(&b.utc2)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let crossed = out
+        .events
+        .iter()
+        .any(|e| matches!(e.kind, EventKind::Rise | EventKind::Set));
+    out.always_up = !crossed && samples.iter().all(|(_, p)| above(p) > 0.0);
+    out.always_down = !crossed && samples.iter().all(|(_, p)| above(p) <= 0.0);
+    Ok(out)
+}
+
+// Samples every STEP or less over `days` from utc2, both ends included.
+pub(crate) fn sample<F: Fn(f64) -> ErfaResult<Topocentric>>(
+    f: &F,
+    utc2: f64,
+    days: f64,
+) -> ErfaResult<Vec<(f64, Topocentric)>> {
+    let n = (days / STEP).ceil().max(1.0) as usize;
+    let step = days / n as f64;
+    (0..=n)
+        .map(|k| {
+            let t = utc2 + k as f64 * step;
+            Ok((t, f(t)?))
+        })
+        .collect()
+}
+
+// Times where value() changes sign between samples, refined, with whether
+// it is rising.  Jumps of more than π (hour-angle wraps) are skipped.
+pub(crate) fn crossings<F, V>(
+    f: &F,
+    samples: &[(f64, Topocentric)],
+    value: &V,
+) -> ErfaResult<Vec<(f64, bool)>>
+where
+    F: Fn(f64) -> ErfaResult<Topocentric>,
+    V: Fn(&Topocentric) -> f64 + ?Sized,
+{
+    let mut out = Vec::new();
+    for w in samples.windows(2) {
+        let ((t0, p0), (t1, p1)) = (w[0], w[1]);
+        let (v0, v1) = (value(&p0), value(&p1));
+        if (v0 > 0.0) == (v1 > 0.0) || (v1 - v0).abs() > ERFA_DPI {
+            continue;
+        }
+        let t = refine(|t| Ok(value(&f(t)?)), t0, v0, t1, v1)?;
+        out.push((t, v1 > 0.0));
+    }
+    Ok(out)
+}
+
+// Root of g in [t0, t1], where g changes sign (Illinois regula falsi).
+pub(crate) fn refine<G: Fn(f64) -> ErfaResult<f64>>(
+    g: G,
+    mut t0: f64,
+    mut g0: f64,
+    mut t1: f64,
+    mut g1: f64,
+) -> ErfaResult<f64> {
+    let mut side = 0;
+    for _ in 0..60 {
+        if t1 - t0 <= TOLERANCE {
+            break;
+        }
+        let t = (t0 * g1 - t1 * g0) / (g1 - g0);
+        let gt = g(t)?;
+        if gt == 0.0 {
+            return Ok(t);
+        }
+        if (gt > 0.0) == (g1 > 0.0) {
+            t1 = t;
+            g1 = gt;
+            if side == -1 {
+                g0 *= 0.5;
+            }
+            side = -1;
+        } else {
+            t0 = t;
+            g0 = gt;
+            if side == 1 {
+                g1 *= 0.5;
+            }
+            side = 1;
+        }
+        if (g1 - g0).abs() < f64::MIN_POSITIVE {
+            break;
+        }
+    }
+    Ok((t0 * g1 - t1 * g0) / (g1 - g0))
+}
+
+pub(crate) fn topocentric<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    target: Target,
+    site: &Site,
+    utc1: f64,
+    utc2: f64,
+) -> ErfaResult<Topocentric> {
+    let s = site;
+    let (az, el, ha, sd) = match target {
+        Target::Star {
+            rc,
+            dc,
+            pr,
+            pd,
+            px,
+            rv,
+        } => {
+            let (aob, zob, hob, _, _, _, _) = eraAtco13_safe(
+                rc, dc, pr, pd, px, rv, utc1, utc2, s.dut1, s.elong, s.phi, s.hm, s.xp, s.yp, 0.0,
+                0.0, 0.0, 0.0,
+            )?;
+            (aob, ERFA_DPI / 2.0 - zob, hob, 0.0)
+        }
+        Target::Body(body) => {
+            let (p, _) = place_with(
+                eph, body, utc1, utc2, s.dut1, s.elong, s.phi, s.hm, s.xp, s.yp, 0.0, 0.0, 0.0, 0.0,
+            )?;
+            let r = match body {
+                Body::Sun => SUN_RADIUS,
+                Body::Moon => MOON_RADIUS,
+                _ => 0.0,
+            };
+            (p.az, p.el, p.ha, (r / (p.distance * ERFA_DAU)).asin())
+        }
+    };
+    Ok(Topocentric {
+        az,
+        el,
+        ha: wrap(ha),
+        sd,
+    })
+}
+
+// Angle to (−π, π].
+pub(crate) fn wrap(a: f64) -> f64 {
+    a - ERFA_D2PI * (a / ERFA_D2PI).round()
+}
@@ -36,3 +36,16 @@ pub mod G32_safe;
 pub mod G33_safe;
 pub mod G34_safe;
 pub mod G35_safe;
+pub mod almanac;
+pub mod bodies;
+pub mod dual;
+pub mod eclipses;
+pub mod eop;
+pub mod events;
+pub mod phenomena;
+pub mod refraction;
+pub mod sexagesimal;
+pub mod sgp4;
+pub mod spk;
+pub mod tangent;
+pub mod time;
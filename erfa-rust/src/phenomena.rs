@@ -0,0 +1,233 @@
+// phenomena
+//   Equinoxes, solstices and the phases of the Moon.
+//
+//   Season                         → March equinox … December solstice
+//   MoonPhase                      → New, FirstQuarter, Full, LastQuarter
+//   apparent_ecliptic              → geocentric apparent ecliptic longitude
+//                                    and latitude of date of a body
+//   seasons / seasons_with         → equinoxes and solstices in a TT window
+//   moon_phases / moon_phases_with → phases in a TT window
+//
+//   An equinox or solstice is when the Sun's apparent longitude is a
+//   multiple of 90°; a phase is when the Moon's apparent longitude exceeds
+//   the Sun's by a multiple of 90°.  Longitudes are geocentric, corrected
+//   for light time and aberration (eraAb), referred to the ecliptic of date
+//   by eraEqec06 and to the true equinox by the nutation in longitude
+//   (eraNut06a).  The window is sampled daily and each crossing refined by
+//   regula falsi to 1e-8 d.  With the built-in ephemerides (eraEpv00 and
+//   eraMoon98) over 1900–2100 the times are good to about 1 s for the
+//   seasons and 1 min for the phases, set by eraMoon98's ~20″ in longitude;
+//   a DE kernel brings both to the level of the root tolerance.  Times are
+//   TT two-part JDs, with the first part of the window's start; TDB−TT
+//   (under 2 ms) is ignored.
+
+use crate::bodies::{Body, BodyEphemeris};
+use crate::events::{refine, wrap};
+use crate::G13_safe::Epv00;
+use crate::G14_safe::eraEqec06_safe;
+use crate::G1_safe::{eraAb_safe, eraAnp_safe};
+use crate::G23_safe::eraNut06a_safe;
+use crate::G25_safe::eraPn_safe;
+use crate::G7_safe::eraC2s_safe;
+use crate::H1_safe::{ERFA_AULT, ERFA_DAYSEC, ERFA_DPI};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Sampling step (days): the Moon's elongation moves 12°–15° a day.
+const STEP: f64 = 1.0;
+
+// Light-time iterations, as in bodies::place_astrom.
+const LIGHT_TIME_ITERATIONS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Season {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [
+        Season::MarchEquinox,
+        Season::JuneSolstice,
+        Season::SeptemberEquinox,
+        Season::DecemberSolstice,
+    ];
+
+    // Apparent longitude of the Sun (radians).
+    pub fn longitude(self) -> f64 {
+        self as i32 as f64 * ERFA_DPI / 2.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    New,
+    FirstQuarter,
+    Full,
+    LastQuarter,
+}
+
+impl MoonPhase {
+    pub const ALL: [MoonPhase; 4] = [
+        MoonPhase::New,
+        MoonPhase::FirstQuarter,
+        MoonPhase::Full,
+        MoonPhase::LastQuarter,
+    ];
+
+    // Moon − Sun apparent longitude (radians).
+    pub fn elongation(self) -> f64 {
+        self as i32 as f64 * ERFA_DPI / 2.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeasonEvent {
+    pub season: Season,
+    pub tt1: f64,
+    pub tt2: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhaseEvent {
+    pub phase: MoonPhase,
+    pub tt1: f64,
+    pub tt2: f64,
+}
+
+// Geocentric apparent ecliptic longitude (0–2π) and latitude of date of a
+// body at a TT date, true equinox.
+pub fn apparent_ecliptic<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    body: Body,
+    tt1: f64,
+    tt2: f64,
+) -> ErfaResult<(f64, f64)> {
+    let (ppr, _) = apparent_geocentric(eph, body, tt1, tt2)?;
+
+    // Ecliptic of date, then the true equinox.
+    let (ra, dec) = eraC2s_safe(&ppr)?;
+    let (el, eb) = eraEqec06_safe(tt1, tt2, ra, dec)?;
+    let (dpsi, _) = eraNut06a_safe(tt1, tt2)?;
+    Ok((eraAnp_safe(el + dpsi)?, eb))
+}
+
+// Geocentric apparent direction (GCRS unit vector) of a body at a TT date,
+// and its distance at emission (au).
+pub(crate) fn apparent_geocentric<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    body: Body,
+    tt1: f64,
+    tt2: f64,
+) -> ErfaResult<([f64; 3], f64)> {
+    let (_, pvb) = eph.earth_pv(tt1, tt2)?;
+
+    // Earth → body at emission, iterating the light time.
+    let mut p = [0.0; 3];
+    let mut tau = 0.0;
+    for _ in 0..LIGHT_TIME_ITERATIONS {
+        let pv = eph.body_pv(body, tt1, tt2 - tau)?;
+        for i in 0..3 {
+            p[i] = pv[0][i] - pvb[0][i];
+        }
+        tau = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt() * ERFA_AULT / ERFA_DAYSEC;
+    }
+    let (distance, u) = eraPn_safe(&p)?;
+
+    // Aberration from the Earth's barycentric velocity (units of c).
+    let sun = eph.body_pv(Body::Sun, tt1, tt2)?;
+    let mut v = [0.0; 3];
+    let mut sh = [0.0; 3];
+    for i in 0..3 {
+        v[i] = pvb[1][i] * ERFA_AULT / ERFA_DAYSEC;
+        sh[i] = pvb[0][i] - sun[0][i];
+    }
+    let bm1 = (1.0 - (v[0] * v[0] + v[1] * v[1] + v[2] * v[2])).sqrt();
+    let (s, _) = eraPn_safe(&sh)?;
+    Ok((eraAb_safe(&u, &v, s, bm1)?, distance))
+}
+
+// Equinoxes and solstices over `days` from the TT (tt1, tt2), with the
+// built-in ephemerides.
+pub fn seasons(tt1: f64, tt2: f64, days: f64) -> ErfaResult<Vec<SeasonEvent>> {
+    seasons_with(&Epv00, tt1, tt2, days)
+}
+
+// As seasons, with Earth and Sun positions from the given ephemeris.
+pub fn seasons_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    tt1: f64,
+    tt2: f64,
+    days: f64,
+) -> ErfaResult<Vec<SeasonEvent>> {
+    let f = |t: f64| Ok(apparent_ecliptic(eph, Body::Sun, tt1, t)?.0);
+    let targets = Season::ALL.map(|s| s.longitude());
+    Ok(search(&f, &targets, tt2, days)?
+        .into_iter()
+        .map(|(k, t)| SeasonEvent {
+            season: Season::ALL[k],
+            tt1,
+            tt2: t,
+        })
+        .collect())
+}
+
+// Moon phases over `days` from the TT (tt1, tt2), with the built-in
+// ephemerides.
+pub fn moon_phases(tt1: f64, tt2: f64, days: f64) -> ErfaResult<Vec<PhaseEvent>> {
+    moon_phases_with(&Epv00, tt1, tt2, days)
+}
+
+// As moon_phases, with Earth, Sun and Moon positions from the given
+// ephemeris.
+pub fn moon_phases_with<E: BodyEphemeris + ?Sized>(
+    eph: &E,
+    tt1: f64,
+    tt2: f64,
+    days: f64,
+) -> ErfaResult<Vec<PhaseEvent>> {
+    let f = |t: f64| {
+        let (lm, _) = apparent_ecliptic(eph, Body::Moon, tt1, t)?;
+        let (ls, _) = apparent_ecliptic(eph, Body::Sun, tt1, t)?;
+        eraAnp_safe(lm - ls)
+    };
+    let targets = MoonPhase::ALL.map(|p| p.elongation());
+    Ok(search(&f, &targets, tt2, days)?
+        .into_iter()
+        .map(|(k, t)| PhaseEvent {
+            phase: MoonPhase::ALL[k],
+            tt1,
+            tt2: t,
+        })
+        .collect())
+}
+
+// Times in [t2, t2 + days] at which the increasing angle f passes each
+// target, in time order, with the target's index.
+fn search<F: Fn(f64) -> ErfaResult<f64>>(
+    f: &F,
+    targets: &[f64],
+    t2: f64,
+    days: f64,
+) -> ErfaResult<Vec<(usize, f64)>> {
+    let n = (days / STEP).ceil().max(1.0) as usize;
+    let step = days / n as f64;
+    let mut out = Vec::new();
+    let (mut t0, mut a0) = (t2, f(t2)?);
+    for k in 1..=n {
+        let t1 = t2 + k as f64 * step;
+        let a1 = f(t1)?;
+        for (j, &target) in targets.iter().enumerate() {
+            let (g0, g1) = (wrap(a0 - target), wrap(a1 - target));
+            if g0 <= 0.0 && g1 > 0.0 && g1 - g0 < ERFA_DPI {
+                let g = |t: f64| Ok(wrap(f(t)? - target));
+                out.push((j, refine(g, t0, g0, t1, g1)?));
+            }
+        }
+        (t0, a0) = (t1, a1);
+    }
+    out.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(out)
+}
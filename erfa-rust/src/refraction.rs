@@ -0,0 +1,340 @@
+// refraction
+//   Refraction models for eraAtioqRef_safe and eraAtoiqRef_safe, for use
+//   where eraRefco's A tan z + B tan³ z fails, below about 15° elevation.
+//
+//   RayTrace → numerical integration through a model atmosphere
+//              (Hohenkerk & Sinclair), optical or radio
+//   Bennett  → Bennett's formula, scaled for pressure and temperature,
+//              inverted from Saemundsson's
+//   ItuR     → ITU-R P.834 radio ray bending for a station height
+//
+//   Each implements Refraction in both directions.  RayTrace follows
+//   Hohenkerk & Sinclair (NAO Technical Note 63, 1985) as in SLALIB's
+//   sla_REFRO: a polytropic troposphere to 11 km and an isothermal
+//   stratosphere to 80 km, integrated by Simpson's rule; its topocentric
+//   direction is the observed one inverted by secant iteration.  Bennett's
+//   formula is good to about 0.07′ down to the horizon; Saemundsson's, its
+//   approximate inverse, differs from it by up to 1′ there and at low
+//   pressure, so it only starts the iteration.  The ITU-R formula is for
+//   elevations up to 10° in the mean global atmosphere; above that it is
+//   continued as cot E from its value at 10°.  Bennett and ITU-R treat
+//   elevations below −1° as −1°.
+
+use crate::G28_safe::Refraction;
+use crate::H1_safe::{ERFA_DD2R, ERFA_DPI};
+
+// Iteration limit and tolerance (radians) for the inverse directions.
+const ITERATIONS: usize = 20;
+const TOLERANCE: f64 = 1e-12;
+
+// Lowest elevation the closed-form models are evaluated at (degrees).
+const MIN_ELEVATION: f64 = -1.0;
+
+// Ray-traced refraction through a model atmosphere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayTrace {
+    pub phpa: f64, // pressure at the observer (hPa)
+    pub tc: f64,   // temperature at the observer (°C)
+    pub rh: f64,   // relative humidity (0–1)
+    pub wl: f64,   // wavelength (µm); above 100 µm is radio
+    pub hm: f64,   // height of the observer above sea level (m)
+    pub phi: f64,  // latitude (radians)
+    pub tlr: f64,  // tropospheric lapse rate (K/m)
+    pub eps: f64,  // precision of the integration (radians)
+}
+
+impl RayTrace {
+    // Model for the given conditions, with a 0.0065 K/m lapse rate and a
+    // precision of 1e-8 radians.
+    pub fn new(phpa: f64, tc: f64, rh: f64, wl: f64, hm: f64, phi: f64) -> RayTrace {
+        RayTrace {
+            phpa,
+            tc,
+            rh,
+            wl,
+            hm,
+            phi,
+            tlr: 0.0065,
+            eps: 1e-8,
+        }
+    }
+}
+
+impl Refraction for RayTrace {
+    fn for_topocentric(&self, zt: f64) -> f64 {
+        invert(|zo| self.for_observed(zo), zt, 1.0, zt)
+    }
+
+    fn for_observed(&self, zo: f64) -> f64 {
+        refro(self, zo)
+    }
+}
+
+// Bennett's formula, scaled by (P/1010 hPa)(283 K/T).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bennett {
+    pub phpa: f64, // pressure (hPa)
+    pub tc: f64,   // temperature (°C)
+}
+
+impl Default for Bennett {
+    // The formulae's own conditions: 1010 hPa, 10 °C.
+    fn default() -> Bennett {
+        Bennett {
+            phpa: 1010.0,
+            tc: 10.0,
+        }
+    }
+}
+
+impl Bennett {
+    fn scale(&self) -> f64 {
+        (self.phpa / 1010.0) * (283.0 / (273.0 + self.tc))
+    }
+}
+
+impl Refraction for Bennett {
+    fn for_topocentric(&self, zt: f64) -> f64 {
+        // Saemundsson's formula for the first estimate.
+        let h = elevation(zt);
+        let r = 1.02 / ((h + 10.3 / (h + 5.11)) * ERFA_DD2R).tan();
+        let zo = zt - (r / 60.0 * ERFA_DD2R * self.scale()).max(0.0);
+        invert(|zo| self.for_observed(zo), zt, 1.0, zo)
+    }
+
+    fn for_observed(&self, zo: f64) -> f64 {
+        let h = elevation(zo);
+        let r = 1.0 / ((h + 7.31 / (h + 4.4)) * ERFA_DD2R).tan();
+        (r / 60.0 * ERFA_DD2R * self.scale()).max(0.0)
+    }
+}
+
+// ITU-R P.834 ray bending for radio waves.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ItuR {
+    pub hm: f64, // station height above sea level (m), 0–3000
+}
+
+impl ItuR {
+    // Bending (degrees) at a free-space elevation (degrees).
+    fn tau(&self, e: f64) -> f64 {
+        let h = (self.hm / 1000.0).clamp(0.0, 3.0);
+        let t = |e: f64| {
+            1.0 / (1.314
+                + 0.6437 * e
+                + 0.02869 * e * e
+                + h * (0.2305 + 0.09428 * e + 0.01096 * e * e)
+                + 0.008583 * h * h)
+        };
+        if e <= 10.0 {
+            t(e)
+        } else {
+            t(10.0) * (10.0 * ERFA_DD2R).tan() / (e * ERFA_DD2R).tan()
+        }
+    }
+}
+
+impl Refraction for ItuR {
+    fn for_topocentric(&self, zt: f64) -> f64 {
+        self.tau(elevation(zt)) * ERFA_DD2R
+    }
+
+    fn for_observed(&self, zo: f64) -> f64 {
+        invert(|zt| self.for_topocentric(zt), zo, -1.0, zo)
+    }
+}
+
+// Elevation (degrees) from zenith distance, floored at MIN_ELEVATION.
+fn elevation(z: f64) -> f64 {
+    (90.0 - z / ERFA_DD2R).max(MIN_ELEVATION)
+}
+
+// Refraction r(x) at the solution of x + s·r(x) = z, by secant iteration
+// from the estimate x0.
+fn invert<F: Fn(f64) -> f64>(r: F, z: f64, s: f64, x0: f64) -> f64 {
+    let g = |x: f64| x + s * r(x) - z;
+    let mut x0 = x0;
+    let mut g0 = g(x0);
+    let mut x1 = x0 - g0;
+    for _ in 0..ITERATIONS {
+        let g1 = g(x1);
+        if (x1 - x0).abs() < TOLERANCE || g1 == g0 {
+            break;
+        }
+        let x2 = x1 - g1 * (x1 - x0) / (g1 - g0);
+        (x0, g0, x1) = (x1, g1, x2);
+    }
+    r(x1)
+}
+
+// Refraction for an observed zenith distance, after sla_REFRO.
+fn refro(m: &RayTrace, zobs: f64) -> f64 {
+    // 93° in radians; gas constant, molecular weights of dry air and water
+    // vapour; mean Earth radius (m); exponent of the humidity variation;
+    // heights of the tropopause and the stratosphere limit (m); maximum
+    // number of strips.
+    const D93: f64 = 93.0 * ERFA_DPI / 180.0;
+    const GCR: f64 = 8314.32;
+    const DMD: f64 = 28.9644;
+    const DMW: f64 = 18.0152;
+    const S: f64 = 6_378_120.0;
+    const DELTA: f64 = 18.36;
+    const HT: f64 = 11_000.0;
+    const HS: f64 = 80_000.0;
+    const ISMAX: usize = 16384;
+
+    // Guard the inputs.
+    let zobs1 = zobs.clamp(-D93, D93);
+    let zobs2 = zobs1.abs().min(D93);
+    let hmok = m.hm.clamp(-1000.0, HS);
+    let tdkok = (m.tc + 273.15).clamp(100.0, 500.0);
+    let pmbok = m.phpa.clamp(0.0, 10000.0);
+    let rhok = m.rh.clamp(0.0, 1.0);
+    let wlok = m.wl.max(0.1);
+    let alpha = m.tlr.abs().clamp(0.001, 0.01);
+    let tol = m.eps.abs().clamp(1e-12, 0.1) / 2.0;
+
+    // Optical or radio, and the refractivity constant.
+    let optic = wlok <= 100.0;
+    let wlsq = wlok * wlok;
+    let gb = 9.784 * (1.0 - 0.0026 * (2.0 * m.phi).cos() - 0.000_000_28 * hmok);
+    let a = if optic {
+        (287.6155 + (1.62887 + 0.01360 / wlsq) / wlsq) * 273.15e-6 / 1013.25
+    } else {
+        77.6890e-6
+    };
+
+    // Model atmosphere.
+    let gamal = (gb * DMD) / GCR;
+    let gamma = gamal / alpha;
+    let gamm2 = gamma - 2.0;
+    let delm2 = DELTA - 2.0;
+    let tdc = tdkok - 273.15;
+    let psat = 10f64.powf((0.7859 + 0.03477 * tdc) / (1.0 + 0.00412 * tdc))
+        * (1.0 + pmbok * (4.5e-6 + 6e-10 * tdc * tdc));
+    let pwo = if pmbok > 0.0 {
+        rhok * psat / (1.0 - (1.0 - rhok) * psat / pmbok)
+    } else {
+        0.0
+    };
+    let w = pwo * (1.0 - DMW / DMD) * gamma / (DELTA - gamma);
+    let c1 = a * (pmbok + w) / tdkok;
+    let c2 = if optic {
+        (a * w + 11.2684e-6 * pwo) / tdkok
+    } else {
+        (a * w + 6.3938e-6 * pwo) / tdkok
+    };
+    let c3 = (gamma - 1.0) * alpha * c1 / tdkok;
+    let c4 = (DELTA - 1.0) * alpha * c2 / tdkok;
+    let (c5, c6) = if optic {
+        (0.0, 0.0)
+    } else {
+        let c5 = 375_463e-6 * pwo / tdkok;
+        (c5, c5 * delm2 * alpha / (tdkok * tdkok))
+    };
+
+    // Refractive index and r dn/dr in the troposphere and stratosphere.
+    let r0 = S + hmok;
+    let atmt = |r: f64| {
+        let t = (tdkok - alpha * (r - r0)).clamp(100.0, 320.0);
+        let tt0 = t / tdkok;
+        let tt0gm2 = tt0.powf(gamm2);
+        let tt0dm2 = tt0.powf(delm2);
+        let dn = 1.0 + (c1 * tt0gm2 - (c2 - c5 / t) * tt0dm2) * tt0;
+        let rdndr = r * (-c3 * tt0gm2 + (c4 - c6 / tt0) * tt0dm2);
+        (t, dn, rdndr)
+    };
+    let rt = S + HT.max(hmok);
+    let (tt, dnt, _) = atmt(rt);
+    let atms = |r: f64| {
+        let b = gamal / tt;
+        let w = (dnt - 1.0) * (-b * (r - rt)).exp();
+        (1.0 + w, -r * b * w)
+    };
+    let refi = |dn: f64, rdndr: f64| rdndr / (dn + rdndr);
+    let zenith = |sine: f64| sine.atan2((1.0 - sine * sine).max(0.0).sqrt());
+
+    // At the observer, at the tropopause from either side, and at the
+    // stratosphere limit.
+    let (_, dn0, rdndr0) = atmt(r0);
+    let sk0 = dn0 * r0 * zobs2.sin();
+    let f0 = refi(dn0, rdndr0);
+    let (_, dnt, rdndrt) = atmt(rt);
+    let zt = zenith(sk0 / (rt * dnt));
+    let ft = refi(dnt, rdndrt);
+    let (dnts, rdndrp) = atms(rt);
+    let zts = zenith(sk0 / (rt * dnts));
+    let fts = refi(dnts, rdndrp);
+    let rs = S + HS;
+    let (dns, rdndrs) = atms(rs);
+    let zs = zenith(sk0 / (rs * dns));
+    let fs = refi(dns, rdndrs);
+
+    // Integrate in the troposphere, then the stratosphere, doubling the
+    // number of strips until the precision is reached.
+    let mut refs = [0.0; 2];
+    for (k, out) in refs.iter_mut().enumerate() {
+        let layer = |r: f64| {
+            if k == 0 {
+                let (_, dn, rdndr) = atmt(r);
+                (dn, rdndr)
+            } else {
+                atms(r)
+            }
+        };
+        let (z0, zrange, fb, ff, rstart) = if k == 0 {
+            (zobs2, zt - zobs2, f0, ft, r0)
+        } else {
+            (zts, zs - zts, fts, fs, rt)
+        };
+        let mut refold = 1.0;
+        let mut is = 8;
+        let (mut fo, mut fe) = (0.0, 0.0);
+        let mut n = 1;
+        loop {
+            let h = zrange / is as f64;
+            let mut r = rstart;
+            for i in (1..is).step_by(n) {
+                // Radius where the ray has this zenith distance, to 1 m.
+                let sz = (z0 + h * i as f64).sin();
+                if sz > 1e-20 {
+                    let w = sk0 / sz;
+                    let mut rg = r;
+                    for _ in 0..4 {
+                        let (dn, rdndr) = layer(rg);
+                        let dr = (rg * dn - w) / (dn + rdndr);
+                        rg -= dr;
+                        if dr.abs() <= 1.0 {
+                            break;
+                        }
+                    }
+                    r = rg;
+                }
+                let (dn, rdndr) = layer(r);
+                let f = refi(dn, rdndr);
+                if n == 1 && i % 2 == 0 {
+                    fe += f;
+                } else {
+                    fo += f;
+                }
+            }
+            let refp = h * (fb + 4.0 * fo + 2.0 * fe + ff) / 3.0;
+            if (refp - refold).abs() > tol && is < ISMAX {
+                refold = refp;
+                is += is;
+                fe += fo;
+                fo = 0.0;
+                n = 2;
+            } else {
+                *out = refp;
+                break;
+            }
+        }
+    }
+    let r = refs[0] + refs[1];
+    if zobs1 < 0.0 {
+        -r
+    } else {
+        r
+    }
+}
@@ -0,0 +1,222 @@
+// sexagesimal
+//   Text ↔ angle for RA/Dec style strings, around eraTf2a/eraAf2a and
+//   eraA2tf/eraA2af.
+//
+//   Parsed:    12h34m56.7s  -05:23:28.4  +12 34 56  12°34′56″  12d34'56"
+//              12ʰ34ᵐ56.7ˢ, with 1–3 fields (the last may be fractional)
+//   Formatted: Colon "12:34:56.7", Space "12 34 56.7",
+//              Letters "12h34m56.7s" / "+12d34m56.7s",
+//              Symbols "12ʰ34ᵐ56.7ˢ" / "+12°34′56.7″"
+//
+//   Range problems come back as the FieldStatus of eraTf2a/eraAf2a.
+
+use crate::G1_safe::{eraA2af_safe, eraA2tf_safe, eraAf2a_safe};
+use crate::G32_safe::eraTf2a_safe;
+use crate::H1_safe::{ErfaError, FieldStatus};
+
+pub use crate::H1_safe::ErfaResult;
+
+// What the first field counts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SexagesimalUnit {
+    Hours,
+    Degrees,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SexagesimalStyle {
+    #[default]
+    Colon,
+    Space,
+    Letters,
+    Symbols,
+}
+
+// Fields of a sexagesimal string; unit is set when the text marks it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SexagesimalFields {
+    pub sign: char,
+    pub whole: i32,
+    pub minutes: i32,
+    pub seconds: f64,
+    pub unit: Option<SexagesimalUnit>,
+}
+
+// Marker after a field: position it may follow, and unit it implies.
+fn marker(c: char) -> Option<(usize, Option<SexagesimalUnit>)> {
+    match c {
+        'h' | 'H' | 'ʰ' => Some((0, Some(SexagesimalUnit::Hours))),
+        'd' | 'D' | '°' => Some((0, Some(SexagesimalUnit::Degrees))),
+        'm' | 'M' | 'ᵐ' | '′' | '\'' => Some((1, None)),
+        's' | 'S' | 'ˢ' | '″' | '"' => Some((2, None)),
+        _ => None,
+    }
+}
+
+// Split a string into sign, fields and unit; no range checks.
+pub fn parse_fields(s: &str) -> ErfaResult<SexagesimalFields> {
+    let bad = ErfaError::BadAngleString;
+    let s = s.trim();
+    let (sign, body) = match s.chars().next() {
+        Some('+') => ('+', &s[1..]),
+        Some('-') => ('-', &s[1..]),
+        Some('−') => ('-', &s['−'.len_utf8()..]),
+        _ => ('+', s),
+    };
+
+    let mut fields: Vec<&str> = Vec::new();
+    let mut unit = None;
+    let mut start = None;
+    let mut after_marker = false;
+    for (i, c) in body.char_indices() {
+        if c.is_ascii_digit() || c == '.' {
+            start.get_or_insert(i);
+            after_marker = false;
+            continue;
+        }
+        if let Some(st) = start.take() {
+            fields.push(&body[st..i]);
+        } else if !c.is_whitespace() && !after_marker {
+            return Err(bad);
+        }
+        if let Some((pos, u)) = marker(c) {
+            if fields.len() != pos + 1 {
+                return Err(bad);
+            }
+            unit = unit.or(u);
+            after_marker = true;
+        } else if c == ':' {
+            if fields.is_empty() || after_marker {
+                return Err(bad);
+            }
+        } else if !c.is_whitespace() {
+            return Err(bad);
+        }
+    }
+    if let Some(st) = start {
+        fields.push(&body[st..]);
+    }
+    if fields.is_empty() || fields.len() > 3 {
+        return Err(bad);
+    }
+
+    // Only the last field may carry a fraction; it spills into the next.
+    let last = fields.len() - 1;
+    let mut v = [0.0_f64; 3];
+    for (k, f) in fields.iter().enumerate() {
+        if f.is_empty() || (k < last && f.contains('.')) || f.starts_with('.') {
+            return Err(bad);
+        }
+        v[k] = f.parse::<f64>().map_err(|_| bad)?;
+    }
+    if last < 2 {
+        let frac = v[last].fract();
+        v[last] = v[last].trunc();
+        v[last + 1] = 60.0 * frac;
+        if last == 0 {
+            v[2] = 60.0 * v[1].fract();
+            v[1] = v[1].trunc();
+        }
+    }
+
+    Ok(SexagesimalFields {
+        sign,
+        whole: v[0] as i32,
+        minutes: v[1] as i32,
+        seconds: v[2],
+        unit,
+    })
+}
+
+// Hours, minutes, seconds → radians (eraTf2a).
+pub fn parse_hms(s: &str) -> ErfaResult<(f64, FieldStatus)> {
+    let f = parse_fields(s)?;
+    if f.unit == Some(SexagesimalUnit::Degrees) {
+        return Err(ErfaError::BadAngleString);
+    }
+    eraTf2a_safe(f.sign, f.whole, f.minutes, f.seconds)
+}
+
+// Degrees, arcminutes, arcseconds → radians (eraAf2a).
+pub fn parse_dms(s: &str) -> ErfaResult<(f64, FieldStatus)> {
+    let f = parse_fields(s)?;
+    if f.unit == Some(SexagesimalUnit::Hours) {
+        return Err(ErfaError::BadAngleString);
+    }
+    eraAf2a_safe(f.sign, f.whole, f.minutes, f.seconds)
+}
+
+// Either form, as marked in the text; unmarked strings use `default`.
+pub fn parse_angle(s: &str, default: SexagesimalUnit) -> ErfaResult<(f64, FieldStatus)> {
+    match parse_fields(s)?.unit.unwrap_or(default) {
+        SexagesimalUnit::Hours => parse_hms(s),
+        SexagesimalUnit::Degrees => parse_dms(s),
+    }
+}
+
+// Radians → hours, minutes, seconds (eraA2tf) with ndp decimals (0–9);
+// a sign only when negative.
+pub fn format_hms(angle: f64, ndp: i32, style: SexagesimalStyle) -> ErfaResult<String> {
+    check_ndp(ndp)?;
+    let (sign, f) = eraA2tf_safe(ndp, angle)?;
+    let sign = if sign == '-' { "-" } else { "" };
+    Ok(layout(
+        sign,
+        f,
+        ndp,
+        style,
+        ["h", "m", "s"],
+        ["ʰ", "ᵐ", "ˢ"],
+    ))
+}
+
+// Radians → degrees, arcminutes, arcseconds (eraA2af) with ndp decimals
+// (0–9); always signed.
+pub fn format_dms(angle: f64, ndp: i32, style: SexagesimalStyle) -> ErfaResult<String> {
+    check_ndp(ndp)?;
+    let (sign, f) = eraA2af_safe(ndp, angle)?;
+    let sign = if sign == '-' { "-" } else { "+" };
+    Ok(layout(
+        sign,
+        f,
+        ndp,
+        style,
+        ["d", "m", "s"],
+        ["°", "′", "″"],
+    ))
+}
+
+fn check_ndp(ndp: i32) -> ErfaResult<()> {
+    if !(0..=9).contains(&ndp) {
+        return Err(ErfaError::BadPrecision);
+    }
+    Ok(())
+}
+
+fn layout(
+    sign: &str,
+    f: [i32; 4],
+    ndp: i32,
+    style: SexagesimalStyle,
+    letters: [&str; 3],
+    symbols: [&str; 3],
+) -> String {
+    let frac = if ndp > 0 {
+        format!(".{:0width$}", f[3], width = ndp as usize)
+    } else {
+        String::new()
+    };
+    let (a, b, c) = (f[0], f[1], f[2]);
+    match style {
+        SexagesimalStyle::Colon => format!("{sign}{a:02}:{b:02}:{c:02}{frac}"),
+        SexagesimalStyle::Space => format!("{sign}{a:02} {b:02} {c:02}{frac}"),
+        SexagesimalStyle::Letters => {
+            let [x, y, z] = letters;
+            format!("{sign}{a:02}{x}{b:02}{y}{c:02}{frac}{z}")
+        }
+        SexagesimalStyle::Symbols => {
+            let [x, y, z] = symbols;
+            format!("{sign}{a:02}{x}{b:02}{y}{c:02}{frac}{z}")
+        }
+    }
+}
@@ -0,0 +1,1286 @@
+// sgp4
+//   Two-line elements, the SGP4/SDP4 propagator and the TEME frame.
+//
+//   Tle                         → one element set, parsed from its two lines
+//   Sgp4                        → propagator initialised from a Tle
+//   teme_to_itrs / itrs_to_teme → via GMST (eraGmst82) and eraPom00
+//   teme_to_gcrs / gcrs_to_teme → via eraEqeq94 and eraPnm06a
+//   LookAngles / look_angles    → azimuth, elevation, range and range rate
+//                                 from a site, via eraPvtob
+//
+//   The propagator follows Vallado et al., "Revisiting Spacetrack Report
+//   #3" (AIAA 2006-6753, with the 2020 revisions) in its "improved" mode
+//   with WGS72 constants, as the element sets are fitted: SGP4 below a
+//   225-minute period and SDP4, with lunar-solar terms and the 12- and
+//   24-hour resonances, above it.  The elements' epoch is taken as UTC and,
+//   for the sidereal angle, as UT1.  TEME (true equator, mean equinox) is
+//   rotated to the Earth-fixed frame by the 1982 GMST and polar motion, and
+//   to GCRS through the true equator and equinox of date; velocities in
+//   GCRS ignore the slow rotation of the precession-nutation matrix.
+//   Positions are in metres and velocities in m/s, as eraPvtob.
+
+use crate::events::Site;
+use crate::tangent::Station;
+use crate::G14_safe::eraEqeq94_safe;
+use crate::G17_safe::eraGmst82_safe;
+use crate::G19_safe::eraIr_safe;
+use crate::G26_safe::{eraPnm06a_safe, eraPom00_safe};
+use crate::G27_safe::eraPvtob_safe;
+use crate::G28_safe::{eraRxp_safe, eraRz_safe};
+use crate::G33_safe::{eraTrxp_safe, eraUtcut1_safe};
+use crate::G8_safe::eraCal2jd_safe;
+use crate::H1_safe::{ErfaError, ERFA_D2PI, ERFA_DAYSEC, ERFA_DD2R, ERFA_DPI};
+
+pub use crate::H1_safe::ErfaResult;
+
+// WGS72 gravity model: GM (km³/s²), equatorial radius (km), harmonics.
+const MU: f64 = 398_600.8;
+const RADIUS: f64 = 6378.135;
+const J2: f64 = 0.001_082_616;
+const J3: f64 = -0.000_002_538_81;
+const J4: f64 = -0.000_001_655_97;
+const J3OJ2: f64 = J3 / J2;
+
+// Earth rotation rate (rad/s), as eraPvtob.
+const OM: f64 = 1.002_737_811_911_354_6 * ERFA_D2PI / ERFA_DAYSEC;
+
+const X2O3: f64 = 2.0 / 3.0;
+const TEMP4: f64 = 1.5e-12;
+
+// Sqrt(GM) in Earth radii³ per minute².
+fn xke() -> f64 {
+    60.0 / (RADIUS * RADIUS * RADIUS / MU).sqrt()
+}
+
+// A NORAD two-line element set (angles in radians).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Tle {
+    pub name: String,    // from a preceding title line, if any
+    pub catalog: String, // catalogue number (possibly Alpha-5)
+    pub classification: char,
+    pub designator: String, // international designator
+    pub epoch1: f64,        // epoch, UTC two-part JD
+    pub epoch2: f64,
+    pub ndot: f64,  // first derivative of mean motion / 2 (rev/day²)
+    pub nddot: f64, // second derivative / 6 (rev/day³)
+    pub bstar: f64, // drag term (1/Earth radii)
+    pub element_number: i32,
+    pub inclination: f64,
+    pub raan: f64, // right ascension of the ascending node
+    pub eccentricity: f64,
+    pub argp: f64, // argument of perigee
+    pub mean_anomaly: f64,
+    pub mean_motion: f64, // (rev/day)
+    pub revolution: i32,  // revolution number at epoch
+}
+
+impl Tle {
+    // Element set from its two lines; checksums are verified where present.
+    pub fn parse(line1: &str, line2: &str) -> ErfaResult<Tle> {
+        let (l1, l2) = (line1.trim_end(), line2.trim_end());
+        if l1.len() < 68 || l2.len() < 68 || !l1.is_ascii() || !l2.is_ascii() {
+            return Err(ErfaError::TleSyntax);
+        }
+        if !l1.starts_with("1 ") || !l2.starts_with("2 ") || col(l1, 3, 7) != col(l2, 3, 7) {
+            return Err(ErfaError::TleSyntax);
+        }
+        checksum(l1)?;
+        checksum(l2)?;
+
+        // Epoch: two-digit year (1957–2056) and day of the year.
+        let yy = int(col(l1, 19, 20))?;
+        let iy = if yy < 57 { 2000 + yy } else { 1900 + yy };
+        let (djm0, djm) = eraCal2jd_safe(iy, 1, 1)?;
+        let day = num(col(l1, 21, 32))?;
+
+        Ok(Tle {
+            name: String::new(),
+            catalog: col(l1, 3, 7).trim().to_string(),
+            classification: l1.as_bytes()[7] as char,
+            designator: col(l1, 10, 17).trim().to_string(),
+            epoch1: djm0 + djm,
+            epoch2: day - 1.0,
+            ndot: num(col(l1, 34, 43))?,
+            nddot: exponent(col(l1, 45, 52))?,
+            bstar: exponent(col(l1, 54, 61))?,
+            element_number: int(col(l1, 65, 68)).unwrap_or(0),
+            inclination: num(col(l2, 9, 16))? * ERFA_DD2R,
+            raan: num(col(l2, 18, 25))? * ERFA_DD2R,
+            eccentricity: num(&format!("0.{}", col(l2, 27, 33).trim()))?,
+            argp: num(col(l2, 35, 42))? * ERFA_DD2R,
+            mean_anomaly: num(col(l2, 44, 51))? * ERFA_DD2R,
+            mean_motion: num(col(l2, 53, 63))?,
+            revolution: int(col(l2, 64, 68)).unwrap_or(0),
+        })
+    }
+
+    // All element sets in a text, in two-line or three-line (titled) form.
+    pub fn parse_all(text: &str) -> ErfaResult<Vec<Tle>> {
+        let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+        let mut out = Vec::new();
+        let mut k = 0;
+        while k < lines.len() {
+            let name = if lines[k].starts_with("1 ") {
+                ""
+            } else {
+                k += 1;
+                lines[k - 1].trim().trim_start_matches("0 ")
+            };
+            if k + 1 >= lines.len() {
+                return Err(ErfaError::TleSyntax);
+            }
+            let mut tle = Tle::parse(lines[k], lines[k + 1])?;
+            tle.name = name.to_string();
+            out.push(tle);
+            k += 2;
+        }
+        Ok(out)
+    }
+}
+
+// Columns a to b of a line, 1-based and inclusive.
+fn col(line: &str, a: usize, b: usize) -> &str {
+    &line[a - 1..b.min(line.len())]
+}
+
+fn num(s: &str) -> ErfaResult<f64> {
+    s.trim().parse().map_err(|_| ErfaError::TleSyntax)
+}
+
+fn int(s: &str) -> ErfaResult<i32> {
+    s.trim().parse().map_err(|_| ErfaError::TleSyntax)
+}
+
+// Assumed-decimal field with exponent, e.g. " 28098-4" for 0.28098e-4.
+fn exponent(s: &str) -> ErfaResult<f64> {
+    let s = s.trim();
+    if s.len() < 3 {
+        return Err(ErfaError::TleSyntax);
+    }
+    let (m, e) = s.split_at(s.len() - 2);
+    let (sign, m) = match m.strip_prefix('-') {
+        Some(m) => (-1.0, m),
+        None => (1.0, m.trim_start_matches('+')),
+    };
+    Ok(sign * num(&format!("0.{m}"))? * 10f64.powi(int(e)?))
+}
+
+// Modulo-10 sum of the digits, minus signs counting 1, against column 69.
+fn checksum(line: &str) -> ErfaResult<()> {
+    let Some(c) = line.as_bytes().get(68).filter(|c| c.is_ascii_digit()) else {
+        return Ok(());
+    };
+    let sum: u32 = line.as_bytes()[..68]
+        .iter()
+        .map(|&b| match b {
+            b'0'..=b'9' => (b - b'0') as u32,
+            b'-' => 1,
+            _ => 0,
+        })
+        .sum();
+    if sum % 10 == (c - b'0') as u32 {
+        Ok(())
+    } else {
+        Err(ErfaError::TleChecksum)
+    }
+}
+
+// Lunar-solar periodic coefficients (dscom) and the secular and resonance
+// terms (dsinit) of SDP4.
+#[derive(Clone, Copy, Debug, Default)]
+struct DeepSpace {
+    e3: f64,
+    ee2: f64,
+    se2: f64,
+    se3: f64,
+    sgh2: f64,
+    sgh3: f64,
+    sgh4: f64,
+    sh2: f64,
+    sh3: f64,
+    si2: f64,
+    si3: f64,
+    sl2: f64,
+    sl3: f64,
+    sl4: f64,
+    xgh2: f64,
+    xgh3: f64,
+    xgh4: f64,
+    xh2: f64,
+    xh3: f64,
+    xi2: f64,
+    xi3: f64,
+    xl2: f64,
+    xl3: f64,
+    xl4: f64,
+    zmol: f64,
+    zmos: f64,
+    irez: i32,
+    d2201: f64,
+    d2211: f64,
+    d3210: f64,
+    d3222: f64,
+    d4410: f64,
+    d4422: f64,
+    d5220: f64,
+    d5232: f64,
+    d5421: f64,
+    d5433: f64,
+    dedt: f64,
+    didt: f64,
+    dmdt: f64,
+    dnodt: f64,
+    domdt: f64,
+    del1: f64,
+    del2: f64,
+    del3: f64,
+    xfact: f64,
+    xlamo: f64,
+}
+
+// SGP4/SDP4 state for one element set.
+#[derive(Clone, Debug)]
+pub struct Sgp4 {
+    pub epoch1: f64, // epoch of the elements, UTC two-part JD
+    pub epoch2: f64,
+    bstar: f64,
+    ecco: f64,
+    inclo: f64,
+    nodeo: f64,
+    argpo: f64,
+    mo: f64,
+    no: f64, // Brouwer mean motion (rad/min)
+    isimp: bool,
+    aycof: f64,
+    con41: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    delmo: f64,
+    eta: f64,
+    argpdot: f64,
+    omgcof: f64,
+    sinmao: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    mdot: f64,
+    nodedot: f64,
+    xlcof: f64,
+    xmcof: f64,
+    nodecf: f64,
+    gsto: f64,
+    deep: Option<DeepSpace>,
+}
+
+impl Sgp4 {
+    // Propagator for an element set (sgp4init).
+    pub fn new(tle: &Tle) -> ErfaResult<Sgp4> {
+        let xke = xke();
+        let ecco = tle.eccentricity;
+        let inclo = tle.inclination;
+        let argpo = tle.argp;
+        let no_kozai = tle.mean_motion * ERFA_D2PI / 1440.0;
+
+        // initl: un-Kozai the mean motion, and the GMST at epoch.
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let cosio = inclo.cos();
+        let cosio2 = cosio * cosio;
+        let ak = (xke / no_kozai).powf(X2O3);
+        let d1 = 0.75 * J2 * (3.0 * cosio2 - 1.0) / (rteosq * omeosq);
+        let mut del = d1 / (ak * ak);
+        let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+        del = d1 / (adel * adel);
+        let no = no_kozai / (1.0 + del);
+        let ao = (xke / no).powf(X2O3);
+        let sinio = inclo.sin();
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = -con42 - cosio2 - cosio2;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+        let gsto = eraGmst82_safe(tle.epoch1, tle.epoch2)?;
+        if !(0.0..1.0).contains(&ecco) || no <= 0.0 {
+            return Err(ErfaError::Sgp4Elements);
+        }
+
+        // Drag: the atmosphere's s and q0 parameters, lowered for perigees
+        // under 156 km.
+        let ss = 78.0 / RADIUS + 1.0;
+        let mut sfour = ss;
+        let mut qzms24 = ((120.0 - 78.0) / RADIUS).powi(4);
+        let perige = (rp - 1.0) * RADIUS;
+        if perige < 156.0 {
+            sfour = if perige < 98.0 { 20.0 } else { perige - 78.0 };
+            qzms24 = ((120.0 - sfour) / RADIUS).powi(4);
+            sfour = sfour / RADIUS + 1.0;
+        }
+        let pinvsq = 1.0 / posq;
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+        let cc2 = coef1
+            * no
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = tle.bstar * cc2;
+        let cc3 = if ecco > 1e-4 {
+            -2.0 * coef * tsi * J3OJ2 * no * sinio / ecco
+        } else {
+            0.0
+        };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75
+                            * x1mth2
+                            * (2.0 * etasq - eeta * (1.0 + etasq))
+                            * (2.0 * argpo).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        // Secular rates.
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * J2 * pinvsq * no;
+        let temp2 = 0.5 * temp1 * J2 * pinvsq;
+        let temp3 = -0.46875 * J4 * pinvsq * pinvsq * no;
+        let mdot = no
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1
+            + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+        let xpidot = argpdot + nodedot;
+        let omgcof = tle.bstar * cc3 * argpo.cos();
+        let xmcof = if ecco > 1e-4 {
+            -X2O3 * coef * tle.bstar / eeta
+        } else {
+            0.0
+        };
+        let nodecf = 3.5 * omeosq * xhdot1 * cc1;
+        let t2cof = 1.5 * cc1;
+        let xlcof = -0.25 * J3OJ2 * sinio * (3.0 + 5.0 * cosio) / (1.0 + cosio).abs().max(TEMP4);
+        let aycof = -0.5 * J3OJ2 * sinio;
+        let delmo = (1.0 + eta * tle.mean_anomaly.cos()).powi(3);
+
+        let mut sat = Sgp4 {
+            epoch1: tle.epoch1,
+            epoch2: tle.epoch2,
+            bstar: tle.bstar,
+            ecco,
+            inclo,
+            nodeo: tle.raan,
+            argpo,
+            mo: tle.mean_anomaly,
+            no,
+            isimp: rp < 220.0 / RADIUS + 1.0,
+            aycof,
+            con41,
+            cc1,
+            cc4,
+            cc5,
+            d2: 0.0,
+            d3: 0.0,
+            d4: 0.0,
+            delmo,
+            eta,
+            argpdot,
+            omgcof,
+            sinmao: tle.mean_anomaly.sin(),
+            t2cof,
+            t3cof: 0.0,
+            t4cof: 0.0,
+            t5cof: 0.0,
+            x1mth2,
+            x7thm1: 7.0 * cosio2 - 1.0,
+            mdot,
+            nodedot,
+            xlcof,
+            xmcof,
+            nodecf,
+            gsto,
+            deep: None,
+        };
+
+        if ERFA_D2PI / no >= 225.0 {
+            // Deep space: days since 1950 January 0.
+            let epoch = (tle.epoch1 - 2_433_281.5) + tle.epoch2;
+            let mut ds = DeepSpace::default();
+            let c = dscom(&mut ds, epoch, ecco, argpo, inclo, tle.raan, no);
+            dsinit(&mut ds, &sat, &c, eccsq, xpidot, xke);
+            sat.deep = Some(ds);
+            sat.isimp = true;
+        }
+        if !sat.isimp {
+            let cc1sq = cc1 * cc1;
+            sat.d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = sat.d2 * tsi * cc1 / 3.0;
+            sat.d3 = (17.0 * ao + sfour) * temp;
+            sat.d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            sat.t3cof = sat.d2 + 2.0 * cc1sq;
+            sat.t4cof = 0.25 * (3.0 * sat.d3 + cc1 * (12.0 * sat.d2 + 10.0 * cc1sq));
+            sat.t5cof = 0.2
+                * (3.0 * sat.d4
+                    + 12.0 * cc1 * sat.d3
+                    + 6.0 * sat.d2 * sat.d2
+                    + 15.0 * cc1sq * (2.0 * sat.d2 + cc1sq));
+        }
+        sat.propagate(0.0)?;
+        Ok(sat)
+    }
+
+    // True for SDP4 (period of 225 minutes or more).
+    pub fn is_deep_space(&self) -> bool {
+        self.deep.is_some()
+    }
+
+    // TEME pv (m, m/s) at a UTC two-part JD.
+    pub fn pv(&self, utc1: f64, utc2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        self.propagate(((utc1 - self.epoch1) + (utc2 - self.epoch2)) * 1440.0)
+    }
+
+    // TEME pv (m, m/s) at `tsince` minutes from the epoch.
+    pub fn propagate(&self, tsince: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        let xke = xke();
+        let vkmpersec = RADIUS * xke / 60.0;
+        let t = tsince;
+
+        // Secular gravity and drag.
+        let xmdf = self.mo + self.mdot * t;
+        let argpdf = self.argpo + self.argpdot * t;
+        let nodedf = self.nodeo + self.nodedot * t;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let t2 = t * t;
+        let mut nodem = nodedf + self.nodecf * t2;
+        let mut tempa = 1.0 - self.cc1 * t;
+        let mut tempe = self.bstar * self.cc4 * t;
+        let mut templ = self.t2cof * t2;
+        if !self.isimp {
+            let delomg = self.omgcof * t;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            let temp = delomg + delm;
+            mm = xmdf + temp;
+            argpm = argpdf - temp;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            tempa = tempa - self.d2 * t2 - self.d3 * t3 - self.d4 * t4;
+            tempe += self.bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + t * self.t5cof);
+        }
+        let mut nm = self.no;
+        let mut em = self.ecco;
+        let mut inclm = self.inclo;
+        if let Some(ds) = &self.deep {
+            dspace(
+                ds, self, t, &mut em, &mut argpm, &mut inclm, &mut mm, &mut nodem, &mut nm,
+            );
+        }
+        if nm <= 0.0 {
+            return Err(ErfaError::Sgp4Elements);
+        }
+        let am = (xke / nm).powf(X2O3) * tempa * tempa;
+        nm = xke / am.powf(1.5);
+        em -= tempe;
+        if !(-0.001..1.0).contains(&em) {
+            return Err(ErfaError::Sgp4Elements);
+        }
+        em = em.max(1e-6);
+        mm += self.no * templ;
+        let xlm = mm + argpm + nodem;
+        nodem %= ERFA_D2PI;
+        argpm %= ERFA_D2PI;
+        let xlm = xlm % ERFA_D2PI;
+        mm = (xlm - argpm - nodem) % ERFA_D2PI;
+
+        // Lunar-solar periodics.
+        let mut ep = em;
+        let mut xincp = inclm;
+        let mut argpp = argpm;
+        let mut nodep = nodem;
+        let mut mp = mm;
+        let (mut aycof, mut xlcof) = (self.aycof, self.xlcof);
+        let (mut con41, mut x1mth2, mut x7thm1) = (self.con41, self.x1mth2, self.x7thm1);
+        if let Some(ds) = &self.deep {
+            dpper(ds, t, &mut ep, &mut xincp, &mut nodep, &mut argpp, &mut mp);
+            if xincp < 0.0 {
+                xincp = -xincp;
+                nodep += ERFA_DPI;
+                argpp -= ERFA_DPI;
+            }
+            if !(0.0..=1.0).contains(&ep) {
+                return Err(ErfaError::Sgp4Elements);
+            }
+            let sinip = xincp.sin();
+            let cosip = xincp.cos();
+            aycof = -0.5 * J3OJ2 * sinip;
+            xlcof = -0.25 * J3OJ2 * sinip * (3.0 + 5.0 * cosip) / (1.0 + cosip).abs().max(TEMP4);
+            let cosisq = cosip * cosip;
+            con41 = 3.0 * cosisq - 1.0;
+            x1mth2 = 1.0 - cosisq;
+            x7thm1 = 7.0 * cosisq - 1.0;
+        }
+
+        // Long-period periodics.
+        let axnl = ep * argpp.cos();
+        let temp = 1.0 / (am * (1.0 - ep * ep));
+        let aynl = ep * argpp.sin() + temp * aycof;
+        let xl = mp + argpp + nodep + temp * xlcof * axnl;
+
+        // Kepler's equation.
+        let u = (xl - nodep) % ERFA_D2PI;
+        let mut eo1 = u;
+        let mut tem5: f64 = 9999.9;
+        let (mut sineo1, mut coseo1) = (0.0, 0.0);
+        let mut ktr = 1;
+        while tem5.abs() >= 1e-12 && ktr <= 10 {
+            (sineo1, coseo1) = eo1.sin_cos();
+            tem5 = 1.0 - coseo1 * axnl - sineo1 * aynl;
+            tem5 = (u - aynl * coseo1 + axnl * sineo1 - eo1) / tem5;
+            tem5 = tem5.clamp(-0.95, 0.95);
+            eo1 += tem5;
+            ktr += 1;
+        }
+
+        // Short-period periodics.
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return Err(ErfaError::Sgp4Elements);
+        }
+        let rl = am * (1.0 - ecose);
+        let rdotl = am.sqrt() * esine / rl;
+        let rvdotl = pl.sqrt() / rl;
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let mut su = sinu.atan2(cosu);
+        let sin2u = (cosu + cosu) * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp = 1.0 / pl;
+        let temp1 = 0.5 * J2 * temp;
+        let temp2 = temp1 * temp;
+
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * con41) + 0.5 * temp1 * x1mth2 * cos2u;
+        su -= 0.25 * temp2 * x7thm1 * sin2u;
+        let cosip = xincp.cos();
+        let xnode = nodep + 1.5 * temp2 * cosip * sin2u;
+        let xinc = xincp + 1.5 * temp2 * cosip * xincp.sin() * cos2u;
+        let mvt = rdotl - nm * temp1 * x1mth2 * sin2u / xke;
+        let rvdot = rvdotl + nm * temp1 * (x1mth2 * cos2u + 1.5 * con41) / xke;
+
+        // Orientation vectors.
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let uv = [
+            xmx * sinsu + cnod * cossu,
+            xmy * sinsu + snod * cossu,
+            sini * sinsu,
+        ];
+        let vv = [
+            xmx * cossu - cnod * sinsu,
+            xmy * cossu - snod * sinsu,
+            sini * cossu,
+        ];
+
+        if mrt < 1.0 {
+            return Err(ErfaError::Sgp4Decayed);
+        }
+        let mut pv = [[0.0; 3]; 2];
+        for i in 0..3 {
+            pv[0][i] = mrt * uv[i] * RADIUS * 1e3;
+            pv[1][i] = (mvt * uv[i] + rvdot * vv[i]) * vkmpersec * 1e3;
+        }
+        Ok(pv)
+    }
+}
+
+// Quantities from dscom that dsinit needs but SDP4 does not keep.
+struct Dscom {
+    sinim: f64,
+    cosim: f64,
+    emsq: f64,
+    s1: f64,
+    s2: f64,
+    s3: f64,
+    s4: f64,
+    s5: f64,
+    ss1: f64,
+    ss2: f64,
+    ss3: f64,
+    ss4: f64,
+    ss5: f64,
+    sz1: f64,
+    sz3: f64,
+    sz11: f64,
+    sz13: f64,
+    sz21: f64,
+    sz23: f64,
+    sz31: f64,
+    sz33: f64,
+    z1: f64,
+    z3: f64,
+    z11: f64,
+    z13: f64,
+    z21: f64,
+    z23: f64,
+    z31: f64,
+    z33: f64,
+}
+
+// Lunar and solar perturbation coefficients at the epoch.
+fn dscom(
+    ds: &mut DeepSpace,
+    epoch: f64,
+    ep: f64,
+    argpp: f64,
+    inclp: f64,
+    nodep: f64,
+    np: f64,
+) -> Dscom {
+    const ZES: f64 = 0.01675;
+    const ZEL: f64 = 0.05490;
+    const C1SS: f64 = 2.9864797e-6;
+    const C1L: f64 = 4.7968065e-7;
+    const ZSINIS: f64 = 0.39785416;
+    const ZCOSIS: f64 = 0.91744867;
+    const ZCOSGS: f64 = 0.1945905;
+    const ZSINGS: f64 = -0.98088458;
+
+    let (snodm, cnodm) = nodep.sin_cos();
+    let (sinomm, cosomm) = argpp.sin_cos();
+    let (sinim, cosim) = inclp.sin_cos();
+    let emsq = ep * ep;
+    let betasq = 1.0 - emsq;
+    let rtemsq = betasq.sqrt();
+
+    // The Moon's node and the Sun and Moon at the epoch.
+    let day = epoch + 18261.5;
+    let xnodce = (4.5236020 - 9.2422029e-4 * day) % ERFA_D2PI;
+    let (stem, ctem) = xnodce.sin_cos();
+    let zcosil = 0.91375164 - 0.03568096 * ctem;
+    let zsinil = (1.0 - zcosil * zcosil).sqrt();
+    let zsinhl = 0.089683511 * stem / zsinil;
+    let zcoshl = (1.0 - zsinhl * zsinhl).sqrt();
+    let gam = 5.8351514 + 0.0019443680 * day;
+    let zx = 0.39785416 * stem / zsinil;
+    let zy = zcoshl * ctem + 0.91744867 * zsinhl * stem;
+    let zx = gam + zx.atan2(zy) - xnodce;
+    let (zsingl, zcosgl) = zx.sin_cos();
+
+    // Solar terms on the first pass, lunar on the second.
+    let mut zcosg = ZCOSGS;
+    let mut zsing = ZSINGS;
+    let mut zcosi = ZCOSIS;
+    let mut zsini = ZSINIS;
+    let mut zcosh = cnodm;
+    let mut zsinh = snodm;
+    let mut cc = C1SS;
+    let xnoi = 1.0 / np;
+    let mut sol = [0.0; 19];
+    let mut lun = [0.0; 19];
+    for pass in 0..2 {
+        let a1 = zcosg * zcosh + zsing * zcosi * zsinh;
+        let a3 = -zsing * zcosh + zcosg * zcosi * zsinh;
+        let a7 = -zcosg * zsinh + zsing * zcosi * zcosh;
+        let a8 = zsing * zsini;
+        let a9 = zsing * zsinh + zcosg * zcosi * zcosh;
+        let a10 = zcosg * zsini;
+        let a2 = cosim * a7 + sinim * a8;
+        let a4 = cosim * a9 + sinim * a10;
+        let a5 = -sinim * a7 + cosim * a8;
+        let a6 = -sinim * a9 + cosim * a10;
+
+        let x1 = a1 * cosomm + a2 * sinomm;
+        let x2 = a3 * cosomm + a4 * sinomm;
+        let x3 = -a1 * sinomm + a2 * cosomm;
+        let x4 = -a3 * sinomm + a4 * cosomm;
+        let x5 = a5 * sinomm;
+        let x6 = a6 * sinomm;
+        let x7 = a5 * cosomm;
+        let x8 = a6 * cosomm;
+
+        let z31 = 12.0 * x1 * x1 - 3.0 * x3 * x3;
+        let z32 = 24.0 * x1 * x2 - 6.0 * x3 * x4;
+        let z33 = 12.0 * x2 * x2 - 3.0 * x4 * x4;
+        let mut z1 = 3.0 * (a1 * a1 + a2 * a2) + z31 * emsq;
+        let mut z2 = 6.0 * (a1 * a3 + a2 * a4) + z32 * emsq;
+        let mut z3 = 3.0 * (a3 * a3 + a4 * a4) + z33 * emsq;
+        let z11 = -6.0 * a1 * a5 + emsq * (-24.0 * x1 * x7 - 6.0 * x3 * x5);
+        let z12 = -6.0 * (a1 * a6 + a3 * a5)
+            + emsq * (-24.0 * (x2 * x7 + x1 * x8) - 6.0 * (x3 * x6 + x4 * x5));
+        let z13 = -6.0 * a3 * a6 + emsq * (-24.0 * x2 * x8 - 6.0 * x4 * x6);
+        let z21 = 6.0 * a2 * a5 + emsq * (24.0 * x1 * x5 - 6.0 * x3 * x7);
+        let z22 = 6.0 * (a4 * a5 + a2 * a6)
+            + emsq * (24.0 * (x2 * x5 + x1 * x6) - 6.0 * (x4 * x7 + x3 * x8));
+        let z23 = 6.0 * a4 * a6 + emsq * (24.0 * x2 * x6 - 6.0 * x4 * x8);
+        z1 = z1 + z1 + betasq * z31;
+        z2 = z2 + z2 + betasq * z32;
+        z3 = z3 + z3 + betasq * z33;
+        let s3 = cc * xnoi;
+        let s2 = -0.5 * s3 / rtemsq;
+        let s4 = s3 * rtemsq;
+        let s1 = -15.0 * ep * s4;
+        let s5 = x1 * x3 + x2 * x4;
+        let s6 = x2 * x3 + x1 * x4;
+        let s7 = x2 * x4 - x1 * x3;
+
+        let terms = [
+            s1, s2, s3, s4, s5, s6, s7, z1, z2, z3, z11, z12, z13, z21, z22, z23, z31, z32, z33,
+        ];
+        if pass == 0 {
+            sol = terms;
+            zcosg = zcosgl;
+            zsing = zsingl;
+            zcosi = zcosil;
+            zsini = zsinil;
+            zcosh = zcoshl * cnodm + zsinhl * snodm;
+            zsinh = snodm * zcoshl - cnodm * zsinhl;
+            cc = C1L;
+        } else {
+            lun = terms;
+        }
+    }
+    let [ss1, ss2, ss3, ss4, ss5, ss6, ss7, sz1, sz2, sz3, sz11, sz12, sz13, sz21, sz22, sz23, sz31, sz32, sz33] =
+        sol;
+    let [s1, s2, s3, s4, s5, s6, s7, z1, z2, z3, z11, z12, z13, z21, z22, z23, z31, z32, z33] = lun;
+
+    ds.zmol = (4.7199672 + 0.22997150 * day - gam) % ERFA_D2PI;
+    ds.zmos = (6.2565837 + 0.017201977 * day) % ERFA_D2PI;
+
+    // Solar terms.
+    ds.se2 = 2.0 * ss1 * ss6;
+    ds.se3 = 2.0 * ss1 * ss7;
+    ds.si2 = 2.0 * ss2 * sz12;
+    ds.si3 = 2.0 * ss2 * (sz13 - sz11);
+    ds.sl2 = -2.0 * ss3 * sz2;
+    ds.sl3 = -2.0 * ss3 * (sz3 - sz1);
+    ds.sl4 = -2.0 * ss3 * (-21.0 - 9.0 * emsq) * ZES;
+    ds.sgh2 = 2.0 * ss4 * sz32;
+    ds.sgh3 = 2.0 * ss4 * (sz33 - sz31);
+    ds.sgh4 = -18.0 * ss4 * ZES;
+    ds.sh2 = -2.0 * ss2 * sz22;
+    ds.sh3 = -2.0 * ss2 * (sz23 - sz21);
+
+    // Lunar terms.
+    ds.ee2 = 2.0 * s1 * s6;
+    ds.e3 = 2.0 * s1 * s7;
+    ds.xi2 = 2.0 * s2 * z12;
+    ds.xi3 = 2.0 * s2 * (z13 - z11);
+    ds.xl2 = -2.0 * s3 * z2;
+    ds.xl3 = -2.0 * s3 * (z3 - z1);
+    ds.xl4 = -2.0 * s3 * (-21.0 - 9.0 * emsq) * ZEL;
+    ds.xgh2 = 2.0 * s4 * z32;
+    ds.xgh3 = 2.0 * s4 * (z33 - z31);
+    ds.xgh4 = -18.0 * s4 * ZEL;
+    ds.xh2 = -2.0 * s2 * z22;
+    ds.xh3 = -2.0 * s2 * (z23 - z21);
+
+    Dscom {
+        sinim,
+        cosim,
+        emsq,
+        s1,
+        s2,
+        s3,
+        s4,
+        s5,
+        ss1,
+        ss2,
+        ss3,
+        ss4,
+        ss5,
+        sz1,
+        sz3,
+        sz11,
+        sz13,
+        sz21,
+        sz23,
+        sz31,
+        sz33,
+        z1,
+        z3,
+        z11,
+        z13,
+        z21,
+        z23,
+        z31,
+        z33,
+    }
+}
+
+// Lunar-solar secular rates and the resonance coefficients.
+fn dsinit(ds: &mut DeepSpace, sat: &Sgp4, c: &Dscom, eccsq: f64, xpidot: f64, xke: f64) {
+    const Q22: f64 = 1.7891679e-6;
+    const Q31: f64 = 2.1460748e-6;
+    const Q33: f64 = 2.2123015e-7;
+    const ROOT22: f64 = 1.7891679e-6;
+    const ROOT44: f64 = 7.3636953e-9;
+    const ROOT54: f64 = 2.1765803e-9;
+    const RPTIM: f64 = 4.375_269_088_011_3e-3;
+    const ROOT32: f64 = 3.7393792e-7;
+    const ROOT52: f64 = 1.1428639e-7;
+    const ZNL: f64 = 1.5835218e-4;
+    const ZNS: f64 = 1.19459e-5;
+
+    let (nm, em, inclm) = (sat.no, sat.ecco, sat.inclo);
+    let (sinim, cosim, emsq) = (c.sinim, c.cosim, c.emsq);
+
+    // 24-hour (synchronous) and 12-hour eccentric orbits resonate.
+    ds.irez = 0;
+    if nm < 0.0052359877 && nm > 0.0034906585 {
+        ds.irez = 1;
+    }
+    if (8.26e-3..=9.24e-3).contains(&nm) && em >= 0.5 {
+        ds.irez = 2;
+    }
+
+    // Solar terms.
+    let ses = c.ss1 * ZNS * c.ss5;
+    let sis = c.ss2 * ZNS * (c.sz11 + c.sz13);
+    let sls = -ZNS * c.ss3 * (c.sz1 + c.sz3 - 14.0 - 6.0 * emsq);
+    let sghs = c.ss4 * ZNS * (c.sz31 + c.sz33 - 6.0);
+    let mut shs = -ZNS * c.ss2 * (c.sz21 + c.sz23);
+    let equatorial = !(5.2359877e-2..=ERFA_DPI - 5.2359877e-2).contains(&inclm);
+    if equatorial {
+        shs = 0.0;
+    }
+    if sinim != 0.0 {
+        shs /= sinim;
+    }
+    let sgs = sghs - cosim * shs;
+
+    // Lunar terms.
+    ds.dedt = ses + c.s1 * ZNL * c.s5;
+    ds.didt = sis + c.s2 * ZNL * (c.z11 + c.z13);
+    ds.dmdt = sls - ZNL * c.s3 * (c.z1 + c.z3 - 14.0 - 6.0 * emsq);
+    let sghl = c.s4 * ZNL * (c.z31 + c.z33 - 6.0);
+    let shll = if equatorial {
+        0.0
+    } else {
+        -ZNL * c.s2 * (c.z21 + c.z23)
+    };
+    ds.domdt = sgs + sghl;
+    ds.dnodt = shs;
+    if sinim != 0.0 {
+        ds.domdt -= cosim / sinim * shll;
+        ds.dnodt += shll / sinim;
+    }
+
+    if ds.irez == 0 {
+        return;
+    }
+    let theta = sat.gsto % ERFA_D2PI;
+    let aonv = (nm / xke).powf(X2O3);
+    if ds.irez == 2 {
+        // Geopotential resonance for 12-hour orbits.
+        let cosisq = cosim * cosim;
+        let em = sat.ecco;
+        let emsq = eccsq;
+        let eoc = em * emsq;
+        let g201 = -0.306 - (em - 0.64) * 0.440;
+        let (g211, g310, g322, g410, g422, g520);
+        if em <= 0.65 {
+            g211 = 3.616 - 13.2470 * em + 16.2900 * emsq;
+            g310 = -19.302 + 117.3900 * em - 228.4190 * emsq + 156.5910 * eoc;
+            g322 = -18.9068 + 109.7927 * em - 214.6334 * emsq + 146.5816 * eoc;
+            g410 = -41.122 + 242.6940 * em - 471.0940 * emsq + 313.9530 * eoc;
+            g422 = -146.407 + 841.8800 * em - 1629.014 * emsq + 1083.4350 * eoc;
+            g520 = -532.114 + 3017.977 * em - 5740.032 * emsq + 3708.2760 * eoc;
+        } else {
+            g211 = -72.099 + 331.819 * em - 508.738 * emsq + 266.724 * eoc;
+            g310 = -346.844 + 1582.851 * em - 2415.925 * emsq + 1246.113 * eoc;
+            g322 = -342.585 + 1554.908 * em - 2366.899 * emsq + 1215.972 * eoc;
+            g410 = -1052.797 + 4758.686 * em - 7193.992 * emsq + 3651.957 * eoc;
+            g422 = -3581.690 + 16178.110 * em - 24462.770 * emsq + 12422.520 * eoc;
+            g520 = if em > 0.715 {
+                -5149.66 + 29936.92 * em - 54087.36 * emsq + 31324.56 * eoc
+            } else {
+                1464.74 - 4664.75 * em + 3763.64 * emsq
+            };
+        }
+        let (g533, g521, g532) = if em < 0.7 {
+            (
+                -919.22770 + 4988.6100 * em - 9064.7700 * emsq + 5542.21 * eoc,
+                -822.71072 + 4568.6173 * em - 8491.4146 * emsq + 5337.524 * eoc,
+                -853.66600 + 4690.2500 * em - 8624.7700 * emsq + 5341.4 * eoc,
+            )
+        } else {
+            (
+                -37995.780 + 161616.52 * em - 229838.20 * emsq + 109377.94 * eoc,
+                -51752.104 + 218913.95 * em - 309468.16 * emsq + 146349.42 * eoc,
+                -40023.880 + 170470.89 * em - 242699.48 * emsq + 115605.82 * eoc,
+            )
+        };
+
+        let sini2 = sinim * sinim;
+        let f220 = 0.75 * (1.0 + 2.0 * cosim + cosisq);
+        let f221 = 1.5 * sini2;
+        let f321 = 1.875 * sinim * (1.0 - 2.0 * cosim - 3.0 * cosisq);
+        let f322 = -1.875 * sinim * (1.0 + 2.0 * cosim - 3.0 * cosisq);
+        let f441 = 35.0 * sini2 * f220;
+        let f442 = 39.3750 * sini2 * sini2;
+        let f522 = 9.84375
+            * sinim
+            * (sini2 * (1.0 - 2.0 * cosim - 5.0 * cosisq)
+                + 0.33333333 * (-2.0 + 4.0 * cosim + 6.0 * cosisq));
+        let f523 = sinim
+            * (4.92187512 * sini2 * (-2.0 - 4.0 * cosim + 10.0 * cosisq)
+                + 6.56250012 * (1.0 + 2.0 * cosim - 3.0 * cosisq));
+        let f542 =
+            29.53125 * sinim * (2.0 - 8.0 * cosim + cosisq * (-12.0 + 8.0 * cosim + 10.0 * cosisq));
+        let f543 =
+            29.53125 * sinim * (-2.0 - 8.0 * cosim + cosisq * (12.0 + 8.0 * cosim - 10.0 * cosisq));
+        let xno2 = nm * nm;
+        let ainv2 = aonv * aonv;
+        let mut temp1 = 3.0 * xno2 * ainv2;
+        let mut temp = temp1 * ROOT22;
+        ds.d2201 = temp * f220 * g201;
+        ds.d2211 = temp * f221 * g211;
+        temp1 *= aonv;
+        temp = temp1 * ROOT32;
+        ds.d3210 = temp * f321 * g310;
+        ds.d3222 = temp * f322 * g322;
+        temp1 *= aonv;
+        temp = 2.0 * temp1 * ROOT44;
+        ds.d4410 = temp * f441 * g410;
+        ds.d4422 = temp * f442 * g422;
+        temp1 *= aonv;
+        temp = temp1 * ROOT52;
+        ds.d5220 = temp * f522 * g520;
+        ds.d5232 = temp * f523 * g532;
+        temp = 2.0 * temp1 * ROOT54;
+        ds.d5421 = temp * f542 * g521;
+        ds.d5433 = temp * f543 * g533;
+        ds.xlamo = (sat.mo + sat.nodeo + sat.nodeo - theta - theta) % ERFA_D2PI;
+        ds.xfact = sat.mdot + ds.dmdt + 2.0 * (sat.nodedot + ds.dnodt - RPTIM) - sat.no;
+    } else {
+        // Synchronous resonance.
+        let g200 = 1.0 + emsq * (-2.5 + 0.8125 * emsq);
+        let g310 = 1.0 + 2.0 * emsq;
+        let g300 = 1.0 + emsq * (-6.0 + 6.60937 * emsq);
+        let f220 = 0.75 * (1.0 + cosim) * (1.0 + cosim);
+        let f311 = 0.9375 * sinim * sinim * (1.0 + 3.0 * cosim) - 0.75 * (1.0 + cosim);
+        let f330 = 1.875 * (1.0 + cosim).powi(3);
+        let del1 = 3.0 * nm * nm * aonv * aonv;
+        ds.del2 = 2.0 * del1 * f220 * g200 * Q22;
+        ds.del3 = 3.0 * del1 * f330 * g300 * Q33 * aonv;
+        ds.del1 = del1 * f311 * g310 * Q31 * aonv;
+        ds.xlamo = (sat.mo + sat.nodeo + sat.argpo - theta) % ERFA_D2PI;
+        ds.xfact = sat.mdot + xpidot - RPTIM + ds.dmdt + ds.domdt + ds.dnodt - sat.no;
+    }
+}
+
+// Deep-space secular effects and the resonance integration from the epoch.
+#[allow(clippy::too_many_arguments)]
+fn dspace(
+    ds: &DeepSpace,
+    sat: &Sgp4,
+    t: f64,
+    em: &mut f64,
+    argpm: &mut f64,
+    inclm: &mut f64,
+    mm: &mut f64,
+    nodem: &mut f64,
+    nm: &mut f64,
+) {
+    const FASX2: f64 = 0.13130908;
+    const FASX4: f64 = 2.8843198;
+    const FASX6: f64 = 0.37448087;
+    const G22: f64 = 5.7686396;
+    const G32: f64 = 0.95240898;
+    const G44: f64 = 1.8014998;
+    const G52: f64 = 1.0508330;
+    const G54: f64 = 4.4108898;
+    const RPTIM: f64 = 4.375_269_088_011_3e-3;
+    const STEPP: f64 = 720.0;
+    const STEP2: f64 = 259200.0;
+
+    let theta = (sat.gsto + t * RPTIM) % ERFA_D2PI;
+    *em += ds.dedt * t;
+    *inclm += ds.didt * t;
+    *argpm += ds.domdt * t;
+    *nodem += ds.dnodt * t;
+    *mm += ds.dmdt * t;
+    if ds.irez == 0 {
+        return;
+    }
+
+    // Fixed-step integration of the resonance from the epoch.
+    let delt = if t > 0.0 { STEPP } else { -STEPP };
+    let mut atime = 0.0;
+    let mut xni = sat.no;
+    let mut xli = ds.xlamo;
+    let (xndt, xldot, xnddt, ft) = loop {
+        let (xndt, xnddt);
+        let xldot = xni + ds.xfact;
+        if ds.irez != 2 {
+            xndt = ds.del1 * (xli - FASX2).sin()
+                + ds.del2 * (2.0 * (xli - FASX4)).sin()
+                + ds.del3 * (3.0 * (xli - FASX6)).sin();
+            xnddt = (ds.del1 * (xli - FASX2).cos()
+                + 2.0 * ds.del2 * (2.0 * (xli - FASX4)).cos()
+                + 3.0 * ds.del3 * (3.0 * (xli - FASX6)).cos())
+                * xldot;
+        } else {
+            let xomi = sat.argpo + sat.argpdot * atime;
+            let x2omi = xomi + xomi;
+            let x2li = xli + xli;
+            xndt = ds.d2201 * (x2omi + xli - G22).sin()
+                + ds.d2211 * (xli - G22).sin()
+                + ds.d3210 * (xomi + xli - G32).sin()
+                + ds.d3222 * (-xomi + xli - G32).sin()
+                + ds.d4410 * (x2omi + x2li - G44).sin()
+                + ds.d4422 * (x2li - G44).sin()
+                + ds.d5220 * (xomi + xli - G52).sin()
+                + ds.d5232 * (-xomi + xli - G52).sin()
+                + ds.d5421 * (xomi + x2li - G54).sin()
+                + ds.d5433 * (-xomi + x2li - G54).sin();
+            xnddt = (ds.d2201 * (x2omi + xli - G22).cos()
+                + ds.d2211 * (xli - G22).cos()
+                + ds.d3210 * (xomi + xli - G32).cos()
+                + ds.d3222 * (-xomi + xli - G32).cos()
+                + ds.d5220 * (xomi + xli - G52).cos()
+                + ds.d5232 * (-xomi + xli - G52).cos()
+                + 2.0
+                    * (ds.d4410 * (x2omi + x2li - G44).cos()
+                        + ds.d4422 * (x2li - G44).cos()
+                        + ds.d5421 * (xomi + x2li - G54).cos()
+                        + ds.d5433 * (-xomi + x2li - G54).cos()))
+                * xldot;
+        }
+        if (t - atime).abs() < STEPP {
+            break (xndt, xldot, xnddt, t - atime);
+        }
+        xli += xldot * delt + xndt * STEP2;
+        xni += xndt * delt + xnddt * STEP2;
+        atime += delt;
+    };
+
+    *nm = xni + xndt * ft + xnddt * ft * ft * 0.5;
+    let xl = xli + xldot * ft + xndt * ft * ft * 0.5;
+    *mm = if ds.irez != 1 {
+        xl - 2.0 * *nodem + 2.0 * theta
+    } else {
+        xl - *nodem - *argpm + theta
+    };
+}
+
+// Lunar-solar periodics, applied to the elements in place.
+#[allow(clippy::too_many_arguments)]
+fn dpper(
+    ds: &DeepSpace,
+    t: f64,
+    ep: &mut f64,
+    inclp: &mut f64,
+    nodep: &mut f64,
+    argpp: &mut f64,
+    mp: &mut f64,
+) {
+    const ZNS: f64 = 1.19459e-5;
+    const ZES: f64 = 0.01675;
+    const ZNL: f64 = 1.5835218e-4;
+    const ZEL: f64 = 0.05490;
+
+    // Solar, then lunar.
+    let zm = ds.zmos + ZNS * t;
+    let zf = zm + 2.0 * ZES * zm.sin();
+    let sinzf = zf.sin();
+    let f2 = 0.5 * sinzf * sinzf - 0.25;
+    let f3 = -0.5 * sinzf * zf.cos();
+    let ses = ds.se2 * f2 + ds.se3 * f3;
+    let sis = ds.si2 * f2 + ds.si3 * f3;
+    let sls = ds.sl2 * f2 + ds.sl3 * f3 + ds.sl4 * sinzf;
+    let sghs = ds.sgh2 * f2 + ds.sgh3 * f3 + ds.sgh4 * sinzf;
+    let shs = ds.sh2 * f2 + ds.sh3 * f3;
+
+    let zm = ds.zmol + ZNL * t;
+    let zf = zm + 2.0 * ZEL * zm.sin();
+    let sinzf = zf.sin();
+    let f2 = 0.5 * sinzf * sinzf - 0.25;
+    let f3 = -0.5 * sinzf * zf.cos();
+    let sel = ds.ee2 * f2 + ds.e3 * f3;
+    let sil = ds.xi2 * f2 + ds.xi3 * f3;
+    let sll = ds.xl2 * f2 + ds.xl3 * f3 + ds.xl4 * sinzf;
+    let sghl = ds.xgh2 * f2 + ds.xgh3 * f3 + ds.xgh4 * sinzf;
+    let shll = ds.xh2 * f2 + ds.xh3 * f3;
+
+    let pe = ses + sel;
+    let pinc = sis + sil;
+    let pl = sls + sll;
+    let mut pgh = sghs + sghl;
+    let mut ph = shs + shll;
+
+    *inclp += pinc;
+    *ep += pe;
+    let (sinip, cosip) = inclp.sin_cos();
+    if *inclp >= 0.2 {
+        ph /= sinip;
+        pgh -= cosip * ph;
+        *argpp += pgh;
+        *nodep += ph;
+        *mp += pl;
+    } else {
+        // Lyddane's modification for low inclinations.
+        let (sinop, cosop) = nodep.sin_cos();
+        let mut alfdp = sinip * sinop;
+        let mut betdp = sinip * cosop;
+        let dalf = ph * cosop + pinc * cosip * sinop;
+        let dbet = -ph * sinop + pinc * cosip * cosop;
+        alfdp += dalf;
+        betdp += dbet;
+        *nodep %= ERFA_D2PI;
+        let mut xls = *mp + *argpp + cosip * *nodep;
+        let dls = pl + pgh - pinc * *nodep * sinip;
+        xls += dls;
+        let xnoh = *nodep;
+        *nodep = alfdp.atan2(betdp);
+        if (xnoh - *nodep).abs() > ERFA_DPI {
+            if *nodep < xnoh {
+                *nodep += ERFA_D2PI;
+            } else {
+                *nodep -= ERFA_D2PI;
+            }
+        }
+        *mp += pl;
+        *argpp = xls - *mp - cosip * *nodep;
+    }
+}
+
+// TEME to pseudo-Earth-fixed: rotation by the 1982 GMST.
+fn gmst_matrix(ut11: f64, ut12: f64) -> ErfaResult<[[f64; 3]; 3]> {
+    let mut r = [[0.0; 3]; 3];
+    eraIr_safe(&mut r)?;
+    eraRz_safe(eraGmst82_safe(ut11, ut12)?, &mut r)?;
+    Ok(r)
+}
+
+// TEME pv to ITRS at a UT1 date, with polar motion (radians).
+pub fn teme_to_itrs(
+    pv: &[[f64; 3]; 2],
+    ut11: f64,
+    ut12: f64,
+    xp: f64,
+    yp: f64,
+) -> ErfaResult<[[f64; 3]; 2]> {
+    let rpom = eraPom00_safe(xp, yp, 0.0)?;
+    let r = gmst_matrix(ut11, ut12)?;
+
+    // Pseudo-Earth-fixed, removing the Earth's rotation from the velocity.
+    let p = eraRxp_safe(&r, &pv[0])?;
+    let mut v = eraRxp_safe(&r, &pv[1])?;
+    v[0] += OM * p[1];
+    v[1] -= OM * p[0];
+    Ok([eraRxp_safe(&rpom, &p)?, eraRxp_safe(&rpom, &v)?])
+}
+
+// Inverse of teme_to_itrs.
+pub fn itrs_to_teme(
+    pv: &[[f64; 3]; 2],
+    ut11: f64,
+    ut12: f64,
+    xp: f64,
+    yp: f64,
+) -> ErfaResult<[[f64; 3]; 2]> {
+    let rpom = eraPom00_safe(xp, yp, 0.0)?;
+    let r = gmst_matrix(ut11, ut12)?;
+
+    let p = eraTrxp_safe(&rpom, &pv[0])?;
+    let mut v = eraTrxp_safe(&rpom, &pv[1])?;
+    v[0] -= OM * p[1];
+    v[1] += OM * p[0];
+    Ok([eraTrxp_safe(&r, &p)?, eraTrxp_safe(&r, &v)?])
+}
+
+// GCRS to TEME: bias-precession-nutation to the true equator and equinox,
+// then the equation of the equinoxes back to the mean equinox.
+fn gcrs_teme_matrix(tt1: f64, tt2: f64) -> ErfaResult<[[f64; 3]; 3]> {
+    let mut r = eraPnm06a_safe(tt1, tt2)?;
+    eraRz_safe(eraEqeq94_safe(tt1, tt2)?, &mut r)?;
+    Ok(r)
+}
+
+// TEME pv to GCRS at a TT date.
+pub fn teme_to_gcrs(pv: &[[f64; 3]; 2], tt1: f64, tt2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+    let r = gcrs_teme_matrix(tt1, tt2)?;
+    Ok([eraTrxp_safe(&r, &pv[0])?, eraTrxp_safe(&r, &pv[1])?])
+}
+
+// GCRS pv to TEME at a TT date.
+pub fn gcrs_to_teme(pv: &[[f64; 3]; 2], tt1: f64, tt2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+    let r = gcrs_teme_matrix(tt1, tt2)?;
+    Ok([eraRxp_safe(&r, &pv[0])?, eraRxp_safe(&r, &pv[1])?])
+}
+
+// Topocentric look angles of a satellite (radians, m, m/s).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LookAngles {
+    pub az: f64, // azimuth (N = 0, E = 90°)
+    pub el: f64, // geometric elevation
+    pub range: f64,
+    pub range_rate: f64, // positive receding
+}
+
+// Look angles from the site to a TEME pv at a UTC date.
+pub fn look_angles(
+    pv: &[[f64; 3]; 2],
+    site: &Site,
+    utc1: f64,
+    utc2: f64,
+) -> ErfaResult<LookAngles> {
+    let s = site;
+    let ((ut11, ut12), _) = eraUtcut1_safe(utc1, utc2, s.dut1)?;
+    let gmst = eraGmst82_safe(ut11, ut12)?;
+
+    // The site in TEME, and the line of sight.
+    let obs = eraPvtob_safe(s.elong, s.phi, s.hm, s.xp, s.yp, 0.0, gmst)?;
+    let mut rho = [0.0; 3];
+    let mut rhodot = [0.0; 3];
+    for i in 0..3 {
+        rho[i] = pv[0][i] - obs[0][i];
+        rhodot[i] = pv[1][i] - obs[1][i];
+    }
+    let range = (rho[0] * rho[0] + rho[1] * rho[1] + rho[2] * rho[2]).sqrt();
+    if range == 0.0 {
+        return Err(ErfaError::NullPosition);
+    }
+    let range_rate = (rho[0] * rhodot[0] + rho[1] * rhodot[1] + rho[2] * rhodot[2]) / range;
+
+    // Local east, north and up.
+    let rpom = eraPom00_safe(s.xp, s.yp, 0.0)?;
+    let d = eraRxp_safe(&rpom, &eraRxp_safe(&gmst_matrix(ut11, ut12)?, &rho)?)?;
+    let enu = Station::new(s.elong, s.phi, s.hm).enu_matrix();
+    let [e, n, u] = eraRxp_safe(&enu, &d)?;
+    Ok(LookAngles {
+        az: e.atan2(n).rem_euclid(ERFA_D2PI),
+        el: u.atan2(e.hypot(n)),
+        range,
+        range_rate,
+    })
+}
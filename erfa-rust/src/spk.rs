@@ -0,0 +1,338 @@
+// spk
+//   JPL planetary and lunar ephemerides (DE440, DE441, …) from SPK kernels,
+//   as an alternative to eraEpv00/eraPlan94.
+//
+//   SpkKernel::open / from_bytes → DAF file record and segment summaries;
+//                                  either byte order (LTL-IEEE, BIG-IEEE)
+//   SpkKernel::pv                → target relative to centre, chaining
+//                                  segments through the barycentre
+//   SpkKernel::epv               → heliocentric and barycentric Earth, as
+//                                  eraEpv00 returns them; also the
+//                                  Ephemeris impl for eraApc*13Eph_safe
+//
+//   Segment types 2 (Chebyshev position) and 3 (Chebyshev position and
+//   velocity) are evaluated; these are all the DE kernels use.  Segments of
+//   other types are listed but skipped.  Where segments overlap the later
+//   one wins, as in SPICE.  Opened files are read a record at a time, so
+//   DE441 does not have to fit in memory.
+//
+//   Dates are TDB two-part JDs; results are au and au/day on the kernel's
+//   axes (frame 1, J2000/ICRF, for DE kernels), ready for eraApcs/eraApco.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::G13_safe::Ephemeris;
+use crate::H1_safe::{ErfaError, ERFA_DAU, ERFA_DAYSEC, ERFA_DJ00};
+
+pub use crate::H1_safe::ErfaResult;
+
+// NAIF integer codes of the bodies in the DE kernels.
+pub const NAIF_SSB: i32 = 0;
+pub const NAIF_MERCURY_BARYCENTER: i32 = 1;
+pub const NAIF_VENUS_BARYCENTER: i32 = 2;
+pub const NAIF_EMB: i32 = 3;
+pub const NAIF_MARS_BARYCENTER: i32 = 4;
+pub const NAIF_JUPITER_BARYCENTER: i32 = 5;
+pub const NAIF_SATURN_BARYCENTER: i32 = 6;
+pub const NAIF_URANUS_BARYCENTER: i32 = 7;
+pub const NAIF_NEPTUNE_BARYCENTER: i32 = 8;
+pub const NAIF_PLUTO_BARYCENTER: i32 = 9;
+pub const NAIF_SUN: i32 = 10;
+pub const NAIF_MERCURY: i32 = 199;
+pub const NAIF_VENUS: i32 = 299;
+pub const NAIF_MOON: i32 = 301;
+pub const NAIF_EARTH: i32 = 399;
+
+const RECORD_BYTES: usize = 1024;
+const RECORD_WORDS: usize = RECORD_BYTES / 8;
+
+// Longest centre chain followed before giving up.
+const MAX_CHAIN: usize = 16;
+
+// One SPK segment.  Coverage is in TDB seconds from J2000.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpkSegment {
+    pub target: i32,
+    pub center: i32,
+    pub frame: i32,
+    pub data_type: i32,
+    pub start_et: f64,
+    pub end_et: f64,
+    start: usize, // first double-precision address (1-based)
+    end: usize,   // last
+    init: f64,    // start of the first record interval
+    intlen: f64,  // interval length (s)
+    rsize: usize, // doubles per record
+    n: usize,     // number of records
+}
+
+impl SpkSegment {
+    fn evaluable(&self) -> bool {
+        matches!(self.data_type, 2 | 3) && self.n > 0
+    }
+
+    fn covers(&self, et: f64) -> bool {
+        self.evaluable() && et >= self.start_et && et <= self.end_et
+    }
+}
+
+enum Source {
+    Bytes(Vec<u8>),
+    File(Mutex<File>),
+}
+
+pub struct SpkKernel {
+    source: Source,
+    big_endian: bool,
+    segments: Vec<SpkSegment>,
+}
+
+impl SpkKernel {
+    pub fn open<P: AsRef<Path>>(path: P) -> ErfaResult<Self> {
+        let file = File::open(path).map_err(|_| ErfaError::FileRead)?;
+        SpkKernel::from_source(Source::File(Mutex::new(file)))
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> ErfaResult<Self> {
+        SpkKernel::from_source(Source::Bytes(bytes))
+    }
+
+    fn from_source(source: Source) -> ErfaResult<Self> {
+        let mut kernel = SpkKernel {
+            source,
+            big_endian: false,
+            segments: Vec::new(),
+        };
+
+        // File record: LOCIDW, ND, NI, LOCIFN, FWARD, BWARD, FREE, LOCFMT.
+        let head = kernel.read_bytes(0, RECORD_BYTES)?;
+        if !head.starts_with(b"DAF/SPK") && !head.starts_with(b"NAIF/DAF") {
+            return Err(ErfaError::SpkFileSyntax);
+        }
+        // ND = 2 fixes the byte order, whatever LOCFMT says.
+        kernel.big_endian = match head[8..12].try_into() {
+            Ok(b) if i32::from_le_bytes(b) == 2 => false,
+            Ok(b) if i32::from_be_bytes(b) == 2 => true,
+            _ => return Err(ErfaError::SpkFileSyntax),
+        };
+        if kernel.int_at(&head, 12) != 6 {
+            return Err(ErfaError::SpkFileSyntax);
+        }
+
+        // Summary records: NEXT, PREV, NSUM, then 5 words per summary.
+        let mut rec = kernel.int_at(&head, 76);
+        let mut visited = 0;
+        while rec > 0 {
+            visited += 1;
+            if visited > 100_000 {
+                return Err(ErfaError::SpkFileSyntax);
+            }
+            let buf = kernel.read_bytes((rec as usize - 1) * RECORD_BYTES, RECORD_BYTES)?;
+            let next = kernel.f64_at(&buf, 0);
+            let nsum = kernel.f64_at(&buf, 16) as usize;
+            if nsum > (RECORD_WORDS - 3) / 5 {
+                return Err(ErfaError::SpkFileSyntax);
+            }
+            for i in 0..nsum {
+                let o = (3 + 5 * i) * 8;
+                let start = kernel.int_at(&buf, o + 32);
+                let end = kernel.int_at(&buf, o + 36);
+                if start < 1 || end.checked_sub(3).is_none_or(|e| e < start) {
+                    return Err(ErfaError::SpkFileSyntax);
+                }
+                let mut seg = SpkSegment {
+                    target: kernel.int_at(&buf, o + 16),
+                    center: kernel.int_at(&buf, o + 20),
+                    frame: kernel.int_at(&buf, o + 24),
+                    data_type: kernel.int_at(&buf, o + 28),
+                    start_et: kernel.f64_at(&buf, o),
+                    end_et: kernel.f64_at(&buf, o + 8),
+                    start: start as usize,
+                    end: end as usize,
+                    init: 0.0,
+                    intlen: 0.0,
+                    rsize: 0,
+                    n: 0,
+                };
+                if matches!(seg.data_type, 2 | 3) {
+                    // Directory at the end: INIT, INTLEN, RSIZE, N.
+                    let dir = seg.end - 3;
+                    let t = kernel.read_words(dir, 4)?;
+                    if !t[2..].iter().all(|x| x.is_finite() && *x >= 0.0) {
+                        return Err(ErfaError::SpkFileSyntax);
+                    }
+                    let (rsize, n) = (t[2] as usize, t[3] as usize);
+                    let ncomp = if seg.data_type == 2 { 3 } else { 6 };
+                    let last = rsize.checked_mul(n).and_then(|w| w.checked_add(seg.start));
+                    if t[1].is_nan()
+                        || t[1] <= 0.0
+                        || n == 0
+                        || rsize < 2 + ncomp
+                        || (rsize - 2) % ncomp != 0
+                        || last.is_none_or(|w| w > dir)
+                    {
+                        return Err(ErfaError::SpkFileSyntax);
+                    }
+                    seg.init = t[0];
+                    seg.intlen = t[1];
+                    seg.rsize = rsize;
+                    seg.n = n;
+                }
+                kernel.segments.push(seg);
+            }
+            rec = next as i32;
+        }
+        Ok(kernel)
+    }
+
+    pub fn segments(&self) -> &[SpkSegment] {
+        &self.segments
+    }
+
+    // Target relative to centre at a TDB date (au, au/d).
+    pub fn pv(&self, target: i32, center: i32, tdb1: f64, tdb2: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        let et = ((tdb1 - ERFA_DJ00) + tdb2) * ERFA_DAYSEC;
+        let t = self.barycentric_km(target, et)?;
+        let c = self.barycentric_km(center, et)?;
+        let mut pv = [[0.0; 3]; 2];
+        for i in 0..3 {
+            pv[0][i] = (t[0][i] - c[0][i]) * 1e3 / ERFA_DAU;
+            pv[1][i] = (t[1][i] - c[1][i]) * 1e3 * ERFA_DAYSEC / ERFA_DAU;
+        }
+        Ok(pv)
+    }
+
+    // Earth heliocentric and barycentric (pvh, pvb), as eraEpv00.
+    #[allow(clippy::type_complexity)]
+    pub fn epv(&self, tdb1: f64, tdb2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2])> {
+        let pvh = self.pv(NAIF_EARTH, NAIF_SUN, tdb1, tdb2)?;
+        let pvb = self.pv(NAIF_EARTH, NAIF_SSB, tdb1, tdb2)?;
+        Ok((pvh, pvb))
+    }
+
+    // Body relative to the solar-system barycentre (km, km/s).
+    fn barycentric_km(&self, body: i32, et: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        let mut pv = [[0.0; 3]; 2];
+        let mut body = body;
+        for _ in 0..MAX_CHAIN {
+            if body == NAIF_SSB {
+                return Ok(pv);
+            }
+            let seg = self
+                .segments
+                .iter()
+                .rev()
+                .find(|s| s.target == body && s.covers(et))
+                .ok_or(ErfaError::SpkOutOfRange)?;
+            let p = self.evaluate(seg, et)?;
+            for i in 0..3 {
+                pv[0][i] += p[0][i];
+                pv[1][i] += p[1][i];
+            }
+            body = seg.center;
+        }
+        Err(ErfaError::SpkFileSyntax)
+    }
+
+    // One segment at et (km, km/s).
+    fn evaluate(&self, seg: &SpkSegment, et: f64) -> ErfaResult<[[f64; 3]; 2]> {
+        let i = ((et - seg.init) / seg.intlen)
+            .floor()
+            .clamp(0.0, (seg.n - 1) as f64) as usize;
+        let rec = self.read_words(seg.start + i * seg.rsize, seg.rsize)?;
+        let (mid, radius) = (rec[0], rec[1]);
+        let t = (et - mid) / radius;
+
+        let mut pv = [[0.0; 3]; 2];
+        if seg.data_type == 2 {
+            let nc = (seg.rsize - 2) / 3;
+            for k in 0..3 {
+                let (p, dp) = chebyshev(&rec[2 + k * nc..2 + (k + 1) * nc], t);
+                pv[0][k] = p;
+                pv[1][k] = dp / radius;
+            }
+        } else {
+            let nc = (seg.rsize - 2) / 6;
+            for k in 0..6 {
+                let (p, _) = chebyshev(&rec[2 + k * nc..2 + (k + 1) * nc], t);
+                pv[k / 3][k % 3] = p;
+            }
+        }
+        Ok(pv)
+    }
+
+    // n doubles from a 1-based DAF word address.
+    fn read_words(&self, addr: usize, n: usize) -> ErfaResult<Vec<f64>> {
+        let offset = addr.checked_sub(1).and_then(|a| a.checked_mul(8));
+        let len = n.checked_mul(8);
+        let (Some(offset), Some(len)) = (offset, len) else {
+            return Err(ErfaError::SpkFileSyntax);
+        };
+        let buf = self.read_bytes(offset, len)?;
+        Ok((0..n).map(|i| self.f64_at(&buf, 8 * i)).collect())
+    }
+
+    fn read_bytes(&self, offset: usize, len: usize) -> ErfaResult<Vec<u8>> {
+        match &self.source {
+            Source::Bytes(b) => b
+                .get(offset..offset.checked_add(len).ok_or(ErfaError::SpkFileSyntax)?)
+                .map(|s| s.to_vec())
+                .ok_or(ErfaError::SpkFileSyntax),
+            Source::File(f) => {
+                let mut f = f.lock().map_err(|_| ErfaError::FileRead)?;
+                let mut buf = vec![0u8; len];
+                f.seek(SeekFrom::Start(offset as u64))
+                    .and_then(|_| f.read_exact(&mut buf))
+                    .map_err(|_| ErfaError::FileRead)?;
+                Ok(buf)
+            }
+        }
+    }
+
+    fn f64_at(&self, b: &[u8], o: usize) -> f64 {
+        let w: [u8; 8] = b[o..o + 8].try_into().unwrap_or_default();
+        if self.big_endian {
+            f64::from_be_bytes(w)
+        } else {
+            f64::from_le_bytes(w)
+        }
+    }
+
+    fn int_at(&self, b: &[u8], o: usize) -> i32 {
+        let w: [u8; 4] = b[o..o + 4].try_into().unwrap_or_default();
+        if self.big_endian {
+            i32::from_be_bytes(w)
+        } else {
+            i32::from_le_bytes(w)
+        }
+    }
+}
+
+impl Ephemeris for SpkKernel {
+    fn earth_pv(&self, date1: f64, date2: f64) -> ErfaResult<([[f64; 3]; 2], [[f64; 3]; 2])> {
+        self.epv(date1, date2)
+    }
+}
+
+// Chebyshev series and its derivative at t in [−1, 1].
+fn chebyshev(c: &[f64], t: f64) -> (f64, f64) {
+    let (mut p0, mut p1) = (1.0, t);
+    let (mut d0, mut d1) = (0.0, 1.0);
+    let mut p = c[0];
+    let mut d = 0.0;
+    if c.len() > 1 {
+        p += c[1] * t;
+        d += c[1];
+    }
+    for &ck in c.iter().skip(2) {
+        let p2 = 2.0 * t * p1 - p0;
+        let d2 = 2.0 * p1 + 2.0 * t * d1 - d0;
+        p += ck * p2;
+        d += ck * d2;
+        (p0, p1, d0, d1) = (p1, p2, d1, d2);
+    }
+    (p, d)
+}
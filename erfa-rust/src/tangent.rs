@@ -0,0 +1,169 @@
+// tangent
+//   Local tangent-plane coordinates of Earth-fixed (ITRS) positions and
+//   velocities, relative to a station.
+//
+//   Station                     → geodetic site on any Ellipsoid
+//   itrs_to_enu / enu_to_itrs   → east, north, up
+//   itrs_to_ned / ned_to_itrs   → north, east, down
+//   Aer                         → azimuth, elevation, range and their rates
+//   enu_to_aer / aer_to_enu     → between the two local forms
+//   itrs_to_aer / aer_to_itrs   → directly from and to ITRS
+//
+//   The station is fixed in ITRS, so velocities are Earth-fixed ones; for a
+//   satellite state in an inertial frame take it to ITRS first (for TEME,
+//   sgp4::teme_to_itrs, which removes ω×r).  Up is the ellipsoid normal, so
+//   elevations are geodetic, as for eraHd2ae with a geodetic latitude, and
+//   azimuths run from north through east.  Positions are in metres,
+//   velocities in m/s and rates in rad/s; range rate is positive receding.
+//   At the zenith the azimuth and its rate are undefined and returned as
+//   zero, and so is the elevation rate.
+
+use crate::G11_safe::Ellipsoid;
+use crate::G17_safe::eraGd2gcEll_safe;
+use crate::G28_safe::eraRxp_safe;
+use crate::G33_safe::eraTrxp_safe;
+use crate::H1_safe::{ErfaError, ERFA_D2PI};
+
+pub use crate::H1_safe::ErfaResult;
+
+// A station: east longitude, geodetic latitude (radians) and height
+// above the ellipsoid (m).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Station {
+    pub elong: f64,
+    pub phi: f64,
+    pub hm: f64,
+    pub ellipsoid: Ellipsoid,
+}
+
+impl Station {
+    // Station on WGS84.
+    pub fn new(elong: f64, phi: f64, hm: f64) -> Station {
+        Station::on(Ellipsoid::WGS84, elong, phi, hm)
+    }
+
+    pub fn on(ellipsoid: Ellipsoid, elong: f64, phi: f64, hm: f64) -> Station {
+        Station {
+            elong,
+            phi,
+            hm,
+            ellipsoid,
+        }
+    }
+
+    // ITRS position (m).
+    pub fn position(&self) -> ErfaResult<[f64; 3]> {
+        eraGd2gcEll_safe(&self.ellipsoid, self.elong, self.phi, self.hm)
+    }
+
+    // Rotation from ITRS to east, north, up.
+    pub fn enu_matrix(&self) -> [[f64; 3]; 3] {
+        let (sl, cl) = self.elong.sin_cos();
+        let (sp, cp) = self.phi.sin_cos();
+        [
+            [-sl, cl, 0.0],
+            [-sp * cl, -sp * sl, cp],
+            [cp * cl, cp * sl, sp],
+        ]
+    }
+}
+
+// Azimuth and elevation (radians), range (m), and their rates.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aer {
+    pub az: f64, // 0 to 2π, N = 0, E = π/2
+    pub el: f64,
+    pub range: f64,
+    pub az_rate: f64,
+    pub el_rate: f64,
+    pub range_rate: f64,
+}
+
+// East, north, up pv of an ITRS pv (m, m/s) relative to the station.
+pub fn itrs_to_enu(station: &Station, pv: &[[f64; 3]; 2]) -> ErfaResult<[[f64; 3]; 2]> {
+    let o = station.position()?;
+    let r = station.enu_matrix();
+    let d = [pv[0][0] - o[0], pv[0][1] - o[1], pv[0][2] - o[2]];
+    Ok([eraRxp_safe(&r, &d)?, eraRxp_safe(&r, &pv[1])?])
+}
+
+// ITRS pv of an east, north, up pv relative to the station.
+pub fn enu_to_itrs(station: &Station, enu: &[[f64; 3]; 2]) -> ErfaResult<[[f64; 3]; 2]> {
+    let o = station.position()?;
+    let r = station.enu_matrix();
+    let d = eraTrxp_safe(&r, &enu[0])?;
+    Ok([
+        [o[0] + d[0], o[1] + d[1], o[2] + d[2]],
+        eraTrxp_safe(&r, &enu[1])?,
+    ])
+}
+
+// North, east, down pv of an ITRS pv relative to the station.
+pub fn itrs_to_ned(station: &Station, pv: &[[f64; 3]; 2]) -> ErfaResult<[[f64; 3]; 2]> {
+    Ok(swap_enu_ned(&itrs_to_enu(station, pv)?))
+}
+
+// ITRS pv of a north, east, down pv relative to the station.
+pub fn ned_to_itrs(station: &Station, ned: &[[f64; 3]; 2]) -> ErfaResult<[[f64; 3]; 2]> {
+    enu_to_itrs(station, &swap_enu_ned(ned))
+}
+
+// Azimuth, elevation, range and rates of an east, north, up pv.
+pub fn enu_to_aer(enu: &[[f64; 3]; 2]) -> ErfaResult<Aer> {
+    let [e, n, u] = enu[0];
+    let [de, dn, du] = enu[1];
+    let h2 = e * e + n * n;
+    let r2 = h2 + u * u;
+    if r2 == 0.0 {
+        return Err(ErfaError::NullPosition);
+    }
+    let (h, range) = (h2.sqrt(), r2.sqrt());
+    let mut aer = Aer {
+        el: u.atan2(h),
+        range,
+        range_rate: (e * de + n * dn + u * du) / range,
+        ..Aer::default()
+    };
+    if h > 0.0 {
+        aer.az = e.atan2(n).rem_euclid(ERFA_D2PI);
+        aer.az_rate = (n * de - e * dn) / h2;
+        aer.el_rate = (du * h2 - u * (e * de + n * dn)) / (r2 * h);
+    }
+    Ok(aer)
+}
+
+// East, north, up pv of an azimuth, elevation, range and rates.
+pub fn aer_to_enu(aer: &Aer) -> [[f64; 3]; 2] {
+    let (sa, ca) = aer.az.sin_cos();
+    let (se, ce) = aer.el.sin_cos();
+    let r = aer.range;
+
+    // Rates of r cos E and r sin E.
+    let dh = aer.range_rate * ce - r * se * aer.el_rate;
+    let du = aer.range_rate * se + r * ce * aer.el_rate;
+    let h = r * ce;
+    [
+        [h * sa, h * ca, r * se],
+        [
+            dh * sa + h * ca * aer.az_rate,
+            dh * ca - h * sa * aer.az_rate,
+            du,
+        ],
+    ]
+}
+
+// Azimuth, elevation, range and rates of an ITRS pv from the station.
+pub fn itrs_to_aer(station: &Station, pv: &[[f64; 3]; 2]) -> ErfaResult<Aer> {
+    enu_to_aer(&itrs_to_enu(station, pv)?)
+}
+
+// ITRS pv of an azimuth, elevation, range and rates from the station.
+pub fn aer_to_itrs(station: &Station, aer: &Aer) -> ErfaResult<[[f64; 3]; 2]> {
+    enu_to_itrs(station, &aer_to_enu(aer))
+}
+
+// East, north, up ↔ north, east, down (its own inverse).
+fn swap_enu_ned(pv: &[[f64; 3]; 2]) -> [[f64; 3]; 2] {
+    let f = |p: &[f64; 3]| [p[1], p[0], -p[2]];
+    [f(&pv[0]), f(&pv[1])]
+}
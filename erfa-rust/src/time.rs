@@ -0,0 +1,371 @@
+// time
+//   Two-part Julian Date and scale-tagged wrappers over G32/G33.
+//
+//   JulianDate           → (jd1, jd2) pair as used by every ERFA time routine
+//   Tai Tt Utc Ut1       → scale-tagged dates; conversions call the G32/G33
+//   Tdb Tcb Tcg            routines, so dates in different scales cannot mix
+//   convert              → route between any two scales (see time/convert.rs)
+//   leap                 → leap-seconds.list / Leap_Second.dat readers
+//   delta_t              → historical ΔT models for TT↔UT1 without IERS data
+//   gnss                 → GPS/Galileo/BeiDou week and seconds of week
+//   unix                 → Unix and NTP seconds
+//   iso                  → ISO 8601 / FITS DATE-OBS strings
+
+pub mod convert;
+pub mod delta_t;
+pub mod gnss;
+pub mod iso;
+pub mod leap;
+pub mod unix;
+
+use std::ops::Sub;
+
+use crate::G10_safe::eraDtf2d_safe;
+use crate::G14_safe::LeapSecondTable;
+use crate::G19_safe::eraJd2cal_safe;
+use crate::G32_safe::{
+    eraTaitt_safe, eraTaiut1_safe, eraTaiutcTable_safe, eraTaiutc_safe, eraTcbtdb_safe,
+    eraTcgtt_safe, eraTdbtcb_safe, eraTdbtt_safe,
+};
+use crate::G33_safe::{
+    eraTttai_safe, eraTttcg_safe, eraTttdb_safe, eraTtut1_safe, eraUt1tai_safe, eraUt1tt_safe,
+    eraUt1utc_safe, eraUtctaiTable_safe, eraUtctai_safe, eraUtcut1_safe,
+};
+use crate::G8_safe::eraCal2jd_safe;
+use crate::G9_safe::eraD2dtf_safe;
+use crate::H1_safe::{DatStatus, Dtf2dStatus, ERFA_DJ00, ERFA_DJM0};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Two-part Julian Date; the split is preserved through every conversion.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct JulianDate {
+    pub jd1: f64,
+    pub jd2: f64,
+}
+
+impl JulianDate {
+    pub const fn new(jd1: f64, jd2: f64) -> Self {
+        JulianDate { jd1, jd2 }
+    }
+
+    // J2000.0 split as (2451545.0, 0.0).
+    pub const fn j2000() -> Self {
+        JulianDate::new(ERFA_DJ00, 0.0)
+    }
+
+    // Modified Julian Date, split as (2400000.5, mjd).
+    pub const fn from_mjd(mjd: f64) -> Self {
+        JulianDate::new(ERFA_DJM0, mjd)
+    }
+
+    // Gregorian calendar date at 0h, via eraCal2jd.
+    pub fn from_calendar(iy: i32, im: i32, id: i32) -> ErfaResult<Self> {
+        let (djm0, djm) = eraCal2jd_safe(iy, im, id)?;
+        Ok(JulianDate::new(djm0, djm))
+    }
+
+    // Gregorian calendar date and fraction of day, via eraJd2cal.
+    pub fn to_calendar(self) -> ErfaResult<((i32, i32, i32), f64)> {
+        eraJd2cal_safe(self.jd1, self.jd2)
+    }
+
+    // Single-number JD (loses the precision the split provides).
+    pub fn jd(self) -> f64 {
+        self.jd1 + self.jd2
+    }
+
+    pub fn mjd(self) -> f64 {
+        (self.jd1 - ERFA_DJM0) + self.jd2
+    }
+
+    // Offset by a number of days, applied to the smaller part.
+    pub fn add_days(self, days: f64) -> Self {
+        if self.jd1.abs() >= self.jd2.abs() {
+            JulianDate::new(self.jd1, self.jd2 + days)
+        } else {
+            JulianDate::new(self.jd1 + days, self.jd2)
+        }
+    }
+
+    // Difference self − other in days, subtracting like parts first.
+    pub fn days_since(self, other: JulianDate) -> f64 {
+        (self.jd1 - other.jd1) + (self.jd2 - other.jd2)
+    }
+}
+
+impl From<(f64, f64)> for JulianDate {
+    fn from((jd1, jd2): (f64, f64)) -> Self {
+        JulianDate::new(jd1, jd2)
+    }
+}
+
+impl From<JulianDate> for (f64, f64) {
+    fn from(jd: JulianDate) -> Self {
+        (jd.jd1, jd.jd2)
+    }
+}
+
+// Runtime identifier for the scales above.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    Tai,
+    Tt,
+    Utc,
+    Ut1,
+    Tdb,
+    Tcb,
+    Tcg,
+}
+
+impl TimeScale {
+    pub const ALL: [TimeScale; 7] = [
+        TimeScale::Tai,
+        TimeScale::Tt,
+        TimeScale::Utc,
+        TimeScale::Ut1,
+        TimeScale::Tdb,
+        TimeScale::Tcb,
+        TimeScale::Tcg,
+    ];
+
+    // ERFA scale label, as accepted by eraD2dtf/eraDtf2d.
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeScale::Tai => "TAI",
+            TimeScale::Tt => "TT",
+            TimeScale::Utc => "UTC",
+            TimeScale::Ut1 => "UT1",
+            TimeScale::Tdb => "TDB",
+            TimeScale::Tcb => "TCB",
+            TimeScale::Tcg => "TCG",
+        }
+    }
+}
+
+// Calendar date and (hours, minutes, seconds, fraction) as from eraD2dtf.
+pub type CalendarFields = ((i32, i32, i32), [i32; 4]);
+
+// Scale-tagged date: a JulianDate that only converts through named methods.
+macro_rules! time_scale {
+    ($name:ident, $label:literal, $kind:expr) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $name(pub JulianDate);
+
+        impl $name {
+            // ERFA scale label, as accepted by eraD2dtf/eraDtf2d.
+            pub const SCALE: &'static str = $label;
+            pub const KIND: TimeScale = $kind;
+
+            pub const fn new(jd1: f64, jd2: f64) -> Self {
+                $name(JulianDate::new(jd1, jd2))
+            }
+
+            pub const fn from_jd(jd: JulianDate) -> Self {
+                $name(jd)
+            }
+
+            pub fn jd(self) -> JulianDate {
+                self.0
+            }
+
+            pub fn jd1(self) -> f64 {
+                self.0.jd1
+            }
+
+            pub fn jd2(self) -> f64 {
+                self.0.jd2
+            }
+
+            pub fn add_days(self, days: f64) -> Self {
+                $name(self.0.add_days(days))
+            }
+        }
+
+        impl From<$name> for JulianDate {
+            fn from(t: $name) -> Self {
+                t.0
+            }
+        }
+
+        // Interval in days between two dates on the same scale.
+        impl Sub for $name {
+            type Output = f64;
+
+            fn sub(self, rhs: $name) -> f64 {
+                self.0.days_since(rhs.0)
+            }
+        }
+    };
+}
+
+time_scale!(Tai, "TAI", TimeScale::Tai);
+time_scale!(Tt, "TT", TimeScale::Tt);
+time_scale!(Utc, "UTC", TimeScale::Utc);
+time_scale!(Ut1, "UT1", TimeScale::Ut1);
+time_scale!(Tdb, "TDB", TimeScale::Tdb);
+time_scale!(Tcb, "TCB", TimeScale::Tcb);
+time_scale!(Tcg, "TCG", TimeScale::Tcg);
+
+// Calendar + clock for scales that eraDtf2d/eraD2dtf treat uniformly.
+macro_rules! calendar_fields {
+    ($name:ident) => {
+        impl $name {
+            pub fn from_calendar(
+                iy: i32,
+                im: i32,
+                id: i32,
+                ihr: i32,
+                imn: i32,
+                sec: f64,
+            ) -> ErfaResult<(Self, Dtf2dStatus)> {
+                let ((d1, d2), js) = eraDtf2d_safe(Self::SCALE, iy, im, id, ihr, imn, sec)?;
+                Ok(($name::new(d1, d2), js))
+            }
+
+            pub fn to_calendar(self, ndp: i32) -> ErfaResult<CalendarFields> {
+                let (ymd, ihmsf, _js) = eraD2dtf_safe(Self::SCALE, ndp, self.jd1(), self.jd2())?;
+                Ok((ymd, ihmsf))
+            }
+        }
+    };
+}
+
+calendar_fields!(Tai);
+calendar_fields!(Tt);
+calendar_fields!(Ut1);
+calendar_fields!(Tdb);
+calendar_fields!(Tcb);
+calendar_fields!(Tcg);
+
+impl Tai {
+    pub fn to_tt(self) -> ErfaResult<Tt> {
+        let (tt1, tt2) = eraTaitt_safe(self.jd1(), self.jd2())?;
+        Ok(Tt::new(tt1, tt2))
+    }
+
+    pub fn to_utc(self) -> ErfaResult<(Utc, DatStatus)> {
+        let ((utc1, utc2), js) = eraTaiutc_safe(self.jd1(), self.jd2())?;
+        Ok((Utc::new(utc1, utc2), js))
+    }
+
+    // As to_utc, with leap seconds from `table` rather than the global one.
+    pub fn to_utc_in(self, table: &LeapSecondTable) -> ErfaResult<(Utc, DatStatus)> {
+        let ((utc1, utc2), js) = eraTaiutcTable_safe(table, self.jd1(), self.jd2())?;
+        Ok((Utc::new(utc1, utc2), js))
+    }
+
+    // dta = UT1−TAI (s).
+    pub fn to_ut1(self, dta: f64) -> ErfaResult<Ut1> {
+        let (ut11, ut12) = eraTaiut1_safe(self.jd1(), self.jd2(), dta)?;
+        Ok(Ut1::new(ut11, ut12))
+    }
+}
+
+impl Tt {
+    pub fn to_tai(self) -> ErfaResult<Tai> {
+        let (tai1, tai2) = eraTttai_safe(self.jd1(), self.jd2())?;
+        Ok(Tai::new(tai1, tai2))
+    }
+
+    pub fn to_tcg(self) -> ErfaResult<Tcg> {
+        let (tcg1, tcg2) = eraTttcg_safe(self.jd1(), self.jd2())?;
+        Ok(Tcg::new(tcg1, tcg2))
+    }
+
+    // dtr = TDB−TT (s), e.g. from eraDtdb.
+    pub fn to_tdb(self, dtr: f64) -> ErfaResult<Tdb> {
+        let (tdb1, tdb2) = eraTttdb_safe(self.jd1(), self.jd2(), dtr)?;
+        Ok(Tdb::new(tdb1, tdb2))
+    }
+
+    // dt = TT−UT1 (s).
+    pub fn to_ut1(self, dt: f64) -> ErfaResult<Ut1> {
+        let (ut11, ut12) = eraTtut1_safe(self.jd1(), self.jd2(), dt)?;
+        Ok(Ut1::new(ut11, ut12))
+    }
+}
+
+impl Utc {
+    // UTC calendar + clock, honouring a 61 s final minute on leap-second days.
+    pub fn from_calendar(
+        iy: i32,
+        im: i32,
+        id: i32,
+        ihr: i32,
+        imn: i32,
+        sec: f64,
+    ) -> ErfaResult<(Self, Dtf2dStatus)> {
+        let ((d1, d2), js) = eraDtf2d_safe(Self::SCALE, iy, im, id, ihr, imn, sec)?;
+        Ok((Utc::new(d1, d2), js))
+    }
+
+    pub fn to_calendar(self, ndp: i32) -> ErfaResult<(CalendarFields, DatStatus)> {
+        let (ymd, ihmsf, js) = eraD2dtf_safe(Self::SCALE, ndp, self.jd1(), self.jd2())?;
+        Ok(((ymd, ihmsf), js))
+    }
+
+    pub fn to_tai(self) -> ErfaResult<(Tai, DatStatus)> {
+        let ((tai1, tai2), js) = eraUtctai_safe(self.jd1(), self.jd2())?;
+        Ok((Tai::new(tai1, tai2), js))
+    }
+
+    // As to_tai, with leap seconds from `table` rather than the global one.
+    pub fn to_tai_in(self, table: &LeapSecondTable) -> ErfaResult<(Tai, DatStatus)> {
+        let ((tai1, tai2), js) = eraUtctaiTable_safe(table, self.jd1(), self.jd2())?;
+        Ok((Tai::new(tai1, tai2), js))
+    }
+
+    // dut1 = UT1−UTC (s).
+    pub fn to_ut1(self, dut1: f64) -> ErfaResult<(Ut1, DatStatus)> {
+        let ((ut11, ut12), js) = eraUtcut1_safe(self.jd1(), self.jd2(), dut1)?;
+        Ok((Ut1::new(ut11, ut12), js))
+    }
+}
+
+impl Ut1 {
+    // dta = UT1−TAI (s).
+    pub fn to_tai(self, dta: f64) -> ErfaResult<Tai> {
+        let (tai1, tai2) = eraUt1tai_safe(self.jd1(), self.jd2(), dta)?;
+        Ok(Tai::new(tai1, tai2))
+    }
+
+    // dt = TT−UT1 (s).
+    pub fn to_tt(self, dt: f64) -> ErfaResult<Tt> {
+        let (tt1, tt2) = eraUt1tt_safe(self.jd1(), self.jd2(), dt)?;
+        Ok(Tt::new(tt1, tt2))
+    }
+
+    // dut1 = UT1−UTC (s).
+    pub fn to_utc(self, dut1: f64) -> ErfaResult<(Utc, DatStatus)> {
+        let ((utc1, utc2), js) = eraUt1utc_safe(self.jd1(), self.jd2(), dut1)?;
+        Ok((Utc::new(utc1, utc2), js))
+    }
+}
+
+impl Tdb {
+    pub fn to_tcb(self) -> ErfaResult<Tcb> {
+        let (tcb1, tcb2) = eraTdbtcb_safe(self.jd1(), self.jd2())?;
+        Ok(Tcb::new(tcb1, tcb2))
+    }
+
+    // dtr = TDB−TT (s).
+    pub fn to_tt(self, dtr: f64) -> ErfaResult<Tt> {
+        let (tt1, tt2) = eraTdbtt_safe(self.jd1(), self.jd2(), dtr)?;
+        Ok(Tt::new(tt1, tt2))
+    }
+}
+
+impl Tcb {
+    pub fn to_tdb(self) -> ErfaResult<Tdb> {
+        let (tdb1, tdb2) = eraTcbtdb_safe(self.jd1(), self.jd2())?;
+        Ok(Tdb::new(tdb1, tdb2))
+    }
+}
+
+impl Tcg {
+    pub fn to_tt(self) -> ErfaResult<Tt> {
+        let (tt1, tt2) = eraTcgtt_safe(self.jd1(), self.jd2())?;
+        Ok(Tt::new(tt1, tt2))
+    }
+}
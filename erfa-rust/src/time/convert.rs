@@ -0,0 +1,220 @@
+// time/convert
+//   Route a date between any two of TAI/TT/UTC/UT1/TDB/TCB/TCG by
+//   chaining the single-step G32/G33 routines.
+//
+//   TimeContext   → extras some steps need (DUT1, TDB−TT or site for eraDtdb)
+//   convert       → runtime scales, returns the converted JulianDate
+//   ScaledDate    → typed form: Utc::to::<Tdb>(&ctx)
+
+use std::collections::VecDeque;
+
+use crate::G10_safe::eraDtdb_safe;
+use crate::G17_safe::eraGd2gc_safe;
+use crate::G32_safe::{
+    eraTaitt_safe, eraTaiutc_safe, eraTcbtdb_safe, eraTcgtt_safe, eraTdbtcb_safe, eraTdbtt_safe,
+};
+use crate::G33_safe::{
+    eraTttai_safe, eraTttcg_safe, eraTttdb_safe, eraUt1utc_safe, eraUtctai_safe, eraUtcut1_safe,
+};
+use crate::H1_safe::{DatStatus, ErfaError, ERFA_WGS84};
+
+use super::{JulianDate, Tai, Tcb, Tcg, Tdb, TimeScale, Tt, Ut1, Utc};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Observer site for the topocentric terms of eraDtdb.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ObserverLocation {
+    pub elong: f64, // east longitude (radians)
+    pub u: f64,     // distance from Earth spin axis (km)
+    pub v: f64,     // distance north of equatorial plane (km)
+}
+
+impl ObserverLocation {
+    // From WGS84 geodetic longitude, latitude (radians) and height (m).
+    pub fn from_geodetic(elong: f64, phi: f64, height: f64) -> ErfaResult<Self> {
+        let xyz = eraGd2gc_safe(ERFA_WGS84, elong, phi, height)?;
+        Ok(ObserverLocation {
+            elong,
+            u: (xyz[0] * xyz[0] + xyz[1] * xyz[1]).sqrt() / 1000.0,
+            v: xyz[2] / 1000.0,
+        })
+    }
+}
+
+// Extra quantities needed by some conversion steps.
+// Without dtr, TDB−TT comes from eraDtdb at the location (geocentre if none).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimeContext {
+    pub dut1: Option<f64>,                  // UT1−UTC (s)
+    pub dtr: Option<f64>,                   // TDB−TT (s)
+    pub location: Option<ObserverLocation>, // site for eraDtdb
+}
+
+impl TimeContext {
+    pub fn with_dut1(mut self, dut1: f64) -> Self {
+        self.dut1 = Some(dut1);
+        self
+    }
+
+    pub fn with_dtr(mut self, dtr: f64) -> Self {
+        self.dtr = Some(dtr);
+        self
+    }
+
+    pub fn with_location(mut self, location: ObserverLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    fn dut1(&self) -> ErfaResult<f64> {
+        self.dut1.ok_or(ErfaError::MissingDut1)
+    }
+
+    // TDB−TT (s) at a TT or TDB date; either is adequate for eraDtdb.
+    fn dtr(&self, jd: JulianDate) -> ErfaResult<f64> {
+        if let Some(dtr) = self.dtr {
+            return Ok(dtr);
+        }
+        let site = self.location.unwrap_or_default();
+        // UT1 fraction of day approximated from the TT/TDB date.
+        let ut = ((jd.jd1 - 0.5).fract() + jd.jd2.fract()).rem_euclid(1.0);
+        eraDtdb_safe(jd.jd1, jd.jd2, ut, site.elong, site.u, site.v)
+    }
+}
+
+// Direct steps, each one G32/G33 call in either direction.
+const EDGES: [(TimeScale, TimeScale); 6] = [
+    (TimeScale::Tai, TimeScale::Tt),
+    (TimeScale::Tai, TimeScale::Utc),
+    (TimeScale::Utc, TimeScale::Ut1),
+    (TimeScale::Tt, TimeScale::Tcg),
+    (TimeScale::Tt, TimeScale::Tdb),
+    (TimeScale::Tdb, TimeScale::Tcb),
+];
+
+fn index(scale: TimeScale) -> usize {
+    TimeScale::ALL.iter().position(|&s| s == scale).unwrap_or(0)
+}
+
+// Shortest chain of scales from `from` to `to`, both ends included.
+pub fn route(from: TimeScale, to: TimeScale) -> Vec<TimeScale> {
+    let mut prev: [Option<TimeScale>; 7] = [None; 7];
+    let mut seen = [false; 7];
+    let mut queue = VecDeque::new();
+    seen[index(from)] = true;
+    queue.push_back(from);
+
+    while let Some(s) = queue.pop_front() {
+        if s == to {
+            break;
+        }
+        for &(a, b) in EDGES.iter() {
+            let next = if a == s {
+                b
+            } else if b == s {
+                a
+            } else {
+                continue;
+            };
+            if !seen[index(next)] {
+                seen[index(next)] = true;
+                prev[index(next)] = Some(s);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut path = vec![to];
+    let mut s = to;
+    while let Some(p) = prev[index(s)] {
+        path.push(p);
+        s = p;
+    }
+    path.reverse();
+    path
+}
+
+// One direct step between adjacent scales.
+fn step(
+    jd: JulianDate,
+    from: TimeScale,
+    to: TimeScale,
+    ctx: &TimeContext,
+) -> ErfaResult<(JulianDate, DatStatus)> {
+    use TimeScale::*;
+    let (d1, d2) = (jd.jd1, jd.jd2);
+    let ok = |(a, b): (f64, f64)| (JulianDate::new(a, b), DatStatus::Ok);
+    let warn = |((a, b), js): ((f64, f64), DatStatus)| (JulianDate::new(a, b), js);
+
+    let out = match (from, to) {
+        (Tai, Tt) => ok(eraTaitt_safe(d1, d2)?),
+        (Tt, Tai) => ok(eraTttai_safe(d1, d2)?),
+        (Tai, Utc) => warn(eraTaiutc_safe(d1, d2)?),
+        (Utc, Tai) => warn(eraUtctai_safe(d1, d2)?),
+        (Utc, Ut1) => warn(eraUtcut1_safe(d1, d2, ctx.dut1()?)?),
+        (Ut1, Utc) => warn(eraUt1utc_safe(d1, d2, ctx.dut1()?)?),
+        (Tt, Tcg) => ok(eraTttcg_safe(d1, d2)?),
+        (Tcg, Tt) => ok(eraTcgtt_safe(d1, d2)?),
+        (Tt, Tdb) => ok(eraTttdb_safe(d1, d2, ctx.dtr(jd)?)?),
+        (Tdb, Tt) => ok(eraTdbtt_safe(d1, d2, ctx.dtr(jd)?)?),
+        (Tdb, Tcb) => ok(eraTdbtcb_safe(d1, d2)?),
+        (Tcb, Tdb) => ok(eraTcbtdb_safe(d1, d2)?),
+        _ => return Err(ErfaError::InternalError),
+    };
+    Ok(out)
+}
+
+// Convert a two-part JD between any two scales.
+// The status is the last warning any UTC step along the route reported.
+pub fn convert(
+    jd: JulianDate,
+    from: TimeScale,
+    to: TimeScale,
+    ctx: &TimeContext,
+) -> ErfaResult<(JulianDate, DatStatus)> {
+    let path = route(from, to);
+    let mut out = jd;
+    let mut js = DatStatus::Ok;
+    for pair in path.windows(2) {
+        let (next, j) = step(out, pair[0], pair[1], ctx)?;
+        if j != DatStatus::Ok {
+            js = j;
+        }
+        out = next;
+    }
+    Ok((out, js))
+}
+
+// Scale-tagged dates that can be routed generically.
+pub trait ScaledDate: Copy {
+    const KIND: TimeScale;
+
+    fn from_jd(jd: JulianDate) -> Self;
+    fn jd(self) -> JulianDate;
+
+    fn to<S: ScaledDate>(self, ctx: &TimeContext) -> ErfaResult<(S, DatStatus)> {
+        let (jd, js) = convert(self.jd(), Self::KIND, S::KIND, ctx)?;
+        Ok((S::from_jd(jd), js))
+    }
+}
+
+macro_rules! scaled_date {
+    ($($name:ident),*) => {
+        $(
+            impl ScaledDate for $name {
+                const KIND: TimeScale = $name::KIND;
+
+                fn from_jd(jd: JulianDate) -> Self {
+                    $name(jd)
+                }
+
+                fn jd(self) -> JulianDate {
+                    self.0
+                }
+            }
+        )*
+    };
+}
+
+scaled_date!(Tai, Tt, Utc, Ut1, Tdb, Tcb, Tcg);
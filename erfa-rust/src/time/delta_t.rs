@@ -0,0 +1,253 @@
+// time/delta_t
+//   ΔT = TT−UT1 from historical models, for dates without IERS data.
+//
+//   EspenakMeeus           → NASA eclipse-canon polynomials (−1999 … +3000)
+//   MorrisonStephenson2004 → cubic spline through the MS2004 centennial
+//                            table (−1000 … 1600)
+//   Stephenson2016         → spline of Stephenson, Morrison & Hohenkerk
+//                            (2016), Table S15 segments −720 … 1810
+//   Parabola               → long-term −20 + 32u², u = (y − 1820)/100
+//
+//   The spline models fall back to their parabola before the first knot and
+//   to the Espenak–Meeus fits of the telescopic record after the last.  The
+//   SMH2016 parabola is shifted to meet the spline at −720, where it would
+//   otherwise stand about 358 s above it.  S15 continues to 2019 but only
+//   its segments to 1810 are carried here, so Stephenson2016 hands over
+//   through Espenak–Meeus offset by their difference at 1810 (about 3.2 s),
+//   the offset tapering linearly to zero over 20 years; the model stays
+//   continuous, and further S15 rows extend the spline with the hand-off
+//   moving to their last knot.
+//   ttut1/ut1tt apply a model through eraTtut1_safe/eraUt1tt_safe.
+
+use crate::G33_safe::{eraTtut1_safe, eraUt1tt_safe};
+use crate::H1_safe::{ERFA_DJ00, ERFA_DJY};
+
+use super::{JulianDate, Tt, Ut1};
+
+pub use crate::H1_safe::ErfaResult;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DeltaTModel {
+    #[default]
+    EspenakMeeus,
+    MorrisonStephenson2004,
+    Stephenson2016,
+    Parabola,
+}
+
+impl DeltaTModel {
+    // ΔT (s) at a decimal year (Julian epoch is adequate).
+    pub fn at_year(self, y: f64) -> f64 {
+        match self {
+            DeltaTModel::EspenakMeeus => espenak_meeus(y),
+            DeltaTModel::MorrisonStephenson2004 => morrison_stephenson_2004(y),
+            DeltaTModel::Stephenson2016 => stephenson_2016(y),
+            DeltaTModel::Parabola => parabola_2004(y),
+        }
+    }
+
+    // ΔT (s) at a two-part JD in TT or UT1; the model cannot tell them apart.
+    pub fn at(self, date: JulianDate) -> f64 {
+        self.at_year(2000.0 + ((date.jd1 - ERFA_DJ00) + date.jd2) / ERFA_DJY)
+    }
+}
+
+// TT → UT1 with ΔT from the model, evaluated at the UT1 date.
+pub fn ttut1(model: DeltaTModel, tt1: f64, tt2: f64) -> ErfaResult<(f64, f64)> {
+    let tt = JulianDate::new(tt1, tt2);
+    let dt = model.at(tt.add_days(-model.at(tt) / 86_400.0));
+    eraTtut1_safe(tt1, tt2, dt)
+}
+
+// UT1 → TT with ΔT from the model.
+pub fn ut1tt(model: DeltaTModel, ut11: f64, ut12: f64) -> ErfaResult<(f64, f64)> {
+    eraUt1tt_safe(ut11, ut12, model.at(JulianDate::new(ut11, ut12)))
+}
+
+impl Tt {
+    pub fn to_ut1_modelled(self, model: DeltaTModel) -> ErfaResult<Ut1> {
+        let (ut11, ut12) = ttut1(model, self.jd1(), self.jd2())?;
+        Ok(Ut1::new(ut11, ut12))
+    }
+}
+
+impl Ut1 {
+    pub fn to_tt_modelled(self, model: DeltaTModel) -> ErfaResult<Tt> {
+        let (tt1, tt2) = ut1tt(model, self.jd1(), self.jd2())?;
+        Ok(Tt::new(tt1, tt2))
+    }
+}
+
+// Polynomial in t, lowest order first.
+fn poly(c: &[f64], t: f64) -> f64 {
+    c.iter().rev().fold(0.0, |acc, &k| acc * t + k)
+}
+
+// Morrison & Stephenson (2004) long-term parabola.
+fn parabola_2004(y: f64) -> f64 {
+    let u = (y - 1820.0) / 100.0;
+    -20.0 + 32.0 * u * u
+}
+
+// Stephenson, Morrison & Hohenkerk (2016) long-term parabola.
+fn parabola_2016(y: f64) -> f64 {
+    let u = (y - 1825.0) / 100.0;
+    -320.0 + 32.5 * u * u
+}
+
+// Espenak & Meeus, Five Millennium Canon of Solar Eclipses (2006).
+fn espenak_meeus(y: f64) -> f64 {
+    if y < -500.0 {
+        parabola_2004(y)
+    } else if y < 500.0 {
+        let c = [
+            10583.6,
+            -1014.41,
+            33.78311,
+            -5.952053,
+            -0.1798452,
+            0.022174192,
+            0.0090316521,
+        ];
+        poly(&c, y / 100.0)
+    } else if y < 1600.0 {
+        let c = [
+            1574.2,
+            -556.01,
+            71.23472,
+            0.319781,
+            -0.8503463,
+            -0.005050998,
+            0.0083572073,
+        ];
+        poly(&c, (y - 1000.0) / 100.0)
+    } else if y < 1700.0 {
+        poly(&[120.0, -0.9808, -0.01532, 1.0 / 7129.0], y - 1600.0)
+    } else if y < 1800.0 {
+        let c = [8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1_174_000.0];
+        poly(&c, y - 1700.0)
+    } else if y < 1860.0 {
+        let c = [
+            13.72,
+            -0.332447,
+            0.0068612,
+            0.0041116,
+            -0.00037436,
+            0.0000121272,
+            -0.0000001699,
+            0.000000000875,
+        ];
+        poly(&c, y - 1800.0)
+    } else if y < 1900.0 {
+        let c = [
+            7.62,
+            0.5737,
+            -0.251754,
+            0.01680668,
+            -0.0004473624,
+            1.0 / 233_174.0,
+        ];
+        poly(&c, y - 1860.0)
+    } else if y < 1920.0 {
+        let c = [-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197];
+        poly(&c, y - 1900.0)
+    } else if y < 1941.0 {
+        poly(&[21.20, 0.84493, -0.076100, 0.0020936], y - 1920.0)
+    } else if y < 1961.0 {
+        poly(&[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0], y - 1950.0)
+    } else if y < 1986.0 {
+        poly(&[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0], y - 1975.0)
+    } else if y < 2005.0 {
+        let c = [
+            63.86,
+            0.3345,
+            -0.060374,
+            0.0017275,
+            0.000651814,
+            0.00002373599,
+        ];
+        poly(&c, y - 2000.0)
+    } else if y < 2050.0 {
+        poly(&[62.92, 0.32217, 0.005589], y - 2000.0)
+    } else if y < 2150.0 {
+        parabola_2004(y) - 0.5628 * (2150.0 - y)
+    } else {
+        parabola_2004(y)
+    }
+}
+
+// Morrison & Stephenson (2004), Table 1: ΔT (s) every century, −1000 … 1600.
+const MS2004_START: f64 = -1000.0;
+const MS2004: [f64; 27] = [
+    25400.0, 23700.0, 22000.0, 21000.0, 19040.0, 17190.0, 15530.0, 14080.0, 12790.0, 11640.0,
+    10580.0, 9600.0, 8640.0, 7680.0, 6700.0, 5710.0, 4740.0, 3810.0, 2960.0, 2200.0, 1570.0,
+    1090.0, 740.0, 490.0, 320.0, 200.0, 120.0,
+];
+
+fn morrison_stephenson_2004(y: f64) -> f64 {
+    let end = MS2004_START + 100.0 * (MS2004.len() - 1) as f64;
+    if y < MS2004_START {
+        parabola_2004(y)
+    } else if y > end {
+        espenak_meeus(y)
+    } else {
+        natural_spline(&MS2004, (y - MS2004_START) / 100.0)
+    }
+}
+
+// Stephenson, Morrison & Hohenkerk (2016), Table S15: knots K_i, K_i+1 and
+// a0…a3 of ΔT = a0 + a1 t + a2 t² + a3 t³, t = (y − K_i)/(K_i+1 − K_i).
+const SMH2016: [[f64; 6]; 11] = [
+    [-720.0, -100.0, 20371.848, -9999.586, 776.247, 409.160],
+    [-100.0, 400.0, 11557.668, -5822.270, 1303.151, -503.433],
+    [400.0, 1000.0, 6535.116, -5671.519, -298.291, 1085.087],
+    [1000.0, 1150.0, 1650.393, -753.210, 184.811, -25.347],
+    [1150.0, 1300.0, 1056.647, -459.628, 108.771, -24.641],
+    [1300.0, 1500.0, 681.149, -421.345, 61.953, -29.414],
+    [1500.0, 1600.0, 292.343, -192.841, -6.572, 16.197],
+    [1600.0, 1650.0, 109.127, -78.697, 10.505, 3.018],
+    [1650.0, 1720.0, 43.952, -68.089, 38.333, -2.127],
+    [1720.0, 1800.0, 12.068, 2.507, 41.731, -37.939],
+    [1800.0, 1810.0, 18.367, -3.481, -1.126, 1.918],
+];
+
+// Years over which the spline's offset from Espenak–Meeus is tapered out.
+const SMH2016_TAPER: f64 = 20.0;
+
+fn stephenson_2016(y: f64) -> f64 {
+    let last = SMH2016[SMH2016.len() - 1];
+    if y < SMH2016[0][0] {
+        let start = SMH2016[0][0];
+        return parabola_2016(y) + SMH2016[0][2] - parabola_2016(start);
+    }
+    if y > last[1] {
+        let end = last[1];
+        let offset = poly(&last[2..], 1.0) - espenak_meeus(end);
+        let w = (1.0 - (y - end) / SMH2016_TAPER).max(0.0);
+        return espenak_meeus(y) + offset * w;
+    }
+    let s = SMH2016.iter().find(|s| y <= s[1]).unwrap_or(&last);
+    poly(&s[2..], (y - s[0]) / (s[1] - s[0]))
+}
+
+// Natural cubic spline through y[0..n] at unit spacing, evaluated at x.
+fn natural_spline(y: &[f64], x: f64) -> f64 {
+    let n = y.len();
+    // Second derivatives from the tridiagonal system (Thomas algorithm).
+    let mut m = vec![0.0; n];
+    let mut c = vec![0.0; n];
+    for i in 1..n - 1 {
+        let rhs = 6.0 * (y[i + 1] - 2.0 * y[i] + y[i - 1]);
+        let den = 4.0 - c[i - 1];
+        c[i] = 1.0 / den;
+        m[i] = (rhs - m[i - 1]) / den;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] -= c[i] * m[i + 1];
+    }
+
+    let i = (x.floor() as usize).min(n - 2);
+    let t = x - i as f64;
+    let a = 1.0 - t;
+    a * y[i] + t * y[i + 1] + ((a * a * a - a) * m[i] + (t * t * t - t) * m[i + 1]) / 6.0
+}
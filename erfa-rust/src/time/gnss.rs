@@ -0,0 +1,134 @@
+// time/gnss
+//   GNSS system times as week number + seconds of week.
+//
+//   GPS     → epoch 1980-01-06, TAI − GPS = 19 s, 10-bit broadcast week
+//   Galileo → epoch 1999-08-22 (GPS week 1024), TAI − GST = 19 s, 12-bit week
+//   BeiDou  → epoch 2006-01-01 (GPS week 1356), TAI − BDT = 33 s, 13-bit week
+//
+//   All three are continuous and tied to TAI by a fixed offset, so the
+//   conversions go through Tai; UTC goes through eraTaiutc/eraUtctai.
+
+use crate::H1_safe::{DatStatus, ERFA_DAYSEC, ERFA_DJM0};
+
+use super::{Tai, Utc};
+
+pub use crate::H1_safe::ErfaResult;
+
+const SECONDS_PER_WEEK: f64 = 604_800.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GnssSystem {
+    Gps,
+    Galileo,
+    BeiDou,
+}
+
+impl GnssSystem {
+    pub const ALL: [GnssSystem; 3] = [GnssSystem::Gps, GnssSystem::Galileo, GnssSystem::BeiDou];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GnssSystem::Gps => "GPS",
+            GnssSystem::Galileo => "GST",
+            GnssSystem::BeiDou => "BDT",
+        }
+    }
+
+    // TAI − system time (s).
+    pub fn tai_offset(self) -> f64 {
+        match self {
+            GnssSystem::Gps | GnssSystem::Galileo => 19.0,
+            GnssSystem::BeiDou => 33.0,
+        }
+    }
+
+    // MJD of week 0, 0 s, counted in the system's own time.
+    pub fn epoch_mjd(self) -> f64 {
+        match self {
+            GnssSystem::Gps => 44_244.0,
+            GnssSystem::Galileo => 51_412.0,
+            GnssSystem::BeiDou => 53_736.0,
+        }
+    }
+
+    // Modulus of the week number in the navigation message.
+    pub fn week_rollover(self) -> i32 {
+        match self {
+            GnssSystem::Gps => 1024,
+            GnssSystem::Galileo => 4096,
+            GnssSystem::BeiDou => 8192,
+        }
+    }
+}
+
+// Full (not rolled-over) week and seconds of week, 0 ≤ tow < 604800.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GnssTime {
+    pub system: GnssSystem,
+    pub week: i32,
+    pub tow: f64,
+}
+
+impl GnssTime {
+    // Any tow is accepted and carried into the week.
+    pub fn new(system: GnssSystem, week: i32, tow: f64) -> Self {
+        let carry = (tow / SECONDS_PER_WEEK).floor();
+        GnssTime {
+            system,
+            week: week + carry as i32,
+            tow: tow - carry * SECONDS_PER_WEEK,
+        }
+    }
+
+    // Week from a broadcast (truncated) week number, taking the rollover
+    // cycle that puts the result nearest `near`.
+    pub fn from_broadcast(system: GnssSystem, week: i32, tow: f64, near: Tai) -> Self {
+        let n = system.week_rollover();
+        let guess = GnssTime::from_tai(system, near).week;
+        let cycle = ((guess - week.rem_euclid(n)) as f64 / n as f64).round() as i32;
+        GnssTime::new(system, week.rem_euclid(n) + cycle * n, tow)
+    }
+
+    pub fn broadcast_week(self) -> i32 {
+        self.week.rem_euclid(self.system.week_rollover())
+    }
+
+    // Seconds since the system epoch.
+    pub fn seconds(self) -> f64 {
+        self.week as f64 * SECONDS_PER_WEEK + self.tow
+    }
+
+    pub fn from_tai(system: GnssSystem, tai: Tai) -> Self {
+        // Whole days first so the seconds keep their precision.
+        let d = tai.jd1() - ERFA_DJM0 - system.epoch_mjd();
+        let days = d.floor();
+        let s = days * ERFA_DAYSEC + ((d - days) + tai.jd2()) * ERFA_DAYSEC - system.tai_offset();
+        GnssTime::new(system, 0, s)
+    }
+
+    pub fn to_tai(self) -> Tai {
+        let s = self.tow + self.system.tai_offset();
+        let days = (s / ERFA_DAYSEC).floor();
+        Tai::new(
+            ERFA_DJM0 + self.system.epoch_mjd() + 7.0 * self.week as f64 + days,
+            (s - days * ERFA_DAYSEC) / ERFA_DAYSEC,
+        )
+    }
+
+    pub fn from_utc(system: GnssSystem, utc: Utc) -> ErfaResult<(Self, DatStatus)> {
+        let (tai, js) = utc.to_tai()?;
+        Ok((GnssTime::from_tai(system, tai), js))
+    }
+
+    pub fn to_utc(self) -> ErfaResult<(Utc, DatStatus)> {
+        self.to_tai().to_utc()
+    }
+
+    // Same instant in another system's week/seconds.
+    pub fn to_system(self, system: GnssSystem) -> Self {
+        let from = self.system;
+        let epochs = (from.epoch_mjd() - system.epoch_mjd()) * ERFA_DAYSEC;
+        let s = self.seconds() + epochs + from.tai_offset() - system.tai_offset();
+        GnssTime::new(system, 0, s)
+    }
+}
@@ -0,0 +1,159 @@
+// time/iso
+//   ISO 8601 and FITS DATE-OBS strings ↔ two-part JD, via eraDtf2d/eraD2dtf.
+//
+//   Accepted:  YYYY-MM-DD, YYYY-MM-DDThh:mm, YYYY-MM-DDThh:mm:ss[.f…]
+//              (space for T allowed, trailing Z for UTC only, signed
+//              years of four or more digits as in FITS), and the old
+//              FITS DD/MM/YY form for 1900–1999.
+//   Produced:  YYYY-MM-DDThh:mm:ss[.f…], 0–9 decimals, no Z, which is
+//              valid both as ISO 8601 and as FITS DATE-OBS.
+//
+//   UTC leap seconds (23:59:60.x) go through eraDtf2d/eraD2dtf unchanged.
+
+use crate::G10_safe::eraDtf2d_safe;
+use crate::G9_safe::eraD2dtf_safe;
+use crate::H1_safe::{DatStatus, Dtf2dStatus, ErfaError};
+
+use super::{Tai, Tcb, Tcg, Tdb, Tt, Ut1, Utc};
+
+pub use crate::H1_safe::ErfaResult;
+
+// Calendar and clock fields of a date-time string.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DateTimeFields {
+    pub iy: i32,
+    pub im: i32,
+    pub id: i32,
+    pub ihr: i32,
+    pub imn: i32,
+    pub sec: f64,
+    pub utc_designator: bool, // trailing Z
+}
+
+// Split an ISO 8601 / FITS DATE-OBS string into fields (no range checks
+// beyond digit counts; eraDtf2d does those).
+pub fn parse_fields(s: &str) -> ErfaResult<DateTimeFields> {
+    let s = s.trim();
+    let (s, utc_designator) = match s.strip_suffix('Z') {
+        Some(rest) => (rest, true),
+        None => (s, false),
+    };
+
+    // Pre-2000 FITS: DD/MM/YY.
+    let b = s.as_bytes();
+    if b.len() == 8 && b[2] == b'/' && b[5] == b'/' && !utc_designator {
+        return Ok(DateTimeFields {
+            iy: 1900 + digits(&s[6..8], 2)?,
+            im: digits(&s[3..5], 2)?,
+            id: digits(&s[0..2], 2)?,
+            ..DateTimeFields::default()
+        });
+    }
+
+    let (date, time) = match s.find(['T', ' ']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let (sign, body) = match date.as_bytes().first() {
+        Some(b'+') => (Some(1), &date[1..]),
+        Some(b'-') => (Some(-1), &date[1..]),
+        _ => (None, date),
+    };
+    let mut ymd = body.split('-');
+    let (Some(y), Some(m), Some(d), None) = (ymd.next(), ymd.next(), ymd.next(), ymd.next()) else {
+        return Err(ErfaError::BadTimeString);
+    };
+    // Unsigned years have four digits; signed (expanded) years at least four.
+    let (sign, ny) = match sign {
+        Some(sign) if y.len() >= 4 => (sign, y.len()),
+        None => (1, 4),
+        _ => return Err(ErfaError::BadTimeString),
+    };
+    let mut f = DateTimeFields {
+        iy: sign * digits(y, ny)?,
+        im: digits(m, 2)?,
+        id: digits(d, 2)?,
+        utc_designator,
+        ..DateTimeFields::default()
+    };
+
+    if let Some(time) = time {
+        let mut hms = time.split(':');
+        let (Some(h), Some(m)) = (hms.next(), hms.next()) else {
+            return Err(ErfaError::BadTimeString);
+        };
+        f.ihr = digits(h, 2)?;
+        f.imn = digits(m, 2)?;
+        if let Some(sec) = hms.next() {
+            let (whole, frac) = sec.split_once('.').unwrap_or((sec, ""));
+            digits(whole, 2)?;
+            if !frac.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(ErfaError::BadTimeString);
+            }
+            f.sec = sec.parse::<f64>().map_err(|_| ErfaError::BadTimeString)?;
+        }
+        if hms.next().is_some() {
+            return Err(ErfaError::BadTimeString);
+        }
+    }
+    Ok(f)
+}
+
+// Parse a date-time string in the given scale to a two-part JD.
+// A trailing Z is only accepted for UTC.
+pub fn parse_iso(scale: &str, s: &str) -> ErfaResult<((f64, f64), Dtf2dStatus)> {
+    let f = parse_fields(s)?;
+    if f.utc_designator && scale != "UTC" {
+        return Err(ErfaError::BadTimeString);
+    }
+    eraDtf2d_safe(scale, f.iy, f.im, f.id, f.ihr, f.imn, f.sec)
+}
+
+// Format a two-part JD as YYYY-MM-DDThh:mm:ss with ndp (0–9) decimals.
+pub fn format_iso(scale: &str, ndp: i32, d1: f64, d2: f64) -> ErfaResult<(String, DatStatus)> {
+    if !(0..=9).contains(&ndp) {
+        return Err(ErfaError::BadPrecision);
+    }
+    let ((iy, im, id), ihmsf, js) = eraD2dtf_safe(scale, ndp, d1, d2)?;
+    let year = if (0..=9999).contains(&iy) {
+        format!("{:04}", iy)
+    } else {
+        format!("{:+06}", iy)
+    };
+    let mut out = format!(
+        "{}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year, im, id, ihmsf[0], ihmsf[1], ihmsf[2]
+    );
+    if ndp > 0 {
+        out.push_str(&format!(".{:0width$}", ihmsf[3], width = ndp as usize));
+    }
+    Ok((out, js))
+}
+
+// Exactly n ASCII digits.
+fn digits(s: &str, n: usize) -> ErfaResult<i32> {
+    if s.len() != n || !s.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(ErfaError::BadTimeString);
+    }
+    s.parse::<i32>().map_err(|_| ErfaError::BadTimeString)
+}
+
+macro_rules! iso_methods {
+    ($($name:ident),*) => {
+        $(
+            impl $name {
+                pub fn from_iso(s: &str) -> ErfaResult<(Self, Dtf2dStatus)> {
+                    let ((d1, d2), js) = parse_iso(Self::SCALE, s)?;
+                    Ok(($name::new(d1, d2), js))
+                }
+
+                pub fn to_iso(self, ndp: i32) -> ErfaResult<(String, DatStatus)> {
+                    format_iso(Self::SCALE, ndp, self.jd1(), self.jd2())
+                }
+            }
+        )*
+    };
+}
+
+iso_methods!(Tai, Tt, Utc, Ut1, Tdb, Tcb, Tcg);
@@ -0,0 +1,285 @@
+// time/leap
+//   Readers for published leap-second tables.
+//
+//   leap-seconds.list (NIST/IETF) → NTP-second epochs, #$ update, #@ expiry,
+//                                   #h SHA-1 of the data
+//   Leap_Second.dat   (IERS)      → MJD, calendar date, TAI−UTC, expiry comment
+//
+//   Both give the table eraDat expects (the pre-1972 drift eras are kept from
+//   the built-in table) and the file's expiry, either as a LeapSecondTable or
+//   installed as the process-wide table.
+
+use std::fs;
+use std::path::Path;
+
+use crate::G14_safe::{LeapSecondTable, G14_BUILTIN_LEAPSECONDS};
+use crate::G19_safe::eraJd2cal_safe;
+use crate::G8_safe::eraCal2jd_safe;
+use crate::H1_safe::{eraLEAPSECOND, ErfaError, ERFA_DJM0};
+
+use super::Utc;
+
+pub use crate::H1_safe::ErfaResult;
+
+// MJD of the NTP epoch, 1900 January 1.
+const NTP_EPOCH_MJD: f64 = 15_020.0;
+
+// A parsed leap-second file.
+#[derive(Clone, Debug)]
+pub struct LeapSecondFile {
+    pub table: Vec<eraLEAPSECOND>,
+    pub expires: Utc,
+    pub updated: Option<Utc>,
+    pub checksum_verified: bool,
+}
+
+impl LeapSecondFile {
+    // Parse the text of a NIST/IETF leap-seconds.list file.
+    // A #h line that does not match the data is an error.
+    pub fn parse_leap_seconds_list(text: &str) -> ErfaResult<Self> {
+        let mut updated = None;
+        let mut expires = None;
+        let mut expires_comment = None;
+        let mut hash = None;
+        let mut hashed = String::new();
+        let mut entries = Vec::new();
+
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("#$") {
+                let ntp = first_field(rest)?;
+                hashed.push_str(ntp);
+                updated = Some(ntp_to_utc(parse_u64(ntp)?));
+            } else if let Some(rest) = line.strip_prefix("#@") {
+                let ntp = first_field(rest)?;
+                hashed.push_str(ntp);
+                expires = Some(ntp_to_utc(parse_u64(ntp)?));
+            } else if let Some(rest) = line.strip_prefix("#h") {
+                hash = Some(parse_hash(rest)?);
+            } else if let Some(rest) = line.strip_prefix('#') {
+                if let Some(ymd) = expiry_comment(rest) {
+                    expires_comment = Some(ymd);
+                }
+            } else {
+                let data = line.split('#').next().unwrap_or("");
+                let mut fields = data.split_whitespace();
+                let (Some(ntp), Some(dat)) = (fields.next(), fields.next()) else {
+                    continue;
+                };
+                hashed.push_str(ntp);
+                hashed.push_str(dat);
+                let ((iy, im, _), _) = eraJd2cal_safe(ERFA_DJM0, ntp_mjd(parse_u64(ntp)?))?;
+                let delat = dat.parse::<f64>().map_err(|_| ErfaError::LeapFileSyntax)?;
+                entries.push(eraLEAPSECOND {
+                    iyear: iy,
+                    month: im,
+                    delat,
+                });
+            }
+        }
+
+        let checksum_verified = match hash {
+            Some(h) if h != sha1(hashed.as_bytes()) => return Err(ErfaError::LeapFileChecksum),
+            Some(_) => true,
+            None => false,
+        };
+        let expires = match (expires, expires_comment) {
+            (Some(utc), _) => utc,
+            (None, Some((iy, im, id))) => calendar_utc(iy, im, id)?,
+            (None, None) => return Err(ErfaError::LeapFileNoExpiry),
+        };
+
+        Ok(LeapSecondFile {
+            table: with_drift_eras(entries)?,
+            expires,
+            updated,
+            checksum_verified,
+        })
+    }
+
+    // Parse the text of an IERS Leap_Second.dat file.
+    pub fn parse_leap_second_dat(text: &str) -> ErfaResult<Self> {
+        let mut expires = None;
+        let mut entries = Vec::new();
+
+        for line in text.lines() {
+            if let Some(rest) = line.trim_start().strip_prefix('#') {
+                if let Some(ymd) = expiry_comment(rest) {
+                    expires = Some(ymd);
+                }
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+            if fields.len() < 5 {
+                return Err(ErfaError::LeapFileSyntax);
+            }
+            let int = |s: &str| s.parse::<i32>().map_err(|_| ErfaError::LeapFileSyntax);
+            let (im, iy) = (int(fields[2])?, int(fields[3])?);
+            let delat = fields[4]
+                .parse::<f64>()
+                .map_err(|_| ErfaError::LeapFileSyntax)?;
+            entries.push(eraLEAPSECOND {
+                iyear: iy,
+                month: im,
+                delat,
+            });
+        }
+
+        let (iy, im, id) = expires.ok_or(ErfaError::LeapFileNoExpiry)?;
+        Ok(LeapSecondFile {
+            table: with_drift_eras(entries)?,
+            expires: calendar_utc(iy, im, id)?,
+            updated: None,
+            checksum_verified: false,
+        })
+    }
+
+    pub fn load_leap_seconds_list<P: AsRef<Path>>(path: P) -> ErfaResult<Self> {
+        let text = fs::read_to_string(path).map_err(|_| ErfaError::FileRead)?;
+        LeapSecondFile::parse_leap_seconds_list(&text)
+    }
+
+    pub fn load_leap_second_dat<P: AsRef<Path>>(path: P) -> ErfaResult<Self> {
+        let text = fs::read_to_string(path).map_err(|_| ErfaError::FileRead)?;
+        LeapSecondFile::parse_leap_second_dat(&text)
+    }
+
+    // The table as a value for the *Table_safe routines.
+    pub fn to_table(&self) -> LeapSecondTable {
+        let expires = self.expires.jd();
+        LeapSecondTable::new(self.table.clone(), expires.jd1 - ERFA_DJM0 + expires.jd2)
+    }
+
+    // Make this table, and its expiry, the one used by eraDat and everything
+    // built on it.
+    pub fn install(&self) -> ErfaResult<()> {
+        self.to_table().install()
+    }
+
+    // True once `at` reaches the expiry date; later leap seconds may be missing.
+    pub fn is_expired(&self, at: Utc) -> bool {
+        at - self.expires >= 0.0
+    }
+}
+
+fn first_field(s: &str) -> ErfaResult<&str> {
+    s.split_whitespace().next().ok_or(ErfaError::LeapFileSyntax)
+}
+
+fn parse_u64(s: &str) -> ErfaResult<u64> {
+    s.parse::<u64>().map_err(|_| ErfaError::LeapFileSyntax)
+}
+
+fn ntp_mjd(ntp: u64) -> f64 {
+    NTP_EPOCH_MJD + (ntp / 86_400) as f64 + (ntp % 86_400) as f64 / 86_400.0
+}
+
+fn ntp_to_utc(ntp: u64) -> Utc {
+    Utc::new(ERFA_DJM0, ntp_mjd(ntp))
+}
+
+fn calendar_utc(iy: i32, im: i32, id: i32) -> ErfaResult<Utc> {
+    let (djm0, djm) = eraCal2jd_safe(iy, im, id)?;
+    Ok(Utc::new(djm0, djm))
+}
+
+// "File expires on 28 June 2026" → (2026, 6, 28).
+fn expiry_comment(comment: &str) -> Option<(i32, i32, i32)> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let rest = comment.trim().strip_prefix("File expires on")?;
+    let mut fields = rest.trim_start_matches(':').split_whitespace();
+    let id = fields.next()?.parse::<i32>().ok()?;
+    let name = fields.next()?.to_ascii_lowercase();
+    let im = MONTHS.iter().position(|m| name.starts_with(m))? as i32 + 1;
+    let iy = fields.next()?.parse::<i32>().ok()?;
+    Some((iy, im, id))
+}
+
+// Five hex words; the published files drop leading zeros.
+fn parse_hash(s: &str) -> ErfaResult<[u32; 5]> {
+    let mut h = [0_u32; 5];
+    let mut words = s.split_whitespace();
+    for w in h.iter_mut() {
+        let word = words.next().ok_or(ErfaError::LeapFileSyntax)?;
+        *w = u32::from_str_radix(word, 16).map_err(|_| ErfaError::LeapFileSyntax)?;
+    }
+    Ok(h)
+}
+
+// Prefix the built-in pre-1972 entries, which eraDat pairs with its drift
+// rates, and check the file part is in date order.
+fn with_drift_eras(entries: Vec<eraLEAPSECOND>) -> ErfaResult<Vec<eraLEAPSECOND>> {
+    let first = entries.first().ok_or(ErfaError::LeapFileSyntax)?;
+    let start = 12 * first.iyear + first.month;
+    for pair in entries.windows(2) {
+        if 12 * pair[1].iyear + pair[1].month <= 12 * pair[0].iyear + pair[0].month {
+            return Err(ErfaError::LeapFileSyntax);
+        }
+    }
+    let mut table: Vec<eraLEAPSECOND> = G14_BUILTIN_LEAPSECONDS
+        .iter()
+        .filter(|e| e.iyear < 1972 && 12 * e.iyear + e.month < start)
+        .copied()
+        .collect();
+    table.extend(entries);
+    Ok(table)
+}
+
+// SHA-1 (FIPS 180-4), enough for the leap-seconds.list #h check.
+fn sha1(data: &[u8]) -> [u32; 5] {
+    let mut h: [u32; 5] = [
+        0x6745_2301,
+        0xEFCD_AB89,
+        0x98BA_DCFE,
+        0x1032_5476,
+        0xC3D2_E1F0,
+    ];
+
+    let mut msg = data.to_vec();
+    let bits = (data.len() as u64).wrapping_mul(8);
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&bits.to_be_bytes());
+
+    for block in msg.chunks(64) {
+        let mut w = [0_u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, &wi) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let t = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(wi);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = t;
+        }
+
+        for (x, y) in h.iter_mut().zip([a, b, c, d, e]) {
+            *x = x.wrapping_add(y);
+        }
+    }
+    h
+}
@@ -0,0 +1,112 @@
+// time/unix
+//   Unix and NTP seconds ↔ UTC.
+//
+//   Both count 86400 s per UTC day and skip leap seconds: 23:59:60.x on a
+//   leap-second day gives the same count as 00:00:00.x the next day, and
+//   counts never map back to :60.  Day lengths come from the leap-second
+//   table, so the UTC quasi-JD is the one eraUtctai/eraD2dtf expect.
+//
+//   NTP timestamps are 32.32 fixed point in eras of 2^32 s from 1900.
+
+use crate::G10_safe::eraDtf2dTable_safe;
+use crate::G14_safe::LeapSecondTable;
+use crate::G19_safe::eraJd2cal_safe;
+use crate::G8_safe::eraCal2jd_safe;
+use crate::G9_safe::eraDatTable_safe;
+use crate::H1_safe::{DatStatus, ERFA_DAYSEC, ERFA_DJM0};
+
+use super::Utc;
+
+pub use crate::H1_safe::ErfaResult;
+
+// MJD of 1970-01-01 and 1900-01-01.
+const UNIX_EPOCH_MJD: f64 = 40_587.0;
+const NTP_EPOCH_MJD: f64 = 15_020.0;
+
+// NTP seconds at the Unix epoch.
+pub const NTP_UNIX_OFFSET: f64 = (UNIX_EPOCH_MJD - NTP_EPOCH_MJD) * ERFA_DAYSEC;
+
+// Seconds in one NTP era.
+const NTP_ERA: f64 = 4_294_967_296.0;
+
+// Unix seconds → UTC, with day lengths from the given leap-second table.
+pub fn utc_from_unix(table: &LeapSecondTable, unix: f64) -> ErfaResult<(Utc, DatStatus)> {
+    let day = (unix / ERFA_DAYSEC).floor();
+    let secs = unix - day * ERFA_DAYSEC;
+    let ((iy, im, id), _) = eraJd2cal_safe(ERFA_DJM0, UNIX_EPOCH_MJD + day)?;
+    let ihr = (secs / 3600.0).floor();
+    let imn = ((secs - 3600.0 * ihr) / 60.0).floor();
+    let sec = secs - 3600.0 * ihr - 60.0 * imn;
+    let ((d1, d2), js) = eraDtf2dTable_safe(table, "UTC", iy, im, id, ihr as i32, imn as i32, sec)?;
+    Ok((Utc::new(d1, d2), js.dat))
+}
+
+// UTC → Unix seconds, with day lengths from the given leap-second table.
+pub fn unix_from_utc(table: &LeapSecondTable, utc: Utc) -> ErfaResult<(f64, DatStatus)> {
+    let ((iy, im, id), fd) = eraJd2cal_safe(utc.jd1(), utc.jd2())?;
+
+    // Length of this UTC day, as in eraUtctai.
+    let (dat0, _) = eraDatTable_safe(table, iy, im, id, 0.0)?;
+    let (dat12, _) = eraDatTable_safe(table, iy, im, id, 0.5)?;
+    let (djm0, djm) = eraCal2jd_safe(iy, im, id)?;
+    let ((iy2, im2, id2), _) = eraJd2cal_safe(djm0 + djm, 1.0)?;
+    let (dat24, js) = eraDatTable_safe(table, iy2, im2, id2, 0.0)?;
+    let day = ERFA_DAYSEC + dat24 - (2.0 * dat12 - dat0);
+
+    Ok(((djm - UNIX_EPOCH_MJD) * ERFA_DAYSEC + fd * day, js))
+}
+
+// NTP seconds (from 1900, any era) ↔ Unix seconds.
+pub fn ntp_to_unix(ntp: f64) -> f64 {
+    ntp - NTP_UNIX_OFFSET
+}
+
+pub fn unix_to_ntp(unix: f64) -> f64 {
+    unix + NTP_UNIX_OFFSET
+}
+
+// 64-bit NTP timestamp plus era → NTP seconds.
+pub fn ntp_from_timestamp(era: i32, timestamp: u64) -> f64 {
+    let seconds = (timestamp >> 32) as f64;
+    let fraction = (timestamp & 0xFFFF_FFFF) as f64 / NTP_ERA;
+    era as f64 * NTP_ERA + seconds + fraction
+}
+
+// NTP seconds → (era, 64-bit timestamp), fraction rounded to 2^-32 s.
+pub fn ntp_to_timestamp(ntp: f64) -> (i32, u64) {
+    let era = (ntp / NTP_ERA).floor();
+    let s = ntp - era * NTP_ERA;
+    let whole = s.floor();
+    let mut seconds = whole as u64;
+    let mut fraction = ((s - whole) * NTP_ERA).round() as u64;
+    if fraction > 0xFFFF_FFFF {
+        fraction = 0;
+        seconds += 1;
+    }
+    let mut era = era as i32;
+    if seconds > 0xFFFF_FFFF {
+        seconds = 0;
+        era += 1;
+    }
+    (era, (seconds << 32) | fraction)
+}
+
+impl Utc {
+    // Using the process-wide leap-second table.
+    pub fn from_unix(unix: f64) -> ErfaResult<(Utc, DatStatus)> {
+        utc_from_unix(&LeapSecondTable::current()?, unix)
+    }
+
+    pub fn to_unix(self) -> ErfaResult<(f64, DatStatus)> {
+        unix_from_utc(&LeapSecondTable::current()?, self)
+    }
+
+    pub fn from_ntp(ntp: f64) -> ErfaResult<(Utc, DatStatus)> {
+        Utc::from_unix(ntp_to_unix(ntp))
+    }
+
+    pub fn to_ntp(self) -> ErfaResult<(f64, DatStatus)> {
+        let (unix, js) = self.to_unix()?;
+        Ok((unix_to_ntp(unix), js))
+    }
+}